@@ -1,6 +1,22 @@
+use crate::config::Manifest;
 use crate::error::ZackstrapError;
-use crate::generators::{hooks::GitHooksGenerator, ConfigGenerator, ProjectType};
+use crate::generators::common::WrittenPaths;
+use crate::generators::git::GitRepo;
+use crate::generators::hooks::lifecycle::hook_script;
+use crate::generators::hooks::runner::{CheckOutcome, CheckStatus};
+use crate::generators::hooks::{
+    CommitMsgPolicy, GitHooksGenerator, HookKind, HookPlan, HookRunner, HookStatus, HooksManager,
+    UninstallOutcome, COMMITTED_HOOKS_DIR,
+};
+use crate::generators::node::FLAT_ESLINT_CONFIG;
+use crate::generators::toolchain::{
+    find_config, EslintFormat, GolangciPreset, NodePackageManager, PythonLinter,
+    PythonPackageManager, RustLinker, Toolchain, TsStrictness, FLAT_ESLINT_CONFIGS,
+    LEGACY_ESLINT_CONFIGS,
+};
+use crate::generators::{ConfigGenerator, ProjectType};
 use colored::*;
+use indicatif::MultiProgress;
 use std::path::PathBuf;
 
 pub struct CommandHandler {
@@ -9,6 +25,10 @@ pub struct CommandHandler {
     fail_on_exists: bool,
     dry_run: bool,
     hooks: bool,
+    hooks_manager: HooksManager,
+    hook_kinds: Vec<HookKind>,
+    toolchain: Toolchain,
+    written: WrittenPaths,
 }
 
 impl CommandHandler {
@@ -19,17 +39,96 @@ impl CommandHandler {
         dry_run: bool,
         hooks: bool,
     ) -> Self {
+        let toolchain = Toolchain::detect(&target_dir);
         Self {
             target_dir,
             force,
             fail_on_exists,
             dry_run,
             hooks,
+            hooks_manager: HooksManager::default(),
+            hook_kinds: HookKind::DEFAULT.to_vec(),
+            toolchain,
+            written: WrittenPaths::default(),
         }
     }
 
+    /// Choose the tool that installs the git hooks (defaults to raw
+    /// `.git/hooks` scripts).
+    pub fn with_hooks_manager(mut self, hooks_manager: HooksManager) -> Self {
+        self.hooks_manager = hooks_manager;
+        self
+    }
+
+    /// Choose which git hooks to install (defaults to pre-commit, pre-push
+    /// and commit-msg).
+    pub fn with_hook_kinds(mut self, hook_kinds: Vec<HookKind>) -> Self {
+        self.hook_kinds = hook_kinds;
+        self
+    }
+
+    /// Choose the Python package manager and linter. Choices left out keep
+    /// what the project already uses (pip and Flake8 for new projects).
+    pub fn with_python_toolchain(
+        mut self,
+        package_manager: Option<PythonPackageManager>,
+        linter: Option<PythonLinter>,
+    ) -> Self {
+        self.toolchain.python = self
+            .toolchain
+            .python
+            .with_overrides(package_manager, linter);
+        self
+    }
+
+    pub fn with_node_toolchain(
+        mut self,
+        typescript: bool,
+        strictness: Option<TsStrictness>,
+        eslint_format: Option<EslintFormat>,
+        package_manager: Option<NodePackageManager>,
+        workspaces: bool,
+    ) -> Self {
+        self.toolchain.node = self.toolchain.node.with_overrides(
+            typescript,
+            strictness,
+            eslint_format,
+            package_manager,
+            workspaces,
+        );
+        self
+    }
+
+    pub fn with_rust_toolchain(
+        mut self,
+        native_cpu: bool,
+        linker: Option<RustLinker>,
+        sccache: bool,
+    ) -> Self {
+        self.toolchain.rust = self
+            .toolchain
+            .rust
+            .with_overrides(native_cpu, linker, sccache);
+        self
+    }
+
+    pub fn with_go_toolchain(
+        mut self,
+        module: Option<String>,
+        lint_preset: Option<GolangciPreset>,
+    ) -> Self {
+        self.toolchain.go = self
+            .toolchain
+            .go
+            .clone()
+            .with_overrides(module, lint_preset);
+        self
+    }
+
     fn make_generator(&self) -> ConfigGenerator {
         ConfigGenerator::with_options(self.target_dir.clone(), self.dry_run, self.force)
+            .with_toolchain(self.toolchain.clone())
+            .with_written_paths(self.written.clone())
     }
 
     pub async fn handle_basic(&self, template: Option<String>) -> Result<(), ZackstrapError> {
@@ -66,6 +165,8 @@ impl CommandHandler {
                     "{}",
                     "🪝 [DRY RUN] Would generate git hooks for basic project...".blue()
                 );
+                self.install_hooks(ProjectType::Basic, template_name)
+                    .await?;
             }
         } else {
             println!(
@@ -75,8 +176,8 @@ impl CommandHandler {
 
             if self.hooks {
                 println!("{}", "🪝 Generating git hooks for basic project...".green());
-                let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
-                hooks_generator.generate_basic_hooks(self.force).await?;
+                self.install_hooks(ProjectType::Basic, template_name)
+                    .await?;
                 println!("{}", "✅ Git hooks generated successfully!".green());
             }
         }
@@ -119,6 +220,7 @@ impl CommandHandler {
                     )
                     .blue()
                 );
+                self.install_hooks(ProjectType::Ruby, template_name).await?;
             }
         } else {
             println!(
@@ -135,10 +237,7 @@ impl CommandHandler {
                     )
                     .green()
                 );
-                let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
-                hooks_generator
-                    .generate_ruby_hooks(template_name, self.force)
-                    .await?;
+                self.install_hooks(ProjectType::Ruby, template_name).await?;
                 println!("{}", "✅ Git hooks generated successfully!".green());
             }
         }
@@ -153,8 +252,10 @@ impl CommandHandler {
             println!(
                 "{}",
                 format!(
-                    "🐍 [DRY RUN] Would generate Python project configuration (template: {})...",
-                    template_name
+                    "🐍 [DRY RUN] Would generate Python project configuration (template: {}, {} with {})...",
+                    template_name,
+                    self.toolchain.python.package_manager.as_str(),
+                    self.toolchain.python.linter.as_str()
                 )
                 .blue()
             );
@@ -162,8 +263,10 @@ impl CommandHandler {
             println!(
                 "{}",
                 format!(
-                    "🐍 Generating Python project configuration (template: {})...",
-                    template_name
+                    "🐍 Generating Python project configuration (template: {}, {} with {})...",
+                    template_name,
+                    self.toolchain.python.package_manager.as_str(),
+                    self.toolchain.python.linter.as_str()
                 )
                 .green()
             );
@@ -182,6 +285,8 @@ impl CommandHandler {
                         template_name
                     ).blue()
                 );
+                self.install_hooks(ProjectType::Python, template_name)
+                    .await?;
             }
         } else {
             println!(
@@ -198,9 +303,7 @@ impl CommandHandler {
                     )
                     .green()
                 );
-                let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
-                hooks_generator
-                    .generate_python_hooks(template_name, self.force)
+                self.install_hooks(ProjectType::Python, template_name)
                     .await?;
                 println!("{}", "✅ Git hooks generated successfully!".green());
             }
@@ -211,13 +314,23 @@ impl CommandHandler {
     pub async fn handle_node(&self, template: Option<String>) -> Result<(), ZackstrapError> {
         let template_name = template.as_deref().unwrap_or("default");
         let generator = self.make_generator();
+        let mut language = format!(", {}", self.toolchain.node.package_manager.as_str());
+        if self.toolchain.node.workspaces {
+            language.push_str(" workspaces");
+        }
+        if self.toolchain.node.uses_typescript(template_name) {
+            language.push_str(&format!(
+                ", TypeScript ({})",
+                self.toolchain.node.strictness.as_str()
+            ));
+        }
 
         if self.dry_run {
             println!(
                 "{}",
                 format!(
-                    "🟢 [DRY RUN] Would generate Node.js project configuration (template: {})...",
-                    template_name
+                    "🟢 [DRY RUN] Would generate Node.js project configuration (template: {}{})...",
+                    template_name, language
                 )
                 .blue()
             );
@@ -225,13 +338,28 @@ impl CommandHandler {
             println!(
                 "{}",
                 format!(
-                    "🟢 Generating Node.js project configuration (template: {})...",
-                    template_name
+                    "🟢 Generating Node.js project configuration (template: {}{})...",
+                    template_name, language
                 )
                 .green()
             );
         }
 
+        if self.toolchain.node.eslint_format == EslintFormat::Flat
+            && find_config(&self.target_dir, &FLAT_ESLINT_CONFIGS).is_none()
+        {
+            if let Some(legacy) = find_config(&self.target_dir, &LEGACY_ESLINT_CONFIGS) {
+                println!(
+                    "{}",
+                    format!(
+                        "🔁 Converting {} to {} (needs eslint ^9, @eslint/js and @eslint/eslintrc)",
+                        legacy, FLAT_ESLINT_CONFIG
+                    )
+                    .yellow()
+                );
+            }
+        }
+
         generator.generate_node_with_template(template_name).await?;
 
         if self.dry_run {
@@ -243,6 +371,7 @@ impl CommandHandler {
                         template_name
                     ).blue()
                 );
+                self.install_hooks(ProjectType::Node, template_name).await?;
             }
         } else {
             println!(
@@ -259,10 +388,7 @@ impl CommandHandler {
                     )
                     .green()
                 );
-                let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
-                hooks_generator
-                    .generate_node_hooks(template_name, self.force)
-                    .await?;
+                self.install_hooks(ProjectType::Node, template_name).await?;
                 println!("{}", "✅ Git hooks generated successfully!".green());
             }
         }
@@ -272,13 +398,24 @@ impl CommandHandler {
     pub async fn handle_go(&self, template: Option<String>) -> Result<(), ZackstrapError> {
         let template_name = template.as_deref().unwrap_or("default");
         let generator = self.make_generator();
+        let nested = generator.nested_go_modules();
+        let mut layout = format!(
+            ", golangci-lint {}",
+            self.toolchain.go.lint_preset(template_name).as_str()
+        );
+        if nested.is_empty() || self.target_dir.join("go.mod").exists() {
+            layout.push_str(&format!(", module {}", generator.go_module_path()));
+        }
+        if !nested.is_empty() {
+            layout.push_str(&format!(", go.work with {} nested modules", nested.len()));
+        }
 
         if self.dry_run {
             println!(
                 "{}",
                 format!(
-                    "🐹 [DRY RUN] Would generate Go project configuration (template: {})...",
-                    template_name
+                    "🐹 [DRY RUN] Would generate Go project configuration (template: {}{})...",
+                    template_name, layout
                 )
                 .blue()
             );
@@ -286,8 +423,8 @@ impl CommandHandler {
             println!(
                 "{}",
                 format!(
-                    "🐹 Generating Go project configuration (template: {})...",
-                    template_name
+                    "🐹 Generating Go project configuration (template: {}{})...",
+                    template_name, layout
                 )
                 .green()
             );
@@ -305,6 +442,7 @@ impl CommandHandler {
                     )
                     .blue()
                 );
+                self.install_hooks(ProjectType::Go, template_name).await?;
             }
         } else {
             println!(
@@ -321,10 +459,7 @@ impl CommandHandler {
                     )
                     .green()
                 );
-                let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
-                hooks_generator
-                    .generate_go_hooks(template_name, self.force)
-                    .await?;
+                self.install_hooks(ProjectType::Go, template_name).await?;
                 println!("{}", "✅ Git hooks generated successfully!".green());
             }
         }
@@ -334,13 +469,24 @@ impl CommandHandler {
     pub async fn handle_rust(&self, template: Option<String>) -> Result<(), ZackstrapError> {
         let template_name = template.as_deref().unwrap_or("default");
         let generator = self.make_generator();
+        let rust = &self.toolchain.rust;
+        let mut settings = String::new();
+        if rust.native_cpu {
+            settings.push_str(", native CPU");
+        }
+        if let Some(linker) = rust.linker {
+            settings.push_str(&format!(", {}", linker.as_str()));
+        }
+        if rust.sccache {
+            settings.push_str(", sccache");
+        }
 
         if self.dry_run {
             println!(
                 "{}",
                 format!(
-                    "🦀 [DRY RUN] Would generate Rust project configuration (template: {})...",
-                    template_name
+                    "🦀 [DRY RUN] Would generate Rust project configuration (template: {}{})...",
+                    template_name, settings
                 )
                 .blue()
             );
@@ -348,8 +494,8 @@ impl CommandHandler {
             println!(
                 "{}",
                 format!(
-                    "🦀 Generating Rust project configuration (template: {})...",
-                    template_name
+                    "🦀 Generating Rust project configuration (template: {}{})...",
+                    template_name, settings
                 )
                 .green()
             );
@@ -367,6 +513,7 @@ impl CommandHandler {
                     )
                     .blue()
                 );
+                self.install_hooks(ProjectType::Rust, template_name).await?;
             }
         } else {
             println!(
@@ -383,10 +530,7 @@ impl CommandHandler {
                     )
                     .green()
                 );
-                let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
-                hooks_generator
-                    .generate_rust_hooks(template_name, self.force)
-                    .await?;
+                self.install_hooks(ProjectType::Rust, template_name).await?;
                 println!("{}", "✅ Git hooks generated successfully!".green());
             }
         }
@@ -396,13 +540,14 @@ impl CommandHandler {
     pub async fn handle_bash(&self, template: Option<String>) -> Result<(), ZackstrapError> {
         let template_name = template.as_deref().unwrap_or("default");
         let generator = self.make_generator();
+        let script = generator.bash_script_name();
 
         if self.dry_run {
             println!(
                 "{}",
                 format!(
-                    "🐚 [DRY RUN] Would generate Bash project configuration (template: {})...",
-                    template_name
+                    "🐚 [DRY RUN] Would generate Bash project configuration (template: {}, script bin/{})...",
+                    template_name, script
                 )
                 .blue()
             );
@@ -410,8 +555,8 @@ impl CommandHandler {
             println!(
                 "{}",
                 format!(
-                    "🐚 Generating Bash project configuration (template: {})...",
-                    template_name
+                    "🐚 Generating Bash project configuration (template: {}, script bin/{})...",
+                    template_name, script
                 )
                 .green()
             );
@@ -429,6 +574,7 @@ impl CommandHandler {
                     )
                     .blue()
                 );
+                self.install_hooks(ProjectType::Bash, template_name).await?;
             }
         } else {
             println!(
@@ -445,16 +591,117 @@ impl CommandHandler {
                     )
                     .green()
                 );
-                let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
-                hooks_generator
-                    .generate_bash_hooks(template_name, self.force)
-                    .await?;
+                self.install_hooks(ProjectType::Bash, template_name).await?;
                 println!("{}", "✅ Git hooks generated successfully!".green());
             }
         }
         Ok(())
     }
 
+    /// Install hooks with the selected manager. Raw hooks go straight into
+    /// `.git/hooks`; the other managers get committed config files. In
+    /// dry-run both are previewed like any other generated file.
+    async fn install_hooks(
+        &self,
+        project_type: ProjectType,
+        template: &str,
+    ) -> Result<(), ZackstrapError> {
+        let policy = Manifest::load(&self.target_dir)?.commit_msg;
+
+        if self.hooks_manager == HooksManager::Raw {
+            let hooks_generator = GitHooksGenerator::new(self.target_dir.clone())
+                .with_commit_msg_policy(policy)
+                .with_hook_kinds(&self.hook_kinds)
+                .with_toolchain(self.toolchain.clone())
+                .with_written_paths(self.written.clone())
+                .with_dry_run(self.dry_run);
+            return hooks_generator
+                .generate_hooks(&project_type, template, self.force)
+                .await;
+        }
+
+        let plan = HookPlan::new(
+            &project_type,
+            template,
+            &self.toolchain,
+            &self.hook_kinds,
+            policy.clone(),
+        );
+        self.make_generator()
+            .generate_hooks_manager_files(self.hooks_manager, &plan)
+            .await?;
+
+        if self.hooks_manager == HooksManager::Githooks {
+            return self
+                .install_committed_hooks(project_type, template, policy)
+                .await;
+        }
+
+        if self.hooks_manager == HooksManager::Native {
+            return GitHooksGenerator::new(self.target_dir.clone())
+                .with_native_runner()
+                .with_hook_kinds(&self.hook_kinds)
+                .with_written_paths(self.written.clone())
+                .with_dry_run(self.dry_run)
+                .generate_hooks(&project_type, template, self.force)
+                .await;
+        }
+
+        if !self.dry_run {
+            if let Some(hint) = self.hooks_manager.install_hint(&self.toolchain) {
+                println!(
+                    "{}",
+                    format!(
+                        "👉 Run `{}` to activate the {} hooks",
+                        hint,
+                        self.hooks_manager.as_str()
+                    )
+                    .yellow()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the raw hooks into `.githooks/` and point `core.hooksPath` at
+    /// it. Without a repository the files are still written, and teammates
+    /// wire them up with `just install-hooks`.
+    async fn install_committed_hooks(
+        &self,
+        project_type: ProjectType,
+        template: &str,
+        policy: CommitMsgPolicy,
+    ) -> Result<(), ZackstrapError> {
+        let hooks_generator = GitHooksGenerator::new(self.target_dir.clone())
+            .with_hooks_path(COMMITTED_HOOKS_DIR)
+            .with_commit_msg_policy(policy)
+            .with_hook_kinds(&self.hook_kinds)
+            .with_toolchain(self.toolchain.clone())
+            .with_written_paths(self.written.clone())
+            .with_dry_run(self.dry_run);
+        hooks_generator
+            .generate_hooks(&project_type, template, self.force)
+            .await?;
+
+        if self.dry_run {
+            println!("  Would set core.hooksPath to {}", COMMITTED_HOOKS_DIR);
+            return Ok(());
+        }
+
+        match hooks_generator.configure_hooks_path(COMMITTED_HOOKS_DIR) {
+            Ok(()) => println!(
+                "{}",
+                format!("🔗 Set core.hooksPath to {}", COMMITTED_HOOKS_DIR).green()
+            ),
+            Err(ZackstrapError::GitNotInitialized) => println!(
+                "{}",
+                "👉 Run `just install-hooks` after `git init` to activate the hooks".yellow()
+            ),
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+
     pub async fn handle_auto(&self) -> Result<(), ZackstrapError> {
         let generator = self.make_generator();
 
@@ -635,6 +882,311 @@ impl CommandHandler {
         Ok(())
     }
 
+    /// `zackstrap init`: create the git repository when there is none yet,
+    /// generate configuration files and hooks, and optionally commit them.
+    /// An existing repository keeps its branch and history.
+    pub async fn handle_init(
+        &self,
+        language: Option<ProjectType>,
+        template: Option<String>,
+        branch: &str,
+        commit_message: Option<String>,
+    ) -> Result<(), ZackstrapError> {
+        let project_type = match language {
+            Some(project_type) => project_type,
+            None => self.make_generator().detect_project_type().await?,
+        };
+
+        let existing = GitRepo::discover(&self.target_dir);
+        match &existing {
+            Some(repo) => println!(
+                "{}",
+                format!(
+                    "📂 Using the existing git repository at {}",
+                    repo.work_tree.display()
+                )
+                .blue()
+            ),
+            None if self.dry_run => println!(
+                "{}",
+                format!(
+                    "🌱 [DRY RUN] Would initialize a git repository (branch: {})",
+                    branch
+                )
+                .blue()
+            ),
+            None => {
+                GitRepo::init(&self.target_dir, branch)?;
+                println!(
+                    "{}",
+                    format!("🌱 Initialized a git repository (branch: {})", branch).green()
+                );
+            }
+        }
+
+        // Hooks cannot be previewed into a repository that does not exist yet
+        let preview_without_repo = self.dry_run && existing.is_none();
+        let handler = Self {
+            target_dir: self.target_dir.clone(),
+            force: self.force,
+            fail_on_exists: self.fail_on_exists,
+            dry_run: self.dry_run,
+            hooks: !preview_without_repo,
+            hooks_manager: self.hooks_manager,
+            hook_kinds: self.hook_kinds.clone(),
+            toolchain: self.toolchain.clone(),
+            written: self.written.clone(),
+        };
+        handler.handle_project(project_type, template).await?;
+        if preview_without_repo {
+            println!("  Would install git hooks into the new repository");
+        }
+
+        let Some(message) = commit_message else {
+            return Ok(());
+        };
+        if self.dry_run {
+            println!(
+                "{}",
+                format!("📝 [DRY RUN] Would commit the generated files: {}", message).blue()
+            );
+            return Ok(());
+        }
+        let repo = GitRepo::discover(&self.target_dir).ok_or(ZackstrapError::GitNotInitialized)?;
+        if repo.has_commits() {
+            println!(
+                "{}",
+                "⚠️  The repository already has commits; skipping the initial commit".yellow()
+            );
+        } else {
+            repo.commit_paths(&self.written.paths(), &message)?;
+            println!(
+                "{}",
+                format!("📝 Committed the generated files: {}", message).green()
+            );
+        }
+        Ok(())
+    }
+
+    async fn handle_project(
+        &self,
+        project_type: ProjectType,
+        template: Option<String>,
+    ) -> Result<(), ZackstrapError> {
+        match project_type {
+            ProjectType::Basic => self.handle_basic(template).await,
+            ProjectType::Ruby => self.handle_ruby(template).await,
+            ProjectType::Python => self.handle_python(template).await,
+            ProjectType::Node => self.handle_node(template).await,
+            ProjectType::Go => self.handle_go(template).await,
+            ProjectType::Rust => self.handle_rust(template).await,
+            ProjectType::Bash => self.handle_bash(template).await,
+        }
+    }
+
+    /// `zackstrap hooks install`: install hooks for `language`, or for the
+    /// detected project type, without generating configuration files.
+    pub async fn handle_hooks_install(
+        &self,
+        language: Option<ProjectType>,
+        template: Option<String>,
+    ) -> Result<(), ZackstrapError> {
+        let template_name = template.as_deref().unwrap_or("default");
+        let project_type = match language {
+            Some(project_type) => project_type,
+            None => self.make_generator().detect_project_type().await?,
+        };
+
+        if self.dry_run {
+            println!(
+                "{}",
+                format!(
+                    "🪝 [DRY RUN] Would install {} git hooks for {:?} project (template: {})...",
+                    self.hooks_manager.as_str(),
+                    project_type,
+                    template_name
+                )
+                .blue()
+            );
+        } else {
+            println!(
+                "{}",
+                format!(
+                    "🪝 Installing {} git hooks for {:?} project (template: {})...",
+                    self.hooks_manager.as_str(),
+                    project_type,
+                    template_name
+                )
+                .green()
+            );
+        }
+
+        self.install_hooks(project_type, template_name).await?;
+
+        if !self.dry_run {
+            println!("{}", "✅ Git hooks installed successfully!".green());
+        }
+        Ok(())
+    }
+
+    /// `zackstrap hooks status`: show who owns each git hook.
+    pub async fn handle_hooks_status(&self) -> Result<(), ZackstrapError> {
+        println!("{}", "🪝 Git hooks status:".blue());
+        let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
+        for (kind, state) in hooks_generator.hook_states().await? {
+            let hook = kind.as_str();
+            match state.status {
+                HookStatus::Missing => println!("  {} {}", "[MISSING]".dimmed(), hook),
+                HookStatus::Managed => println!("  {} {}", "[MANAGED]".green(), hook),
+                HookStatus::Modified => println!(
+                    "  {} {} (edited since zackstrap installed it)",
+                    "[MODIFIED]".yellow(),
+                    hook
+                ),
+                HookStatus::Foreign => println!(
+                    "  {} {} (not installed by zackstrap)",
+                    "[FOREIGN]".cyan(),
+                    hook
+                ),
+            }
+            if !state.chained.is_empty() {
+                println!("      also runs {}.d/{}", hook, state.chained.join(", "));
+            }
+        }
+        Ok(())
+    }
+
+    /// `zackstrap hooks run`: run an installed hook on the staged files.
+    pub fn handle_hooks_run(&self, hook: HookKind, args: &[String]) -> Result<(), ZackstrapError> {
+        if self.dry_run {
+            println!(
+                "{}",
+                format!("🪝 [DRY RUN] Would run the {} hook", hook.as_str()).blue()
+            );
+            return Ok(());
+        }
+
+        println!(
+            "{}",
+            format!("🪝 Running the {} hook...", hook.as_str()).green()
+        );
+        GitHooksGenerator::new(self.target_dir.clone()).run_hook(hook, args)?;
+        println!(
+            "{}",
+            format!("✅ The {} hook passed!", hook.as_str()).green()
+        );
+        Ok(())
+    }
+
+    /// `zackstrap hook-run`: run a hook natively, as called by the shims
+    /// `--hooks-manager native` installs.
+    pub async fn handle_hook_run(
+        &self,
+        hook: HookKind,
+        args: &[String],
+    ) -> Result<(), ZackstrapError> {
+        let manifest = Manifest::load(&self.target_dir)?;
+        let repo = GitRepo::discover(&self.target_dir).ok_or(ZackstrapError::GitNotInitialized)?;
+        let project_type = match &manifest.hooks.language {
+            Some(project_type) => project_type.clone(),
+            None => self.make_generator().detect_project_type().await?,
+        };
+
+        let passed = match hook {
+            HookKind::PreCommit | HookKind::PrePush => {
+                println!(
+                    "{}",
+                    format!(
+                        "🔍 Running {} checks for {} project...",
+                        hook.as_str(),
+                        project_type.as_str()
+                    )
+                    .blue()
+                );
+                let runner = HookRunner::new(repo, hook, &project_type, &manifest.hooks)
+                    .with_progress(MultiProgress::new());
+                let outcomes = runner.run().await?;
+                print_check_summary(&outcomes);
+                !outcomes.iter().any(CheckOutcome::failed)
+            }
+            HookKind::CommitMsg => {
+                let msg_file = match args.first() {
+                    Some(path) => PathBuf::from(path),
+                    None => repo.git_dir.join("COMMIT_EDITMSG"),
+                };
+                let message = std::fs::read_to_string(&msg_file)?;
+                let violations = manifest.commit_msg.check(&message);
+                for violation in &violations {
+                    println!("❌ {}", violation);
+                }
+                violations.is_empty()
+            }
+            _ => {
+                let script = hook_script(
+                    hook,
+                    &project_type,
+                    &manifest.commit_msg,
+                    ["$1", "$2", "$3"],
+                )
+                .unwrap_or_default();
+                std::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(script)
+                    .arg(hook.as_str())
+                    .args(args)
+                    .current_dir(&repo.work_tree)
+                    .status()?
+                    .success()
+            }
+        };
+
+        if !passed {
+            return Err(ZackstrapError::HookFailed(hook.as_str().to_string()));
+        }
+        Ok(())
+    }
+
+    pub async fn handle_uninstall_hooks(&self) -> Result<(), ZackstrapError> {
+        if self.dry_run {
+            println!(
+                "{}",
+                "🪝 [DRY RUN] Would remove zackstrap git hooks and restore previous hooks..."
+                    .blue()
+            );
+            return Ok(());
+        }
+
+        println!("{}", "🪝 Removing zackstrap git hooks...".green());
+        let hooks_generator = GitHooksGenerator::new(self.target_dir.clone());
+        for (hook, outcome) in hooks_generator.uninstall_hooks().await? {
+            match outcome {
+                UninstallOutcome::NotInstalled => {
+                    println!(
+                        "  {} {} (not installed by zackstrap)",
+                        "[SKIP]".dimmed(),
+                        hook
+                    )
+                }
+                UninstallOutcome::Removed => println!("  {} {}", "[REMOVE]".yellow(), hook),
+                UninstallOutcome::Restored => {
+                    println!(
+                        "  {} {} (previous hook restored)",
+                        "[RESTORE]".green(),
+                        hook
+                    )
+                }
+                UninstallOutcome::Kept => println!(
+                    "  {} {} (other scripts remain in {}.d/)",
+                    "[KEEP]".cyan(),
+                    hook,
+                    hook
+                ),
+            }
+        }
+        println!("{}", "✅ Git hooks uninstalled successfully!".green());
+        Ok(())
+    }
+
     pub fn handle_list(&self) {
         println!("{}", "📋 Available configuration files:".blue());
         println!("  • .editorconfig");
@@ -645,48 +1197,128 @@ impl CommandHandler {
         println!("  • package.json (Ruby projects)");
         println!("  • .python-version (Python projects)");
         println!("  • pyproject.toml (Python projects)");
-        println!("  • .flake8 (Python projects)");
-        println!("  • requirements-dev.txt (Python projects)");
+        println!("  • .flake8 (Python projects using flake8)");
+        println!("  • requirements-dev.txt (Python projects using pip)");
         println!("  • .nvmrc (Node.js projects)");
-        println!("  • .eslintrc.json (Node.js projects)");
-        println!("  • go.mod (Go projects)");
+        println!("  • eslint.config.mjs or .eslintrc.json (Node.js projects)");
+        println!("  • tsconfig.json (TypeScript Node.js projects)");
+        println!(
+            "  • vite.config.ts, tsup.config.ts, nest-cli.json, jest.config.json (framework templates)"
+        );
+        println!("  • go.mod (Go projects; an existing one is never rewritten)");
+        println!("  • go.work (Go repositories with nested modules)");
+        println!("  • cmd/, internal/ skeletons (Go web and cli templates)");
         println!("  • .golangci.yml (Go projects)");
         println!("  • rustfmt.toml (Rust projects)");
         println!("  • .clippy.toml (Rust projects)");
         println!("  • .cargo/config.toml (Rust projects)");
+        println!("  • Cargo.toml release profile (Rust projects with a manifest)");
+        println!("  • rust-toolchain.toml, deny.toml, release.toml, Cargo.toml lints (lib, workspace,\n    embedded templates)");
         println!("  • .shellcheckrc (Bash projects)");
+        println!("  • .gitignore (language projects, zackstrap-managed block)");
         println!("  • justfile (all projects)");
         println!();
         println!("🪝 Available git hooks (with --hooks flag):");
         println!("  • pre-commit - Run linters, formatters, tests before commit");
         println!("  • pre-push - Run full test suite before push");
         println!("  • commit-msg - Validate commit message format");
+        println!("  • prepare-commit-msg - Add the branch's ticket id as a trailer");
+        println!("  • post-merge, post-checkout - Reinstall dependencies after lockfile changes");
+        println!("  Managers (--hooks-manager): raw (.git/hooks or core.hooksPath),");
+        println!("  githooks (.githooks/ + core.hooksPath), pre-commit (.pre-commit-config.yaml),");
+        println!("  lefthook (lefthook.yml), husky (.husky/ + package.json prepare script),");
+        println!("  native (shims calling `zackstrap hook-run`, configured in .zackstrap.toml)");
         println!();
         println!("📋 Available templates:");
         println!("  • Basic: default, google, airbnb");
         println!("  • Ruby: default, rails, sinatra, gem");
         println!("  • Python: default, django, flask");
-        println!("  • Node.js: default, express, react");
+        println!("  • Node.js: default, express, react, typescript, library, next, vite, nest");
         println!("  • Go: default, web, cli");
-        println!("  • Rust: default, web, cli");
+        println!("  • Rust: default, web, cli, lib, workspace, embedded");
         println!("  • Bash: default, devops, cli");
         println!();
         println!("🚀 Available commands:");
         println!("  • basic - Generate basic project configs");
         println!("  • ruby - Generate Ruby project configs");
-        println!("  • python - Generate Python project configs");
-        println!("  • node - Generate Node.js project configs");
-        println!("  • go - Generate Go project configs");
-        println!("  • rust - Generate Rust project configs");
+        println!("  • python - Generate Python project configs (--package-manager, --linter)");
+        println!("  • node - Generate Node.js project configs (--typescript, --ts-strictness, --eslint-format,\n    --package-manager, --workspaces)");
+        println!("  • go - Generate Go project configs (--module, --lint-preset)");
+        println!("  • rust - Generate Rust project configs (--native-cpu, --linker, --sccache)");
         println!("  • bash - Generate Bash project configs");
         println!("  • auto - Auto-detect project type");
         println!("  • interactive - Guided setup");
         println!("  • list - Show this help");
+        println!("  • init [LANGUAGE] - git init, generate configs and hooks, optionally commit");
+        println!("  • hooks install [LANGUAGE] - Install git hooks only");
+        println!("  • hooks uninstall - Remove zackstrap hooks, restoring previous ones");
+        println!("  • hooks status - Show managed, modified and foreign hooks");
+        println!("  • hooks run HOOK - Run an installed hook on the staged files");
+        println!("  • hook-run HOOK - Run a hook's checks natively (used by native hooks)");
         println!();
         println!("⚙️  Global options:");
         println!("  • --force - Overwrite existing files");
         println!("  • --dry-run - Show what would be created");
         println!("  • --hooks - Generate git hooks for the project");
+        println!(
+            "  • --hooks-manager MANAGER - raw, githooks, pre-commit, lefthook, husky or native"
+        );
         println!("  • --target DIR - Specify target directory");
     }
 }
+
+/// Print a table of every check's result and duration, then the output of
+/// every failed check.
+fn print_check_summary(outcomes: &[CheckOutcome]) {
+    let name_width = outcomes
+        .iter()
+        .map(|o| o.check.name.chars().count())
+        .chain(std::iter::once("Check".len()))
+        .max()
+        .unwrap_or(0);
+    let result_width = "MISSING".len();
+
+    println!();
+    println!(
+        "  {:<name_width$}  {:<result_width$}  {:>8}",
+        "Check", "Result", "Time"
+    );
+    println!(
+        "  {}  {}  {}",
+        "─".repeat(name_width),
+        "─".repeat(result_width),
+        "─".repeat(8)
+    );
+    for outcome in outcomes {
+        let (result, note) = match &outcome.status {
+            CheckStatus::Passed => ("PASS".green(), String::new()),
+            CheckStatus::Failed => ("FAIL".red(), String::new()),
+            CheckStatus::Missing(program) => {
+                ("MISSING".red(), format!("  {} not found on PATH", program))
+            }
+            CheckStatus::Skipped => ("SKIP".dimmed(), "  no matching staged files".to_string()),
+        };
+        let time = match outcome.status {
+            CheckStatus::Passed | CheckStatus::Failed => {
+                format!("{:.2}s", outcome.duration.as_secs_f64())
+            }
+            _ => "-".to_string(),
+        };
+        // Pad before coloring: escape codes would count towards the width
+        let padding = " ".repeat(result_width - result.chars().count());
+        println!(
+            "  {:<name_width$}  {}{}  {:>8}{}",
+            outcome.check.name,
+            result,
+            padding,
+            time,
+            note.dimmed()
+        );
+    }
+
+    for outcome in outcomes.iter().filter(|o| o.status == CheckStatus::Failed) {
+        println!();
+        println!("{}", format!("── {} ──", outcome.check.name).red());
+        print!("{}", outcome.output);
+    }
+}
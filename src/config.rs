@@ -1,6 +1,44 @@
+use crate::error::ZackstrapError;
+use crate::generators::hooks::commit_msg::CommitMsgPolicy;
+use crate::generators::hooks::runner::HooksConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::path::Path;
+
+/// Per-project settings read from `.zackstrap.toml` in the target directory.
+pub const MANIFEST_FILE: &str = ".zackstrap.toml";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Manifest {
+    pub commit_msg: CommitMsgPolicy,
+    pub hooks: HooksConfig,
+}
+
+impl Manifest {
+    /// Load `.zackstrap.toml` from `dir`, falling back to the defaults when
+    /// the file does not exist.
+    pub fn load(dir: &Path) -> Result<Self, ZackstrapError> {
+        let path = dir.join(MANIFEST_FILE);
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let manifest: Self = toml_edit::de::from_str(&content)
+            .map_err(|e| ZackstrapError::InvalidManifest(path.clone(), e.to_string()))?;
+        manifest.commit_msg.validate().map_err(|e| {
+            ZackstrapError::InvalidManifest(path.clone(), format!("[commit-msg] {}", e))
+        })?;
+        manifest
+            .hooks
+            .validate()
+            .map_err(|e| ZackstrapError::InvalidManifest(path, format!("[hooks] {}", e)))?;
+        Ok(manifest)
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EditorConfig {
@@ -36,6 +74,25 @@ pub struct PackageJson {
     pub name: String,
     pub version: String,
     pub description: String,
+    /// Corepack's `packageManager` field, e.g. `pnpm@9.12.0`.
+    #[serde(default)]
+    pub package_manager: Option<String>,
+    /// Keeps a workspace root from being published.
+    #[serde(default)]
+    pub private: bool,
+    #[serde(default)]
+    pub workspaces: Vec<String>,
+    /// Sets `"type": "module"`.
+    #[serde(default)]
+    pub module: bool,
+    /// Publishes tsup's ESM and CommonJS builds from `dist/` through
+    /// `main`, `module`, `types`, `exports` and `files`.
+    #[serde(default)]
+    pub dual_package: bool,
+    #[serde(default)]
+    pub scripts: Vec<(String, String)>,
+    #[serde(default)]
+    pub dependencies: HashMap<String, String>,
     pub dev_dependencies: HashMap<String, String>,
 }
 
@@ -230,6 +287,13 @@ impl Default for PackageJson {
             name: "project".to_string(),
             version: "0.1.0".to_string(),
             description: "A Ruby project".to_string(),
+            package_manager: None,
+            private: false,
+            workspaces: Vec::new(),
+            module: false,
+            dual_package: false,
+            scripts: Vec::new(),
+            dependencies: HashMap::new(),
             dev_dependencies,
         }
     }
@@ -252,6 +316,7 @@ impl PackageJson {
                     deps.insert("eslint".to_string(), "^8.0.0".to_string());
                     deps
                 },
+                ..Self::default()
             },
             "sinatra" => Self {
                 name: "sinatra-project".to_string(),
@@ -266,6 +331,7 @@ impl PackageJson {
                     );
                     deps
                 },
+                ..Self::default()
             },
             "gem" => Self {
                 name: "ruby-gem".to_string(),
@@ -281,6 +347,7 @@ impl PackageJson {
                     deps.insert("rspec".to_string(), "^3.12.0".to_string());
                     deps
                 },
+                ..Self::default()
             },
             "express" => Self {
                 name: "express-project".to_string(),
@@ -300,6 +367,7 @@ impl PackageJson {
                     );
                     deps
                 },
+                ..Self::default()
             },
             "react" => Self {
                 name: "react-project".to_string(),
@@ -324,6 +392,121 @@ impl PackageJson {
                     );
                     deps
                 },
+                ..Self::default()
+            },
+            "typescript" => Self {
+                name: "node-app".to_string(),
+                version: "0.1.0".to_string(),
+                description: "A TypeScript project".to_string(),
+                dev_dependencies: {
+                    let mut deps = HashMap::new();
+                    deps.insert("prettier".to_string(), "^3.0.0".to_string());
+                    deps.insert("eslint".to_string(), "^8.0.0".to_string());
+                    deps
+                },
+                ..Self::default()
+            },
+            "library" => Self {
+                name: "node-library".to_string(),
+                version: "0.1.0".to_string(),
+                description: "A TypeScript library".to_string(),
+                module: true,
+                dual_package: true,
+                scripts: scripts(&[
+                    ("build", "tsup"),
+                    ("dev", "tsup --watch"),
+                    ("test", "vitest run"),
+                    ("lint", "eslint ."),
+                    ("typecheck", "tsc --noEmit"),
+                ]),
+                dev_dependencies: versions(&[
+                    ("prettier", "^3.0.0"),
+                    ("eslint", "^8.0.0"),
+                    ("tsup", "^8.3.0"),
+                    ("vitest", "^2.1.0"),
+                    ("@vitest/coverage-v8", "^2.1.0"),
+                ]),
+                ..Self::default()
+            },
+            "next" => Self {
+                name: "next-app".to_string(),
+                version: "0.1.0".to_string(),
+                description: "A Next.js application".to_string(),
+                private: true,
+                scripts: scripts(&[
+                    ("dev", "next dev"),
+                    ("build", "next build"),
+                    ("start", "next start"),
+                    ("lint", "eslint ."),
+                ]),
+                dependencies: versions(&[
+                    ("next", "^15.0.0"),
+                    ("react", "^19.0.0"),
+                    ("react-dom", "^19.0.0"),
+                ]),
+                dev_dependencies: versions(&[
+                    ("prettier", "^3.0.0"),
+                    ("eslint", "^8.0.0"),
+                    ("eslint-config-next", "^15.0.0"),
+                ]),
+                ..Self::default()
+            },
+            "vite" => Self {
+                name: "vite-app".to_string(),
+                version: "0.1.0".to_string(),
+                description: "A Vite React application".to_string(),
+                private: true,
+                module: true,
+                scripts: scripts(&[
+                    ("dev", "vite"),
+                    ("build", "tsc --noEmit && vite build"),
+                    ("preview", "vite preview"),
+                    ("test", "vitest run"),
+                    ("lint", "eslint ."),
+                ]),
+                dependencies: versions(&[("react", "^18.3.0"), ("react-dom", "^18.3.0")]),
+                dev_dependencies: versions(&[
+                    ("prettier", "^3.0.0"),
+                    ("eslint", "^8.0.0"),
+                    ("eslint-plugin-react", "^7.33.0"),
+                    ("eslint-plugin-react-hooks", "^4.6.0"),
+                    ("vite", "^5.4.0"),
+                    ("@vitejs/plugin-react", "^4.3.0"),
+                    ("vitest", "^2.1.0"),
+                    ("@vitest/coverage-v8", "^2.1.0"),
+                ]),
+                ..Self::default()
+            },
+            "nest" => Self {
+                name: "nest-app".to_string(),
+                version: "0.1.0".to_string(),
+                description: "A NestJS application".to_string(),
+                private: true,
+                scripts: scripts(&[
+                    ("build", "nest build"),
+                    ("dev", "nest start --watch"),
+                    ("start", "nest start"),
+                    ("start:prod", "node dist/main"),
+                    ("test", "jest"),
+                    ("lint", "eslint ."),
+                ]),
+                dependencies: versions(&[
+                    ("@nestjs/common", "^10.4.0"),
+                    ("@nestjs/core", "^10.4.0"),
+                    ("@nestjs/platform-express", "^10.4.0"),
+                    ("reflect-metadata", "^0.2.0"),
+                    ("rxjs", "^7.8.0"),
+                ]),
+                dev_dependencies: versions(&[
+                    ("prettier", "^3.0.0"),
+                    ("eslint", "^8.0.0"),
+                    ("@nestjs/cli", "^10.4.0"),
+                    ("@nestjs/testing", "^10.4.0"),
+                    ("@types/jest", "^29.5.0"),
+                    ("jest", "^29.7.0"),
+                    ("ts-jest", "^29.2.0"),
+                ]),
+                ..Self::default()
             },
             "default" => Self {
                 name: "node-app".to_string(),
@@ -335,30 +518,175 @@ impl PackageJson {
                     deps.insert("eslint".to_string(), "^8.0.0".to_string());
                     deps
                 },
+                ..Self::default()
             },
             _ => Self::default(),
         }
     }
+
+    /// Add the TypeScript compiler, the typescript-eslint parser and plugin,
+    /// and the `@types/*` packages the template's runtime needs.
+    pub fn with_typescript(mut self, template: &str) -> Self {
+        let mut deps = vec![
+            ("typescript", "^5.4.0"),
+            ("@typescript-eslint/eslint-plugin", "^6.0.0"),
+            ("@typescript-eslint/parser", "^6.0.0"),
+        ];
+        match template {
+            "react" | "vite" => {
+                deps.push(("@types/react", "^18.2.0"));
+                deps.push(("@types/react-dom", "^18.2.0"));
+            }
+            "next" => {
+                deps.push(("@types/node", "^20.0.0"));
+                deps.push(("@types/react", "^19.0.0"));
+                deps.push(("@types/react-dom", "^19.0.0"));
+            }
+            "express" | "nest" => {
+                deps.push(("@types/node", "^20.0.0"));
+                deps.push(("@types/express", "^4.17.0"));
+            }
+            _ => deps.push(("@types/node", "^20.0.0")),
+        }
+        for (name, version) in deps {
+            self.dev_dependencies
+                .insert(name.to_string(), version.to_string());
+        }
+        self
+    }
+
+    pub fn with_package_manager(mut self, field: &str) -> Self {
+        self.package_manager = Some(field.to_string());
+        self
+    }
+
+    /// Make this the private root of a workspace with packages matching
+    /// `globs`. pnpm reads its globs from `pnpm-workspace.yaml` instead.
+    pub fn with_workspaces(mut self, globs: &[&str]) -> Self {
+        self.private = true;
+        self.workspaces = globs.iter().map(|glob| glob.to_string()).collect();
+        self
+    }
+
+    /// Switch the ESLint packages to ESLint 9 and flat config: `@eslint/js`
+    /// and `globals`, plugin versions that ship flat configs, and the
+    /// `typescript-eslint` package in place of its separate parser and
+    /// plugin.
+    pub fn with_flat_eslint(mut self, typescript: bool) -> Self {
+        self.dev_dependencies
+            .remove("@typescript-eslint/eslint-plugin");
+        self.dev_dependencies.remove("@typescript-eslint/parser");
+        let mut deps = vec![
+            ("eslint", "^9.0.0"),
+            ("@eslint/js", "^9.0.0"),
+            ("globals", "^15.0.0"),
+        ];
+        if self.dev_dependencies.contains_key("eslint-plugin-react") {
+            deps.push(("eslint-plugin-react", "^7.37.0"));
+        }
+        if self
+            .dev_dependencies
+            .contains_key("eslint-plugin-react-hooks")
+        {
+            deps.push(("eslint-plugin-react-hooks", "^5.0.0"));
+        }
+        if self.dev_dependencies.contains_key("eslint-config-next") {
+            // eslint-config-next is still an eslintrc config
+            deps.push(("@eslint/eslintrc", "^3.0.0"));
+        }
+        if typescript {
+            deps.push(("typescript-eslint", "^8.0.0"));
+        }
+        for (name, version) in deps {
+            self.dev_dependencies
+                .insert(name.to_string(), version.to_string());
+        }
+        self
+    }
+}
+
+fn versions(packages: &[(&str, &str)]) -> HashMap<String, String> {
+    packages
+        .iter()
+        .map(|(name, version)| (name.to_string(), version.to_string()))
+        .collect()
+}
+
+fn scripts(scripts: &[(&str, &str)]) -> Vec<(String, String)> {
+    scripts
+        .iter()
+        .map(|(name, command)| (name.to_string(), command.to_string()))
+        .collect()
 }
 
 impl fmt::Display for PackageJson {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            r#"{{"name":"{}","version":"{}","description":"{}","devDependencies":{{"#,
-            self.name, self.version, self.description
-        )?;
+        use serde_json::{json, Map, Value};
 
-        let mut first = true;
-        for (key, value) in &self.dev_dependencies {
-            if !first {
-                write!(f, ",")?;
-            }
-            write!(f, r#""{}":"{}""#, key, value)?;
-            first = false;
-        }
+        let sorted = |deps: &HashMap<String, String>| -> Map<String, Value> {
+            let mut deps: Vec<_> = deps.iter().collect();
+            deps.sort();
+            deps.into_iter()
+                .map(|(name, version)| (name.clone(), json!(version)))
+                .collect()
+        };
 
-        write!(f, "}}}}")?;
-        Ok(())
+        let mut package = Map::new();
+        package.insert("name".into(), json!(self.name));
+        package.insert("version".into(), json!(self.version));
+        package.insert("description".into(), json!(self.description));
+        if self.private {
+            package.insert("private".into(), json!(true));
+        }
+        if self.module {
+            package.insert("type".into(), json!("module"));
+        }
+        if !self.workspaces.is_empty() {
+            package.insert("workspaces".into(), json!(self.workspaces));
+        }
+        if let Some(package_manager) = &self.package_manager {
+            package.insert("packageManager".into(), json!(package_manager));
+        }
+        if self.dual_package {
+            package.insert("main".into(), json!("./dist/index.cjs"));
+            package.insert("module".into(), json!("./dist/index.js"));
+            package.insert("types".into(), json!("./dist/index.d.ts"));
+            package.insert(
+                "exports".into(),
+                json!({
+                    ".": {
+                        "import": {
+                            "types": "./dist/index.d.ts",
+                            "default": "./dist/index.js"
+                        },
+                        "require": {
+                            "types": "./dist/index.d.cts",
+                            "default": "./dist/index.cjs"
+                        }
+                    },
+                    "./package.json": "./package.json"
+                }),
+            );
+            package.insert("files".into(), json!(["dist"]));
+        }
+        if !self.scripts.is_empty() {
+            let scripts: Map<String, Value> = self
+                .scripts
+                .iter()
+                .map(|(name, command)| (name.clone(), json!(command)))
+                .collect();
+            package.insert("scripts".into(), Value::Object(scripts));
+        }
+        if !self.dependencies.is_empty() {
+            package.insert(
+                "dependencies".into(),
+                Value::Object(sorted(&self.dependencies)),
+            );
+        }
+        package.insert(
+            "devDependencies".into(),
+            Value::Object(sorted(&self.dev_dependencies)),
+        );
+        write!(f, "{}", Value::Object(package))
     }
 }
@@ -18,9 +18,27 @@ pub enum ZackstrapError {
     #[error("File already exists and force flag not set: {0}")]
     FileExists(PathBuf),
 
+    #[error("Failed to merge into existing file {0}: {1}")]
+    MergeError(PathBuf, String),
+
     #[error("Git repository not initialized. Please run 'git init' first.")]
     GitNotInitialized,
 
+    #[error("Failed to set git config {0}")]
+    GitConfigFailed(String),
+
+    #[error("Hook {0} is not installed")]
+    HookNotInstalled(String),
+
+    #[error("Hook {0} failed")]
+    HookFailed(String),
+
+    #[error("git {0} failed: {1}")]
+    GitCommandFailed(String, String),
+
+    #[error("Invalid {0}: {1}")]
+    InvalidManifest(PathBuf, String),
+
     #[error("Failed to serialize configuration: {0}")]
     SerializationError(#[from] serde_json::Error),
 }
@@ -1,4 +1,6 @@
+use super::merge::MergeStrategy;
 use crate::error::ZackstrapError;
+use std::path::Path;
 
 impl super::ConfigGenerator {
     #[allow(dead_code)]
@@ -12,6 +14,9 @@ impl super::ConfigGenerator {
 
         // Generate Bash-specific configs
         self.generate_shellcheck_config().await?;
+        self.generate_shfmt_config().await?;
+        self.generate_bash_gitignore(template).await?;
+        self.generate_bash_skeleton(template).await?;
 
         // Overwrite the basic justfile with Bash-specific one
         self.generate_bash_justfile(template).await?;
@@ -19,6 +24,19 @@ impl super::ConfigGenerator {
         Ok(())
     }
 
+    /// Name of the generated script, from the target directory.
+    pub fn bash_script_name(&self) -> String {
+        let dir = self
+            .target_dir
+            .canonicalize()
+            .unwrap_or_else(|_| self.target_dir.clone());
+        let name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        script_name_from_dir(&name)
+    }
+
     async fn generate_shellcheck_config(&self) -> Result<(), ZackstrapError> {
         let content = r#"# ShellCheck configuration
 # See https://www.shellcheck.net/wiki/
@@ -39,6 +57,65 @@ enable=avoid-nullary-conditions
         self.emit_file(".shellcheckrc", content, false, false).await
     }
 
+    /// shfmt reads its settings from `.editorconfig` when no formatting
+    /// flags are given, so the justfile and hooks run plain `shfmt`.
+    async fn generate_shfmt_config(&self) -> Result<(), ZackstrapError> {
+        self.emit_merged_file(
+            ".editorconfig",
+            SHFMT_EDITORCONFIG,
+            MergeStrategy::ManagedBlock,
+            false,
+        )
+        .await
+    }
+
+    async fn generate_bash_gitignore(&self, template: &str) -> Result<(), ZackstrapError> {
+        let base = r#"# Logs and scratch files
+*.log
+/tmp/
+
+# OS and editor files
+.DS_Store
+Thumbs.db
+*.swp
+*~
+"#;
+        let extra = match template {
+            "devops" => "\n# Local secrets and state\n.env\n*.tfstate\n*.tfstate.backup\n",
+            _ => "",
+        };
+        let content = format!("{}{}", base, extra);
+        self.emit_merged_file(".gitignore", &content, MergeStrategy::ManagedBlock, false)
+            .await
+    }
+
+    /// `bin/` entry point, `lib/` functions and a bats test for them.
+    /// Existing scripts are never replaced.
+    async fn generate_bash_skeleton(&self, template: &str) -> Result<(), ZackstrapError> {
+        let (bin, lib, test) = match template {
+            "devops" => (DEVOPS_BIN, DEVOPS_LIB, DEVOPS_TEST),
+            "cli" => (CLI_BIN, CLI_LIB, CLI_TEST),
+            _ => (DEFAULT_BIN, DEFAULT_LIB, DEFAULT_TEST),
+        };
+        let name = self.bash_script_name();
+        let bin_path = format!("bin/{}", name);
+        let files = [
+            (bin_path.clone(), bin),
+            (format!("lib/{}.sh", name), lib),
+            (format!("test/{}.bats", name), test),
+        ];
+
+        let bin_existed = self.target_dir.join(&bin_path).exists();
+        for (path, content) in &files {
+            let content = content.replace("{name}", &name);
+            self.emit_file(path, &content, false, false).await?;
+        }
+        if !self.dry_run && (!bin_existed || self.force) {
+            make_executable(&self.target_dir.join(&bin_path))?;
+        }
+        Ok(())
+    }
+
     async fn generate_bash_justfile(&self, template: &str) -> Result<(), ZackstrapError> {
         let content = match template {
             "devops" => {
@@ -47,20 +124,24 @@ default:
     @echo "Available Bash DevOps commands:"
     @just --list
 
-# Run ShellCheck on all scripts
+# Run the script (DRY_RUN=1 only logs commands)
+run *ARGS:
+    @bash bin/{name} {{ARGS}}
+
+# Run ShellCheck on all scripts and tests
 lint:
     @echo "Running ShellCheck..."
-    @find . -name '*.sh' -not -path './vendor/*' -exec shellcheck {} +
+    @find . -type f \( -path './bin/*' -o -name '*.sh' -o -name '*.bats' \) -not -path './vendor/*' -exec shellcheck {} +
 
-# Format scripts with shfmt
+# Format scripts with shfmt (settings in .editorconfig)
 fmt:
     @echo "Formatting Bash scripts..."
-    @shfmt -w -i 2 -ci .
+    @shfmt -w .
 
 # Check formatting
 fmt-check:
     @echo "Checking Bash formatting..."
-    @shfmt -d -i 2 -ci .
+    @shfmt -d .
 
 # Run BATS tests
 test:
@@ -70,7 +151,7 @@ test:
 # Validate scripts for syntax errors
 check:
     @echo "Checking Bash syntax..."
-    @find . -name '*.sh' -not -path './vendor/*' -exec bash -n {} +
+    @find . -type f \( -path './bin/*' -o -name '*.sh' \) -not -path './vendor/*' -exec bash -n {} \;
 
 # Deploy scripts (customize as needed)
 deploy:
@@ -80,29 +161,30 @@ deploy:
             }
             "cli" => {
                 r#"# Bash CLI project justfile
+prefix := env("PREFIX", "/usr/local")
+
 default:
     @echo "Available Bash CLI commands:"
     @just --list
 
 # Run the CLI tool
 run *ARGS:
-    @echo "Running CLI tool..."
-    @bash main.sh {{ARGS}}
+    @bash bin/{name} {{ARGS}}
 
-# Run ShellCheck on all scripts
+# Run ShellCheck on all scripts and tests
 lint:
     @echo "Running ShellCheck..."
-    @find . -name '*.sh' -not -path './vendor/*' -exec shellcheck {} +
+    @find . -type f \( -path './bin/*' -o -name '*.sh' -o -name '*.bats' \) -not -path './vendor/*' -exec shellcheck {} +
 
-# Format scripts with shfmt
+# Format scripts with shfmt (settings in .editorconfig)
 fmt:
     @echo "Formatting Bash scripts..."
-    @shfmt -w -i 2 -ci .
+    @shfmt -w .
 
 # Check formatting
 fmt-check:
     @echo "Checking Bash formatting..."
-    @shfmt -d -i 2 -ci .
+    @shfmt -d .
 
 # Run BATS tests
 test:
@@ -112,12 +194,14 @@ test:
 # Validate scripts for syntax errors
 check:
     @echo "Checking Bash syntax..."
-    @find . -name '*.sh' -not -path './vendor/*' -exec bash -n {} +
+    @find . -type f \( -path './bin/*' -o -name '*.sh' \) -not -path './vendor/*' -exec bash -n {} \;
 
-# Install the CLI tool
+# Install the CLI tool and its library under PREFIX
 install:
-    @echo "Installing CLI tool..."
-    @install -m 755 main.sh /usr/local/bin/
+    @echo "Installing CLI tool to {{prefix}}..."
+    @install -d {{prefix}}/bin {{prefix}}/lib
+    @install -m 755 bin/{name} {{prefix}}/bin/{name}
+    @install -m 644 lib/{name}.sh {{prefix}}/lib/{name}.sh
 "#
             }
             _ => {
@@ -126,20 +210,24 @@ default:
     @echo "Available Bash commands:"
     @just --list
 
-# Run ShellCheck on all scripts
+# Run the script
+run *ARGS:
+    @bash bin/{name} {{ARGS}}
+
+# Run ShellCheck on all scripts and tests
 lint:
     @echo "Running ShellCheck..."
-    @find . -name '*.sh' -not -path './vendor/*' -exec shellcheck {} +
+    @find . -type f \( -path './bin/*' -o -name '*.sh' -o -name '*.bats' \) -not -path './vendor/*' -exec shellcheck {} +
 
-# Format scripts with shfmt
+# Format scripts with shfmt (settings in .editorconfig)
 fmt:
     @echo "Formatting Bash scripts..."
-    @shfmt -w -i 2 -ci .
+    @shfmt -w .
 
 # Check formatting
 fmt-check:
     @echo "Checking Bash formatting..."
-    @shfmt -d -i 2 -ci .
+    @shfmt -d .
 
 # Run BATS tests
 test:
@@ -149,10 +237,357 @@ test:
 # Validate scripts for syntax errors
 check:
     @echo "Checking Bash syntax..."
-    @find . -name '*.sh' -not -path './vendor/*' -exec bash -n {} +
+    @find . -type f \( -path './bin/*' -o -name '*.sh' \) -not -path './vendor/*' -exec bash -n {} \;
 "#
             }
         };
-        self.emit_file("justfile", content, false, true).await
+        let content = content.replace("{name}", &self.bash_script_name());
+        self.emit_file("justfile", &content, false, true).await
+    }
+}
+
+/// Script name from a directory name: lowercase, with anything but
+/// letters, digits, `-` and `_` replaced by `-`.
+fn script_name_from_dir(name: &str) -> String {
+    let name: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let name = name.trim_matches('-');
+    if name.is_empty() {
+        "app".to_string()
+    } else {
+        name.to_string()
     }
 }
+
+fn make_executable(path: &Path) -> Result<(), ZackstrapError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+/// shfmt settings. Scripts under `bin/` have no extension, and bats files
+/// need shfmt's bats dialect rather than plain bash.
+const SHFMT_EDITORCONFIG: &str = r#"[*.{sh,bash}]
+indent_style = space
+indent_size = 2
+shell_variant = bash
+binary_next_line = true
+switch_case_indent = true
+
+[bin/*]
+indent_style = space
+indent_size = 2
+shell_variant = bash
+binary_next_line = true
+switch_case_indent = true
+
+[*.bats]
+indent_style = space
+indent_size = 2
+shell_variant = bats
+binary_next_line = true
+switch_case_indent = true
+"#;
+
+const DEFAULT_BIN: &str = r#"#!/usr/bin/env bash
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+# shellcheck source=lib/{name}.sh
+source "${ROOT_DIR}/lib/{name}.sh"
+
+main() {
+  greet "$@"
+}
+
+main "$@"
+"#;
+
+const DEFAULT_LIB: &str = r#"#!/usr/bin/env bash
+# Functions for {name}. Sourced by bin/{name} and by the bats tests, so
+# keep side effects out of this file.
+
+# Print a greeting for the given name, or for the world.
+greet() {
+  local who="${1:-world}"
+  printf 'Hello, %s!\n' "${who}"
+}
+"#;
+
+const DEFAULT_TEST: &str = r#"#!/usr/bin/env bats
+
+setup() {
+  # shellcheck source=lib/{name}.sh
+  source "${BATS_TEST_DIRNAME}/../lib/{name}.sh"
+}
+
+@test "greet defaults to the world" {
+  run greet
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "Hello, world!" ]
+}
+
+@test "greet uses the given name" {
+  run greet Ada
+  [ "${output}" = "Hello, Ada!" ]
+}
+
+@test "bin/{name} passes its arguments to greet" {
+  run bash "${BATS_TEST_DIRNAME}/../bin/{name}" Ada
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "Hello, Ada!" ]
+}
+"#;
+
+const CLI_BIN: &str = r#"#!/usr/bin/env bash
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+# shellcheck source=lib/{name}.sh
+source "${ROOT_DIR}/lib/{name}.sh"
+
+main() {
+  parse_args "$@"
+  greet_all
+}
+
+main "$@"
+"#;
+
+const CLI_LIB: &str = r#"#!/usr/bin/env bash
+# Argument parsing and commands for {name}. Sourced by bin/{name} and by
+# the bats tests, so keep side effects out of this file.
+
+VERSION="0.1.0"
+
+usage() {
+  cat <<EOF
+Usage: {name} [options] [--] [NAME...]
+
+Greet each NAME, or the world.
+
+Options:
+  -g, --greeting TEXT  Greeting to use (default: Hello)
+  -q, --quiet          Print nothing
+  -h, --help           Show this help and exit
+  -V, --version        Show the version and exit
+EOF
+}
+
+# Print an error and exit with the usage status.
+die() {
+  printf '{name}: %s\n' "$*" >&2
+  printf "Try '{name} --help' for more information.\n" >&2
+  exit 2
+}
+
+# Parse options into GREETING and QUIET, and operands into ARGS.
+parse_args() {
+  GREETING="Hello"
+  QUIET=0
+  ARGS=()
+  while [[ $# -gt 0 ]]; do
+    case "$1" in
+      -g | --greeting)
+        [[ $# -ge 2 ]] || die "option '$1' needs a value"
+        GREETING="$2"
+        shift 2
+        ;;
+      --greeting=*)
+        GREETING="${1#*=}"
+        shift
+        ;;
+      -q | --quiet)
+        QUIET=1
+        shift
+        ;;
+      -h | --help)
+        usage
+        exit 0
+        ;;
+      -V | --version)
+        printf '{name} %s\n' "${VERSION}"
+        exit 0
+        ;;
+      --)
+        shift
+        ARGS+=("$@")
+        break
+        ;;
+      -?*)
+        die "unknown option '$1'"
+        ;;
+      *)
+        ARGS+=("$1")
+        shift
+        ;;
+    esac
+  done
+}
+
+# Greet each name in ARGS, or the world.
+greet_all() {
+  local who
+  if [[ ${#ARGS[@]} -eq 0 ]]; then
+    ARGS=(world)
+  fi
+  if [[ ${QUIET} -eq 1 ]]; then
+    return 0
+  fi
+  for who in "${ARGS[@]}"; do
+    printf '%s, %s!\n' "${GREETING}" "${who}"
+  done
+}
+"#;
+
+const CLI_TEST: &str = r#"#!/usr/bin/env bats
+
+setup() {
+  BIN="${BATS_TEST_DIRNAME}/../bin/{name}"
+}
+
+@test "greets the world by default" {
+  run bash "${BIN}"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "Hello, world!" ]
+}
+
+@test "greets each name with the chosen greeting" {
+  run bash "${BIN}" --greeting Hi Ada Grace
+  [ "${status}" -eq 0 ]
+  [ "${lines[0]}" = "Hi, Ada!" ]
+  [ "${lines[1]}" = "Hi, Grace!" ]
+}
+
+@test "--quiet prints nothing" {
+  run bash "${BIN}" --quiet Ada
+  [ "${status}" -eq 0 ]
+  [ -z "${output}" ]
+}
+
+@test "--help prints usage" {
+  run bash "${BIN}" --help
+  [ "${status}" -eq 0 ]
+  [[ "${output}" == Usage:* ]]
+}
+
+@test "--version prints the version" {
+  run bash "${BIN}" --version
+  [ "${status}" -eq 0 ]
+  [[ "${output}" == "{name} "* ]]
+}
+
+@test "unknown options are usage errors" {
+  run bash "${BIN}" --nope
+  [ "${status}" -eq 2 ]
+  [[ "${output}" == *"unknown option '--nope'"* ]]
+}
+
+@test "--greeting needs a value" {
+  run bash "${BIN}" --greeting
+  [ "${status}" -eq 2 ]
+}
+"#;
+
+const DEVOPS_BIN: &str = r#"#!/usr/bin/env bash
+set -Eeuo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+# shellcheck source=lib/{name}.sh
+source "${ROOT_DIR}/lib/{name}.sh"
+
+trap 'log "ERROR: line ${LINENO}: ${BASH_COMMAND}"' ERR
+
+main() {
+  require_cmd git
+  run_cmd git --version
+}
+
+main "$@"
+"#;
+
+const DEVOPS_LIB: &str = r#"#!/usr/bin/env bash
+# Logging and command helpers for {name}. Sourced by bin/{name} and by
+# the bats tests, so keep side effects out of this file.
+
+# Log a timestamped message to stderr.
+log() {
+  printf '%s [{name}] %s\n' "$(date -u +%Y-%m-%dT%H:%M:%SZ)" "$*" >&2
+}
+
+# Log an error and exit.
+die() {
+  log "ERROR: $*"
+  exit 1
+}
+
+# Fail unless every named command is on PATH.
+require_cmd() {
+  local cmd
+  for cmd in "$@"; do
+    command -v "${cmd}" >/dev/null 2>&1 || die "missing required command: ${cmd}"
+  done
+}
+
+# Run a command, or only log it when DRY_RUN=1.
+run_cmd() {
+  if [[ "${DRY_RUN:-0}" == 1 ]]; then
+    log "dry run: $*"
+    return 0
+  fi
+  log "running: $*"
+  "$@"
+}
+"#;
+
+const DEVOPS_TEST: &str = r#"#!/usr/bin/env bats
+
+setup() {
+  # shellcheck source=lib/{name}.sh
+  source "${BATS_TEST_DIRNAME}/../lib/{name}.sh"
+}
+
+@test "log writes a timestamped line" {
+  run log "hello"
+  [ "${status}" -eq 0 ]
+  [[ "${output}" == *"[{name}] hello" ]]
+}
+
+@test "die exits with an error" {
+  run die "boom"
+  [ "${status}" -eq 1 ]
+  [[ "${output}" == *"ERROR: boom" ]]
+}
+
+@test "require_cmd fails for missing commands" {
+  run require_cmd bash definitely-not-installed
+  [ "${status}" -eq 1 ]
+  [[ "${output}" == *"definitely-not-installed"* ]]
+}
+
+@test "run_cmd only logs when DRY_RUN=1" {
+  DRY_RUN=1 run run_cmd touch "${BATS_TEST_TMPDIR}/ran"
+  [ "${status}" -eq 0 ]
+  [ ! -e "${BATS_TEST_TMPDIR}/ran" ]
+}
+
+@test "run_cmd runs the command" {
+  run run_cmd touch "${BATS_TEST_TMPDIR}/ran"
+  [ "${status}" -eq 0 ]
+  [ -e "${BATS_TEST_TMPDIR}/ran" ]
+}
+"#;
@@ -1,6 +1,8 @@
+use super::merge::MergeStrategy;
 use crate::error::ZackstrapError;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 #[allow(async_fn_in_trait)]
 pub trait FileGenerator {
@@ -35,6 +37,39 @@ pub trait FileGenerator {
     }
 }
 
+/// Files written during one run, shared by its generators so that
+/// `init --commit` stages exactly what zackstrap wrote.
+#[derive(Debug, Clone, Default)]
+pub struct WrittenPaths(Arc<Mutex<Vec<PathBuf>>>);
+
+impl WrittenPaths {
+    pub(crate) fn record(&self, path: &Path) {
+        let mut paths = self.0.lock().expect("written paths lock poisoned");
+        if !paths.iter().any(|written| written == path) {
+            paths.push(path.to_path_buf());
+        }
+    }
+
+    /// The written files, in the order they were first written.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.0.lock().expect("written paths lock poisoned").clone()
+    }
+}
+
+/// Print the dry-run preview of a file: the action, its path and the
+/// content that would be written.
+pub(crate) fn preview_file(action: colored::ColoredString, path: &str, content: &str) {
+    use colored::*;
+
+    println!("  {} {}", action, path);
+    println!("  {}", "───────────────────────".dimmed());
+    for line in content.lines() {
+        println!("  {}", line.dimmed());
+    }
+    println!("  {}", "───────────────────────".dimmed());
+    println!();
+}
+
 impl FileGenerator for super::ConfigGenerator {
     fn target_dir(&self) -> &PathBuf {
         &self.target_dir
@@ -57,27 +92,73 @@ impl super::ConfigGenerator {
 
             if file_path.exists() {
                 if effective_force {
-                    println!("  {} {}", "[OVERWRITE]".yellow(), filename);
+                    preview_file("[OVERWRITE]".yellow(), filename, content);
                 } else {
                     println!("  {} {} (already exists)", "[SKIP]".dimmed(), filename);
-                    return Ok(());
                 }
             } else {
-                println!("  {} {}", "[CREATE]".green(), filename);
-            }
-
-            println!("  {}", "───────────────────────".dimmed());
-            for line in content.lines() {
-                println!("  {}", line.dimmed());
+                preview_file("[CREATE]".green(), filename, content);
             }
-            println!("  {}", "───────────────────────".dimmed());
-            println!();
 
             return Ok(());
         }
 
         let effective_force = self.force || force_override;
+        let file_path = self.target_dir.join(filename);
+        let writes = effective_force || !file_path.exists();
         self.write_file_if_not_exists(filename, content, effective_force, fail_on_exists)
-            .await
+            .await?;
+        if writes {
+            self.written.record(&file_path);
+        }
+        Ok(())
+    }
+
+    /// Like `emit_file`, but when the file already exists (and `--force` is
+    /// not set) the generated content is merged into it instead of skipping,
+    /// unless `fail_on_exists` is set.
+    pub async fn emit_merged_file(
+        &self,
+        filename: &str,
+        content: &str,
+        strategy: MergeStrategy,
+        fail_on_exists: bool,
+    ) -> Result<(), ZackstrapError> {
+        use colored::*;
+
+        let file_path = self.target_dir.join(filename);
+        if !file_path.exists() || (self.force && strategy.allows_overwrite()) {
+            let content = strategy.initial_content(content);
+            return self.emit_file(filename, &content, false, false).await;
+        }
+        if fail_on_exists && !self.force {
+            return Err(ZackstrapError::FileExists(file_path));
+        }
+
+        let existing = fs::read_to_string(&file_path)?;
+        let outcome = strategy
+            .apply(&existing, content)
+            .map_err(|e| ZackstrapError::MergeError(file_path.clone(), e))?;
+
+        for conflict in &outcome.conflicts {
+            println!("  {} {}: {}", "[CONFLICT]".yellow(), filename, conflict);
+        }
+
+        if outcome.content == existing {
+            if self.dry_run {
+                println!("  {} {} (already up to date)", "[SKIP]".dimmed(), filename);
+            }
+            return Ok(());
+        }
+
+        if self.dry_run {
+            preview_file("[MERGE]".cyan(), filename, &outcome.content);
+            return Ok(());
+        }
+
+        fs::write(&file_path, &outcome.content)
+            .map_err(|e| ZackstrapError::WriteFileError(file_path.clone(), e))?;
+        self.written.record(&file_path);
+        Ok(())
     }
 }
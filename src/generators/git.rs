@@ -0,0 +1,182 @@
+use crate::error::ZackstrapError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Message of the commit `zackstrap init --commit` creates.
+pub const INITIAL_COMMIT_MESSAGE: &str = "chore: initial commit";
+
+/// Locations of a git repository, resolved the way git itself does so that
+/// worktrees, submodules and `GIT_DIR` setups are handled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRepo {
+    /// Top-level directory of the working tree.
+    pub work_tree: PathBuf,
+    /// Per-worktree git directory (`.git`, `.git/worktrees/<name>`, or
+    /// `.git/modules/<name>` for submodules).
+    pub git_dir: PathBuf,
+    /// Directory shared by all worktrees; hooks and config live here.
+    pub common_dir: PathBuf,
+}
+
+impl GitRepo {
+    /// Find the repository containing `start`, honoring `GIT_DIR`,
+    /// `GIT_WORK_TREE` and `GIT_COMMON_DIR` like git does.
+    pub fn discover(start: &Path) -> Option<Self> {
+        let start = absolute(start);
+
+        if let Some(git_dir) = env_path("GIT_DIR") {
+            let work_tree = env_path("GIT_WORK_TREE").unwrap_or_else(|| start.clone());
+            return Some(Self::from_git_dir(work_tree, git_dir));
+        }
+
+        for dir in start.ancestors() {
+            let dot_git = dir.join(".git");
+            if dot_git.is_dir() {
+                return Some(Self::from_git_dir(dir.to_path_buf(), dot_git));
+            }
+            if dot_git.is_file() {
+                let git_dir = read_gitdir_file(&dot_git)?;
+                return Some(Self::from_git_dir(dir.to_path_buf(), git_dir));
+            }
+        }
+        None
+    }
+
+    fn from_git_dir(work_tree: PathBuf, git_dir: PathBuf) -> Self {
+        let common_dir = env_path("GIT_COMMON_DIR")
+            .or_else(|| {
+                let commondir = fs::read_to_string(git_dir.join("commondir")).ok()?;
+                Some(git_dir.join(commondir.trim()))
+            })
+            .unwrap_or_else(|| git_dir.clone());
+
+        Self {
+            work_tree: normalize(&work_tree),
+            git_dir: normalize(&git_dir),
+            common_dir: normalize(&common_dir),
+        }
+    }
+
+    /// Create a repository in `dir` whose first commit will land on
+    /// `branch`. Setting the unborn HEAD directly works on git versions
+    /// older than `git init --initial-branch`.
+    pub fn init(dir: &Path, branch: &str) -> Result<Self, ZackstrapError> {
+        git(dir, &["check-ref-format", "--branch", branch])?;
+        git(dir, &["init", "--quiet"])?;
+        git(
+            dir,
+            &["symbolic-ref", "HEAD", &format!("refs/heads/{}", branch)],
+        )?;
+        Self::discover(dir).ok_or(ZackstrapError::GitNotInitialized)
+    }
+
+    /// Default hooks directory, shared by every worktree.
+    pub fn hooks_dir(&self) -> PathBuf {
+        self.common_dir.join("hooks")
+    }
+
+    /// Whether HEAD points at a commit yet.
+    pub fn has_commits(&self) -> bool {
+        git(
+            &self.work_tree,
+            &["rev-parse", "--verify", "--quiet", "HEAD"],
+        )
+        .is_ok()
+    }
+
+    /// URL of the `origin` remote, or of the first remote when there is
+    /// no `origin`.
+    pub fn remote_url(&self) -> Option<String> {
+        let remote = match git(&self.work_tree, &["remote", "get-url", "origin"]) {
+            Ok(url) => url,
+            Err(_) => {
+                let remotes = git(&self.work_tree, &["remote"]).ok()?;
+                let first = String::from_utf8_lossy(&remotes)
+                    .lines()
+                    .next()?
+                    .to_string();
+                git(&self.work_tree, &["remote", "get-url", &first]).ok()?
+            }
+        };
+        let url = String::from_utf8_lossy(&remote).trim().to_string();
+        (!url.is_empty()).then_some(url)
+    }
+
+    /// Stage `paths` and commit them, leaving the rest of the working tree
+    /// alone. Hooks installed inside the git directory and ignored files
+    /// are not staged. The commit skips the freshly installed hooks: the
+    /// tools they call are usually not installed yet when a project is
+    /// bootstrapped.
+    pub fn commit_paths(&self, paths: &[PathBuf], message: &str) -> Result<(), ZackstrapError> {
+        let paths: Vec<String> = paths
+            .iter()
+            .map(|path| normalize(path))
+            .filter(|path| !path.starts_with(&self.git_dir) && !path.starts_with(&self.common_dir))
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect();
+        if !paths.is_empty() {
+            // Listing them first drops the ignored ones, which `git add` rejects
+            let mut args = vec![
+                "ls-files",
+                "-z",
+                "--others",
+                "--modified",
+                "--exclude-standard",
+                "--",
+            ];
+            args.extend(paths.iter().map(String::as_str));
+            let listed = git(&self.work_tree, &args)?;
+            let listed = String::from_utf8_lossy(&listed);
+            let mut args = vec!["add", "--"];
+            args.extend(listed.split('\0').filter(|path| !path.is_empty()));
+            if args.len() > 2 {
+                git(&self.work_tree, &args)?;
+            }
+        }
+        git(
+            &self.work_tree,
+            &["commit", "--quiet", "--no-verify", "-m", message],
+        )?;
+        Ok(())
+    }
+}
+
+/// Run git in `dir` and return its stdout.
+pub(crate) fn git(dir: &Path, args: &[&str]) -> Result<Vec<u8>, ZackstrapError> {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()?;
+    if !output.status.success() {
+        return Err(ZackstrapError::GitCommandFailed(
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(output.stdout)
+}
+
+/// Parse a `.git` file (`gitdir: <path>`), resolving relative paths against
+/// the file's directory.
+fn read_gitdir_file(dot_git: &Path) -> Option<PathBuf> {
+    let content = fs::read_to_string(dot_git).ok()?;
+    let target = content.trim().strip_prefix("gitdir:")?.trim();
+    let parent = dot_git.parent()?;
+    Some(parent.join(target))
+}
+
+fn env_path(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(|value| absolute(Path::new(&value)))
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Resolve `..` components so that paths like `.git/worktrees/wt/../..`
+/// compare equal to `.git`.
+fn normalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
@@ -1,4 +1,18 @@
+use super::git::GitRepo;
+use super::merge::MergeStrategy;
+use super::toolchain::GolangciPreset;
 use crate::error::ZackstrapError;
+use std::fs;
+use std::path::Path;
+
+/// Go version of new `go.mod` and `go.work` files.
+const GO_VERSION: &str = "1.22";
+
+/// Directories never searched for nested modules.
+const SKIPPED_DIRS: &[&str] = &["vendor", "testdata", "node_modules"];
+
+/// How deep below the project nested modules are looked for.
+const MODULE_SEARCH_DEPTH: usize = 3;
 
 impl super::ConfigGenerator {
     #[allow(dead_code)]
@@ -10,126 +24,258 @@ impl super::ConfigGenerator {
         // Generate basic configs first
         self.generate_basic_with_template(false, template).await?;
 
+        // A repository of several modules gets a go.work instead of a root
+        // module, unless it already has one
+        let nested = self.nested_go_modules();
+        let root_module = self.target_dir.join("go.mod").exists() || nested.is_empty();
+        let workspace = !nested.is_empty();
+
         // Generate Go-specific configs
-        self.generate_go_mod().await?;
-        self.generate_golangci_config().await?;
-        self.generate_go_gitignore().await?;
+        if root_module {
+            self.generate_go_mod().await?;
+        }
+        if workspace {
+            self.generate_go_work(root_module, &nested).await?;
+        }
+        self.generate_golangci_config(template).await?;
+        self.generate_go_gitignore(template, workspace).await?;
+        if root_module {
+            self.generate_go_skeleton(template).await?;
+        }
 
         // Overwrite the basic justfile with Go-specific one
-        self.generate_go_justfile(template).await?;
+        self.generate_go_justfile(template, workspace).await?;
 
         Ok(())
     }
 
+    /// Module path of the project: the one in an existing `go.mod`, then
+    /// `--module`, then the git remote's path (plus the project's
+    /// directory within the repository), then the directory name.
+    pub fn go_module_path(&self) -> String {
+        if let Some(module) = existing_module(&self.target_dir) {
+            return module;
+        }
+        if let Some(module) = &self.toolchain.go.module {
+            return module.clone();
+        }
+        let dir = fs::canonicalize(&self.target_dir).unwrap_or_else(|_| self.target_dir.clone());
+        let from_remote = GitRepo::discover(&dir).and_then(|repo| {
+            let module = module_path_from_remote(&repo.remote_url()?)?;
+            let subdir = dir.strip_prefix(&repo.work_tree).ok()?;
+            Some(subdir.components().fold(module, |path, part| {
+                format!("{}/{}", path, part.as_os_str().to_string_lossy())
+            }))
+        });
+        from_remote.unwrap_or_else(|| {
+            let name = dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            module_name_from_dir(&name)
+        })
+    }
+
+    /// Directories below the project with their own `go.mod`, as `./dir`
+    /// paths in the form `go.work` uses.
+    pub fn nested_go_modules(&self) -> Vec<String> {
+        let mut modules = Vec::new();
+        find_go_modules(&self.target_dir, "", 1, &mut modules);
+        modules.sort();
+        modules
+    }
+
+    /// `go.mod` for a new module. An existing one is never rewritten, even
+    /// with `--force`: its requirements are the project's own.
     async fn generate_go_mod(&self) -> Result<(), ZackstrapError> {
-        let content = r#"module myproject
+        use colored::*;
+
+        if let Some(existing) = existing_module(&self.target_dir) {
+            if let Some(module) = self
+                .toolchain
+                .go
+                .module
+                .as_ref()
+                .filter(|module| **module != existing)
+            {
+                println!(
+                    "  {} go.mod: keeping module {} (not {})",
+                    "[CONFLICT]".yellow(),
+                    existing,
+                    module
+                );
+            } else if self.dry_run {
+                println!("  {} go.mod (already exists)", "[SKIP]".dimmed());
+            }
+            return Ok(());
+        }
+
+        let content = format!(
+            "module {module}\n\ngo {version}\n",
+            module = self.go_module_path(),
+            version = GO_VERSION
+        );
+        self.emit_file("go.mod", &content, false, false).await
+    }
 
-go 1.21
+    /// `go.work` using the root module (when there is one) and every nested
+    /// module, at the newest Go version any of them needs.
+    async fn generate_go_work(
+        &self,
+        root_module: bool,
+        nested: &[String],
+    ) -> Result<(), ZackstrapError> {
+        let mut modules = Vec::new();
+        if root_module {
+            modules.push(".".to_string());
+        }
+        modules.extend(nested.iter().cloned());
+
+        let version = modules
+            .iter()
+            .filter_map(|module| {
+                let go_mod =
+                    fs::read_to_string(self.target_dir.join(module).join("go.mod")).ok()?;
+                go_directive(&go_mod)
+            })
+            .fold(GO_VERSION.to_string(), |newest, version| {
+                if version_parts(&version) > version_parts(&newest) {
+                    version
+                } else {
+                    newest
+                }
+            });
+        let uses: String = modules
+            .iter()
+            .map(|module| format!("\t{}\n", module))
+            .collect();
+        let content = format!(
+            "go {version}\n\nuse (\n{uses})\n",
+            version = version,
+            uses = uses
+        );
+        self.emit_file("go.work", &content, false, false).await
+    }
 
-require (
-	// Add your Go dependencies here
-)
-"#;
-        self.emit_file("go.mod", content, false, false).await
+    /// `cmd/` entry point and `internal/` package for the `cli` and `web`
+    /// templates, with a test so `go test ./...` has something to run.
+    async fn generate_go_skeleton(&self, template: &str) -> Result<(), ZackstrapError> {
+        let files: &[(&str, &str)] = match template {
+            "web" => &[
+                ("cmd/server/main.go", WEB_MAIN),
+                ("internal/server/server.go", WEB_SERVER),
+                ("internal/server/server_test.go", WEB_SERVER_TEST),
+            ],
+            "cli" => &[
+                ("cmd/cli/main.go", CLI_MAIN),
+                ("internal/cli/cli.go", CLI_RUN),
+                ("internal/cli/cli_test.go", CLI_RUN_TEST),
+            ],
+            _ => &[],
+        };
+        if files.is_empty() {
+            return Ok(());
+        }
+        let module = self.go_module_path();
+        for (path, content) in files {
+            let content = content.replace("{module}", &module);
+            self.emit_file(path, &content, false, false).await?;
+        }
+        Ok(())
     }
 
-    async fn generate_golangci_config(&self) -> Result<(), ZackstrapError> {
-        let content = r#"run:
-  timeout: 5m
-  modules-download-mode: readonly
+    /// golangci-lint v2 config for the template's preset. A v1 config is
+    /// left alone: merging v2 keys into it would give neither schema.
+    async fn generate_golangci_config(&self, template: &str) -> Result<(), ZackstrapError> {
+        use colored::*;
+
+        let path = self.target_dir.join(".golangci.yml");
+        if path.exists() && !self.force {
+            let existing = fs::read_to_string(&path)?;
+            let version = serde_yaml::from_str::<serde_yaml::Value>(&existing)
+                .ok()
+                .and_then(|config| match config.get("version")? {
+                    serde_yaml::Value::String(version) => Some(version.clone()),
+                    serde_yaml::Value::Number(version) => Some(version.to_string()),
+                    _ => None,
+                });
+            if version.as_deref() != Some("2") {
+                println!(
+                    "  {} .golangci.yml: v1 config kept; run `golangci-lint migrate` or regenerate with --force",
+                    "[CONFLICT]".yellow()
+                );
+                return Ok(());
+            }
+        }
+
+        let content = golangci_config(
+            self.toolchain.go.lint_preset(template),
+            template,
+            &self.go_local_prefixes(),
+        );
+        self.emit_merged_file(".golangci.yml", &content, MergeStrategy::Yaml, false)
+            .await
+    }
 
-linters:
-  enable:
-    - gofmt
-    - golint
-    - govet
-    - errcheck
-    - staticcheck
-    - gosimple
-    - ineffassign
-    - unused
-    - misspell
-    - gosec
-
-linters-settings:
-  govet:
-    check-shadowing: true
-  gocyclo:
-    min-complexity: 15
-  maligned:
-    suggest-new: true
-  dupl:
-    threshold: 100
-  goconst:
-    min-len: 2
-    min-occurrences: 3
-  misspell:
-    locale: US
-  lll:
-    line-length: 140
-
-issues:
-  exclude-rules:
-    - path: _test\.go
-      linters:
-        - gomnd
-        - dupl
-        - gocyclo
-        - goconst
-        - gosec
-"#;
-        self.emit_file(".golangci.yml", content, false, false).await
+    /// Module paths goimports groups after third-party imports: the root
+    /// module's, or every nested module's in a workspace without one.
+    fn go_local_prefixes(&self) -> Vec<String> {
+        let nested = self.nested_go_modules();
+        if nested.is_empty() || self.target_dir.join("go.mod").exists() {
+            return vec![self.go_module_path()];
+        }
+        nested
+            .iter()
+            .filter_map(|dir| existing_module(&self.target_dir.join(dir)))
+            .collect()
     }
 
-    async fn generate_go_gitignore(&self) -> Result<(), ZackstrapError> {
-        let content = r#"# Binaries for programs and plugins
+    async fn generate_go_gitignore(
+        &self,
+        template: &str,
+        workspace: bool,
+    ) -> Result<(), ZackstrapError> {
+        let base = r#"# Binaries for programs and plugins
 *.exe
 *.exe~
 *.dll
 *.so
 *.dylib
+/bin/
 
 # Test binary, built with `go test -c`
 *.test
 
-# Output of the go coverage tool, specifically when used with LiteIDE
+# Output of the go coverage tool
 *.out
+coverage.html
 
-# Dependency directories (remove the comment below to include it)
-# vendor/
-
-# Go workspace file
-go.work
-
-# Go build cache
-go-build/
-
-# Go test cache
-go-test/
-
-# Go module cache
-go-mod-cache/
-
-# IDE files
-.vscode/
-.idea/
-*.swp
-*.swo
-*~
-
-# OS generated files
+# OS and editor files
 .DS_Store
-.DS_Store?
-._*
-.Spotlight-V100
-.Trashes
-ehthumbs.db
 Thumbs.db
+*.swp
+*~
 "#;
-        self.emit_file(".gitignore", content, false, false).await
+        let extra = match template {
+            "web" => "\n# Local environment\n.env\n",
+            _ => "",
+        };
+        // A generated go.work is shared; otherwise it is a local override
+        let work = if workspace {
+            ""
+        } else {
+            "\n# Local Go workspace file\ngo.work\ngo.work.sum\n"
+        };
+        let content = format!("{}{}{}", base, extra, work);
+        self.emit_merged_file(".gitignore", &content, MergeStrategy::ManagedBlock, false)
+            .await
     }
 
-    async fn generate_go_justfile(&self, template: &str) -> Result<(), ZackstrapError> {
+    async fn generate_go_justfile(
+        &self,
+        template: &str,
+        workspace: bool,
+    ) -> Result<(), ZackstrapError> {
         let content = match template {
             "web" => {
                 r#"# Go web project justfile
@@ -163,10 +309,10 @@ lint:
     @echo "Running golangci-lint..."
     @golangci-lint run
 
-# Format code
+# Format code with the formatters in .golangci.yml
 fmt:
     @echo "Formatting Go code..."
-    @go fmt ./...
+    @golangci-lint fmt
 
 # Install dependencies
 install:
@@ -207,10 +353,10 @@ lint:
     @echo "Running golangci-lint..."
     @golangci-lint run
 
-# Format code
+# Format code with the formatters in .golangci.yml
 fmt:
     @echo "Formatting Go code..."
-    @go fmt ./...
+    @golangci-lint fmt
 
 # Install dependencies
 install-deps:
@@ -251,10 +397,10 @@ lint:
     @echo "Running golangci-lint..."
     @golangci-lint run
 
-# Format code
+# Format code with the formatters in .golangci.yml
 fmt:
     @echo "Formatting Go code..."
-    @go fmt ./...
+    @golangci-lint fmt
 
 # Install dependencies
 install:
@@ -264,6 +410,402 @@ install:
 "#
             }
         };
+        if workspace {
+            let content = format!("{}{}", content, WORKSPACE_RECIPES);
+            return self.emit_file("justfile", &content, false, true).await;
+        }
         self.emit_file("justfile", content, false, true).await
     }
 }
+
+/// `.golangci.yml` in the v2 schema. Generated code is excluded from both
+/// linters and formatters.
+fn golangci_config(preset: GolangciPreset, template: &str, local_prefixes: &[String]) -> String {
+    let (default, mut linters): (&str, Vec<&str>) = match preset {
+        GolangciPreset::Minimal => ("standard", Vec::new()),
+        GolangciPreset::Standard => (
+            "standard",
+            vec![
+                "bodyclose",
+                "errorlint",
+                "gosec",
+                "misspell",
+                "revive",
+                "unconvert",
+            ],
+        ),
+        GolangciPreset::Strict => (
+            "standard",
+            vec![
+                "bodyclose",
+                "dupl",
+                "errorlint",
+                "exhaustive",
+                "gocognit",
+                "goconst",
+                "gocritic",
+                "gocyclo",
+                "gosec",
+                "lll",
+                "misspell",
+                "mnd",
+                "noctx",
+                "nolintlint",
+                "prealloc",
+                "revive",
+                "unconvert",
+                "unparam",
+            ],
+        ),
+    };
+    // HTTP requests without a context cannot be cancelled
+    if template == "web" && preset != GolangciPreset::Minimal && !linters.contains(&"noctx") {
+        linters.push("noctx");
+        linters.sort_unstable();
+    }
+
+    let mut settings = String::from(
+        r#"    govet:
+      enable:
+        - shadow
+"#,
+    );
+    if preset != GolangciPreset::Minimal {
+        settings.push_str(
+            r#"    misspell:
+      locale: US
+"#,
+        );
+    }
+    let mut test_excludes = vec!["gosec"];
+    if preset == GolangciPreset::Strict {
+        settings.push_str(
+            r#"    dupl:
+      threshold: 100
+    gocognit:
+      min-complexity: 20
+    goconst:
+      min-len: 2
+      min-occurrences: 3
+    gocyclo:
+      min-complexity: 15
+    lll:
+      line-length: 140
+    nolintlint:
+      require-explanation: true
+      require-specific: true
+"#,
+        );
+        test_excludes = vec![
+            "dupl", "gocognit", "goconst", "gocyclo", "gosec", "lll", "mnd",
+        ];
+    }
+
+    let enable = if linters.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "  enable:\n{}",
+            linters
+                .iter()
+                .map(|linter| format!("    - {}\n", linter))
+                .collect::<String>()
+        )
+    };
+    let test_rule = if preset == GolangciPreset::Minimal {
+        String::new()
+    } else {
+        format!(
+            "    rules:\n      - path: _test\\.go\n        linters:\n{}",
+            test_excludes
+                .iter()
+                .map(|linter| format!("          - {}\n", linter))
+                .collect::<String>()
+        )
+    };
+    let formatters = match preset {
+        GolangciPreset::Minimal => "    - goimports\n",
+        _ => "    - gofumpt\n    - goimports\n",
+    };
+    let gofumpt = match preset {
+        GolangciPreset::Minimal => "",
+        GolangciPreset::Standard => "    gofumpt:\n      extra-rules: false\n",
+        GolangciPreset::Strict => "    gofumpt:\n      extra-rules: true\n",
+    };
+    let goimports = if local_prefixes.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "    goimports:\n      local-prefixes:\n{}",
+            local_prefixes
+                .iter()
+                .map(|prefix| format!("        - {}\n", prefix))
+                .collect::<String>()
+        )
+    };
+
+    let formatter_settings = if gofumpt.is_empty() && goimports.is_empty() {
+        String::new()
+    } else {
+        format!("  settings:\n{}{}", gofumpt, goimports)
+    };
+
+    format!(
+        r#"# golangci-lint configuration ({preset} preset)
+version: "2"
+
+run:
+  timeout: 5m
+  modules-download-mode: readonly
+
+linters:
+  default: {default}
+{enable}  settings:
+{settings}  exclusions:
+    generated: lax
+    presets:
+      - comments
+      - std-error-handling
+{test_rule}    paths:
+      - third_party$
+      - builtin$
+      - examples$
+
+formatters:
+  enable:
+{formatters}{formatter_settings}  exclusions:
+    generated: lax
+    paths:
+      - third_party$
+      - builtin$
+      - examples$
+"#,
+        preset = preset.as_str(),
+        default = default,
+        enable = enable,
+        settings = settings,
+        test_rule = test_rule,
+        formatters = formatters,
+        formatter_settings = formatter_settings,
+    )
+}
+
+/// Module path declared by `dir/go.mod`.
+fn existing_module(dir: &Path) -> Option<String> {
+    let go_mod = fs::read_to_string(dir.join("go.mod")).ok()?;
+    go_mod.lines().find_map(|line| {
+        let module = line.trim().strip_prefix("module")?.trim();
+        let module = module.split("//").next()?.trim().trim_matches('"');
+        (!module.is_empty()).then(|| module.to_string())
+    })
+}
+
+/// Version in a `go` directive, such as `1.22` or `1.22.3`.
+fn go_directive(go_mod: &str) -> Option<String> {
+    go_mod.lines().find_map(|line| {
+        let version = line.trim().strip_prefix("go ")?.trim();
+        Some(version.to_string())
+    })
+}
+
+fn version_parts(version: &str) -> Vec<u32> {
+    version
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+fn find_go_modules(dir: &Path, prefix: &str, depth: usize, modules: &mut Vec<String>) {
+    if depth > MODULE_SEARCH_DEPTH {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') || SKIPPED_DIRS.contains(&name.as_str()) {
+            continue;
+        }
+        if !entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+            continue;
+        }
+        let path = format!("{}/{}", prefix, name);
+        if entry.path().join("go.mod").exists() {
+            modules.push(format!(".{}", path));
+        }
+        find_go_modules(&entry.path(), &path, depth + 1, modules);
+    }
+}
+
+/// Module path for a git remote URL: `host/path` without `.git`, for
+/// HTTPS, `ssh://` and scp-style (`git@host:path`) URLs. Local paths have
+/// no host and give `None`.
+pub fn module_path_from_remote(url: &str) -> Option<String> {
+    let url = url.trim().trim_end_matches('/');
+    let url = url.strip_suffix(".git").unwrap_or(url);
+    let (authority, path) = match url.split_once("://") {
+        Some((_, rest)) => rest.split_once('/')?,
+        None => url.split_once(':')?,
+    };
+    let host = authority.rsplit('@').next()?;
+    // Module paths have no port
+    let host = host.split(':').next()?.to_lowercase();
+    let path = path.trim_matches('/');
+    if host.is_empty() || path.is_empty() || host.contains(['/', '\\']) {
+        return None;
+    }
+    Some(format!("{}/{}", host, path))
+}
+
+/// Module path from a directory name: lowercase, with characters Go does
+/// not allow in module paths replaced by `-`.
+fn module_name_from_dir(name: &str) -> String {
+    let name: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '~') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let name = name.trim_matches(|c| c == '.' || c == '-');
+    if name.is_empty() {
+        "app".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+const WORKSPACE_RECIPES: &str = r#"
+# Run tests in every workspace module
+test-all:
+    @echo "Running Go tests in every module..."
+    @go list -f '{{{{.Dir}}}}/...' -m | xargs go test
+
+# Sync workspace dependencies back to each module
+work-sync:
+    @echo "Syncing go.work..."
+    @go work sync
+"#;
+
+const WEB_MAIN: &str = r#"package main
+
+import (
+	"log"
+	"net/http"
+	"os"
+	"time"
+
+	"{module}/internal/server"
+)
+
+// Slow clients must not hold connections open forever.
+const readHeaderTimeout = 10 * time.Second
+
+func main() {
+	addr := ":8080"
+	if port := os.Getenv("PORT"); port != "" {
+		addr = ":" + port
+	}
+	srv := &http.Server{
+		Addr:              addr,
+		Handler:           server.New(),
+		ReadHeaderTimeout: readHeaderTimeout,
+	}
+	log.Printf("listening on %s", addr)
+	log.Fatal(srv.ListenAndServe())
+}
+"#;
+
+const WEB_SERVER: &str = r#"// Package server holds the HTTP handlers.
+package server
+
+import "net/http"
+
+// New returns the application's HTTP handler.
+func New() http.Handler {
+	mux := http.NewServeMux()
+	mux.HandleFunc("GET /healthz", func(w http.ResponseWriter, _ *http.Request) {
+		w.WriteHeader(http.StatusOK)
+		_, _ = w.Write([]byte("ok"))
+	})
+	return mux
+}
+"#;
+
+const WEB_SERVER_TEST: &str = r#"package server
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+)
+
+func TestHealthz(t *testing.T) {
+	rec := httptest.NewRecorder()
+	New().ServeHTTP(rec, httptest.NewRequest(http.MethodGet, "/healthz", nil))
+	if rec.Code != http.StatusOK {
+		t.Fatalf("GET /healthz = %d, want %d", rec.Code, http.StatusOK)
+	}
+}
+"#;
+
+const CLI_MAIN: &str = r#"package main
+
+import (
+	"fmt"
+	"os"
+
+	"{module}/internal/cli"
+)
+
+func main() {
+	if err := cli.Run(os.Args[1:], os.Stdout); err != nil {
+		fmt.Fprintln(os.Stderr, err)
+		os.Exit(1)
+	}
+}
+"#;
+
+const CLI_RUN: &str = r#"// Package cli implements the command line interface.
+package cli
+
+import (
+	"flag"
+	"fmt"
+	"io"
+)
+
+// Run parses args and writes the command's output to out.
+func Run(args []string, out io.Writer) error {
+	flags := flag.NewFlagSet("cli", flag.ContinueOnError)
+	name := flags.String("name", "world", "who to greet")
+	if err := flags.Parse(args); err != nil {
+		return err
+	}
+	_, err := fmt.Fprintf(out, "Hello, %s!\n", *name)
+	return err
+}
+"#;
+
+const CLI_RUN_TEST: &str = r#"package cli
+
+import (
+	"bytes"
+	"testing"
+)
+
+func TestRun(t *testing.T) {
+	var out bytes.Buffer
+	if err := Run([]string{"-name", "gopher"}, &out); err != nil {
+		t.Fatal(err)
+	}
+	if got, want := out.String(), "Hello, gopher!\n"; got != want {
+		t.Errorf("Run() wrote %q, want %q", got, want)
+	}
+}
+"#;
@@ -0,0 +1,352 @@
+//! Hook chaining: each installed hook is a small dispatcher that runs every
+//! script in `<hook>.d/` in order, so zackstrap's checks coexist with hooks
+//! installed by other tools instead of refusing or clobbering them.
+
+use crate::error::ZackstrapError;
+use crate::generators::common::preview_file;
+use colored::*;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// Marker line identifying a dispatcher written by zackstrap.
+pub const DISPATCHER_MARKER: &str = "# zackstrap hook dispatcher";
+/// Name of zackstrap's own script inside `<hook>.d/`.
+pub const ZACKSTRAP_SCRIPT: &str = "50-zackstrap";
+/// Name given to a hook that existed before zackstrap was installed.
+pub const LEGACY_SCRIPT: &str = "00-legacy";
+/// Prefix of the line recording the checksum of zackstrap's script, used to
+/// tell whether it was edited after it was generated.
+pub const CHECKSUM_PREFIX: &str = "# zackstrap-checksum: ";
+
+/// What happened to a hook during uninstall.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UninstallOutcome {
+    /// The hook was not installed by zackstrap.
+    NotInstalled,
+    /// zackstrap's script and dispatcher were removed.
+    Removed,
+    /// The pre-existing hook was moved back into place.
+    Restored,
+    /// Other scripts remain in `<hook>.d/`, so the dispatcher was kept.
+    Kept,
+}
+
+/// Whether a hook is zackstrap's, as reported by `zackstrap hooks status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookStatus {
+    /// No hook is installed.
+    Missing,
+    /// zackstrap's script is installed as it was generated.
+    Managed,
+    /// zackstrap's script was edited after it was generated.
+    Modified,
+    /// The hook was installed by something other than zackstrap.
+    Foreign,
+}
+
+/// The state of one installed hook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookState {
+    pub status: HookStatus,
+    /// Other scripts chained in `<hook>.d/`, such as a moved legacy hook.
+    pub chained: Vec<String>,
+}
+
+/// The dispatcher installed as `<hook>`. Hooks that git feeds on stdin
+/// (such as `pre-push`) get the same input replayed to every script.
+pub fn dispatcher_script() -> String {
+    format!(
+        r#"#!/bin/bash
+{marker}
+# Runs every executable in "$(basename "$0").d/" in order, stopping at the
+# first failure. Add your own scripts there instead of editing this file.
+set -e
+
+hook_dir="$(dirname "$0")/$(basename "$0").d"
+[ -d "$hook_dir" ] || exit 0
+
+# Only these hooks receive input on stdin from git
+input=""
+case "$(basename "$0")" in
+    pre-push | pre-receive | post-receive | post-rewrite | reference-transaction)
+        input="$(cat)"
+        ;;
+esac
+
+for script in "$hook_dir"/*; do
+    [ -f "$script" ] && [ -x "$script" ] || continue
+    if [ -n "$input" ]; then
+        printf '%s\n' "$input" | "$script" "$@"
+    else
+        "$script" "$@" </dev/null
+    fi
+done
+"#,
+        marker = DISPATCHER_MARKER
+    )
+}
+
+fn chain_dir(hook_path: &Path) -> PathBuf {
+    let mut name = hook_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".d");
+    hook_path.with_file_name(name)
+}
+
+/// Where [`install_chained_hook`] puts zackstrap's script for `hook_path`.
+pub(crate) fn script_path(hook_path: &Path) -> PathBuf {
+    chain_dir(hook_path).join(ZACKSTRAP_SCRIPT)
+}
+
+/// Whether `hook_path` is a dispatcher written by zackstrap.
+pub async fn is_dispatcher(hook_path: &Path) -> bool {
+    match fs::read_to_string(hook_path).await {
+        Ok(content) => content.lines().take(3).any(|l| l == DISPATCHER_MARKER),
+        Err(_) => false,
+    }
+}
+
+/// Install `content` as zackstrap's script for the hook at `hook_path`.
+///
+/// A foreign hook already at `hook_path` is moved to `<hook>.d/00-legacy`
+/// and keeps running. zackstrap's own script is only replaced with `force`.
+pub async fn install_chained_hook(
+    hook_path: &Path,
+    content: &str,
+    force: bool,
+) -> Result<(), ZackstrapError> {
+    let chain_dir = chain_dir(hook_path);
+    let script_path = chain_dir.join(ZACKSTRAP_SCRIPT);
+    if script_path.exists() && !force {
+        return Err(ZackstrapError::FileExists(script_path));
+    }
+
+    fs::create_dir_all(&chain_dir).await?;
+
+    let existing = fs::symlink_metadata(hook_path).await.is_ok();
+    if existing && !is_dispatcher(hook_path).await {
+        move_legacy_hook(hook_path, &chain_dir).await?;
+    }
+
+    write_executable(&script_path, &with_checksum(content)).await?;
+    write_executable(hook_path, &dispatcher_script()).await
+}
+
+/// Print what [`install_chained_hook`] would do without touching the hooks
+/// directory, with paths shown relative to `root`.
+pub async fn preview_chained_hook(hook_path: &Path, content: &str, force: bool, root: &Path) {
+    let show = |path: &Path| {
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+    let chain_dir = chain_dir(hook_path);
+    let script_path = chain_dir.join(ZACKSTRAP_SCRIPT);
+
+    if !script_path.exists() {
+        preview_file(
+            "[CREATE]".green(),
+            &show(&script_path),
+            &with_checksum(content),
+        );
+    } else if force {
+        preview_file(
+            "[OVERWRITE]".yellow(),
+            &show(&script_path),
+            &with_checksum(content),
+        );
+    } else {
+        println!(
+            "  {} {} (already installed; use --force to replace it)",
+            "[CONFLICT]".red(),
+            show(&script_path)
+        );
+        return;
+    }
+
+    if is_dispatcher(hook_path).await {
+        return;
+    }
+    if fs::symlink_metadata(hook_path).await.is_ok() {
+        println!(
+            "  {} {} (existing hook, kept and chained as {})",
+            "[CONFLICT]".yellow(),
+            show(hook_path),
+            show(&next_legacy_path(&chain_dir).await)
+        );
+    }
+    let hook = hook_path.file_name().unwrap_or_default().to_string_lossy();
+    println!(
+        "  {} {} (dispatcher running {}.d/*)",
+        "[CREATE]".green(),
+        show(hook_path),
+        hook
+    );
+    println!();
+}
+
+/// Inspect the hook at `hook_path`.
+pub async fn hook_state(hook_path: &Path) -> Result<HookState, ZackstrapError> {
+    if fs::symlink_metadata(hook_path).await.is_err() {
+        return Ok(HookState {
+            status: HookStatus::Missing,
+            chained: Vec::new(),
+        });
+    }
+    if !is_dispatcher(hook_path).await {
+        return Ok(HookState {
+            status: HookStatus::Foreign,
+            chained: Vec::new(),
+        });
+    }
+
+    let chain_dir = chain_dir(hook_path);
+    let mut chained = chained_scripts(&chain_dir).await?;
+    let status = match chained.iter().position(|name| name == ZACKSTRAP_SCRIPT) {
+        Some(index) => {
+            chained.remove(index);
+            let content = fs::read_to_string(chain_dir.join(ZACKSTRAP_SCRIPT)).await?;
+            if checksum_matches(&content) {
+                HookStatus::Managed
+            } else {
+                HookStatus::Modified
+            }
+        }
+        // The dispatcher only runs other tools' scripts
+        None => HookStatus::Foreign,
+    };
+    Ok(HookState { status, chained })
+}
+
+/// Add a checksum line after the shebang of `content`.
+fn with_checksum(content: &str) -> String {
+    let line = format!("{}{:016x}\n", CHECKSUM_PREFIX, checksum(content));
+    match content.split_once('\n') {
+        Some((shebang, rest)) if shebang.starts_with("#!") => {
+            format!("{}\n{}{}", shebang, line, rest)
+        }
+        _ => format!("{}{}", line, content),
+    }
+}
+
+/// Whether a script still matches the checksum it was written with. A
+/// script without one cannot be verified and counts as modified.
+fn checksum_matches(content: &str) -> bool {
+    let mut recorded = None;
+    let mut original = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        match line.strip_prefix(CHECKSUM_PREFIX) {
+            Some(value) if recorded.is_none() => recorded = Some(value.trim().to_string()),
+            _ => original.push_str(line),
+        }
+    }
+    recorded.is_some_and(|value| value == format!("{:016x}", checksum(&original)))
+}
+
+/// 64-bit FNV-1a, which is stable across Rust releases unlike `std`'s
+/// default hasher.
+fn checksum(content: &str) -> u64 {
+    content.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+async fn chained_scripts(chain_dir: &Path) -> Result<Vec<String>, ZackstrapError> {
+    let mut names = Vec::new();
+    if chain_dir.is_dir() {
+        let mut entries = fs::read_dir(chain_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Remove zackstrap's script from the hook at `hook_path`, restoring the
+/// pre-existing hook when it is the only one left.
+pub async fn uninstall_chained_hook(hook_path: &Path) -> Result<UninstallOutcome, ZackstrapError> {
+    if !is_dispatcher(hook_path).await {
+        return Ok(UninstallOutcome::NotInstalled);
+    }
+
+    let chain_dir = chain_dir(hook_path);
+    let script_path = chain_dir.join(ZACKSTRAP_SCRIPT);
+    if script_path.exists() {
+        fs::remove_file(&script_path).await?;
+    }
+
+    let mut remaining = Vec::new();
+    if chain_dir.is_dir() {
+        let mut entries = fs::read_dir(&chain_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            remaining.push(entry.file_name());
+        }
+    }
+
+    match remaining.as_slice() {
+        [] => {
+            fs::remove_file(hook_path).await?;
+            if chain_dir.is_dir() {
+                fs::remove_dir(&chain_dir).await?;
+            }
+            Ok(UninstallOutcome::Removed)
+        }
+        [only] if only == LEGACY_SCRIPT => {
+            fs::rename(chain_dir.join(LEGACY_SCRIPT), hook_path).await?;
+            fs::remove_dir(&chain_dir).await?;
+            Ok(UninstallOutcome::Restored)
+        }
+        _ => Ok(UninstallOutcome::Kept),
+    }
+}
+
+/// Move a foreign hook into the chain directory under the first free
+/// `NN-legacy` name, keeping relative symlinks pointing at the same file.
+async fn move_legacy_hook(hook_path: &Path, chain_dir: &Path) -> Result<(), ZackstrapError> {
+    let target = next_legacy_path(chain_dir).await;
+
+    #[cfg(unix)]
+    if fs::symlink_metadata(hook_path)
+        .await?
+        .file_type()
+        .is_symlink()
+    {
+        let link = fs::read_link(hook_path).await?;
+        let link = match hook_path.parent() {
+            Some(parent) if link.is_relative() => parent.join(link),
+            _ => link,
+        };
+        fs::symlink(link, &target).await?;
+        fs::remove_file(hook_path).await?;
+        return Ok(());
+    }
+
+    fs::rename(hook_path, &target).await?;
+    Ok(())
+}
+
+async fn next_legacy_path(chain_dir: &Path) -> PathBuf {
+    let mut target = chain_dir.join(LEGACY_SCRIPT);
+    let mut n = 1;
+    while fs::symlink_metadata(&target).await.is_ok() {
+        target = chain_dir.join(format!("{:02}-legacy", n));
+        n += 1;
+    }
+    target
+}
+
+async fn write_executable(path: &Path, content: &str) -> Result<(), ZackstrapError> {
+    fs::write(path, content)
+        .await
+        .map_err(|e| ZackstrapError::WriteFileError(path.to_path_buf(), e))?;
+
+    // Make the hook executable
+    #[cfg(unix)]
+    {
+        use std::process::Command;
+        let _ = Command::new("chmod").arg("+x").arg(path).output();
+    }
+
+    Ok(())
+}
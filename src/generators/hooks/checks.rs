@@ -0,0 +1,706 @@
+use crate::generators::toolchain::{NodeToolchain, PythonLinter, PythonToolchain, Toolchain};
+use crate::generators::ProjectType;
+use std::borrow::Cow;
+
+/// Git hooks zackstrap knows how to install.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum HookKind {
+    PreCommit,
+    PrePush,
+    CommitMsg,
+    /// Adds a `Refs:` trailer with the ticket id from the branch name
+    PrepareCommitMsg,
+    /// Reinstalls dependencies when a lockfile changed in the merge
+    PostMerge,
+    /// Reinstalls dependencies when a lockfile differs after switching
+    /// branches
+    PostCheckout,
+}
+
+impl HookKind {
+    /// Every hook zackstrap can install.
+    pub const ALL: [HookKind; 6] = [
+        HookKind::PreCommit,
+        HookKind::PrePush,
+        HookKind::CommitMsg,
+        HookKind::PrepareCommitMsg,
+        HookKind::PostMerge,
+        HookKind::PostCheckout,
+    ];
+
+    /// Hooks installed when `--hooks` is given without a list.
+    pub const DEFAULT: [HookKind; 3] =
+        [HookKind::PreCommit, HookKind::PrePush, HookKind::CommitMsg];
+
+    /// The hook's file name inside a hooks directory (and its stage name in
+    /// pre-commit, lefthook and husky).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookKind::PreCommit => "pre-commit",
+            HookKind::PrePush => "pre-push",
+            HookKind::CommitMsg => "commit-msg",
+            HookKind::PrepareCommitMsg => "prepare-commit-msg",
+            HookKind::PostMerge => "post-merge",
+            HookKind::PostCheckout => "post-checkout",
+        }
+    }
+}
+
+/// Placeholder in a check's command replaced by the staged files it runs on.
+pub const FILES_PLACEHOLDER: &str = "{files}";
+
+/// A single command run by a hook, shared by every hooks manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookCheck {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub command: Cow<'static, str>,
+    pub hook: HookKind,
+    /// File extensions the check cares about. When set, the check only runs
+    /// if matching files are staged, and `{files}` in the command expands
+    /// to those files. Empty means the check always runs.
+    pub extensions: &'static [&'static str],
+    /// Other staged files the check cares about, like `extensions`: a file
+    /// name (`Cargo.toml`) matches at any depth, and a directory ending in
+    /// `/` (`bin/`) matches everything under it at the repository root.
+    pub paths: &'static [&'static str],
+    /// The check rewrites files other checks read (`go mod tidy`), so the
+    /// native runner runs it on its own before the others.
+    pub serial: bool,
+}
+
+impl HookCheck {
+    const fn new(
+        id: &'static str,
+        name: &'static str,
+        command: &'static str,
+        hook: HookKind,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            command: Cow::Borrowed(command),
+            hook,
+            extensions: &[],
+            paths: &[],
+            serial: false,
+        }
+    }
+
+    /// Like [`new`](Self::new), for commands that depend on the toolchain.
+    fn owned(id: &'static str, name: &'static str, command: String, hook: HookKind) -> Self {
+        Self {
+            id,
+            name,
+            command: Cow::Owned(command),
+            hook,
+            extensions: &[],
+            paths: &[],
+            serial: false,
+        }
+    }
+
+    /// Restrict the check to staged files with these extensions.
+    const fn on_staged(mut self, extensions: &'static [&'static str]) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Also run the check for these staged files (see [`paths`](Self::paths)).
+    const fn or_paths(mut self, paths: &'static [&'static str]) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Run the check alone, before the others (see [`serial`](Self::serial)).
+    const fn serial(mut self) -> Self {
+        self.serial = true;
+        self
+    }
+
+    /// Whether the command takes the staged files as arguments.
+    pub fn takes_files(&self) -> bool {
+        self.command.contains(FILES_PLACEHOLDER)
+    }
+
+    /// Extended regex matching the check's files, e.g. `\.(py|pyi)$` or
+    /// `\.(sh|bash)$|^bin/`.
+    pub fn files_regex(&self) -> Option<String> {
+        let mut patterns = Vec::new();
+        match self.extensions {
+            [] => {}
+            [ext] => patterns.push(format!("\\.{}$", ext)),
+            exts => patterns.push(format!("\\.({})$", exts.join("|"))),
+        }
+        for path in self.paths {
+            let escaped = path.replace('.', "\\.");
+            patterns.push(if path.ends_with('/') {
+                format!("^{}", escaped)
+            } else {
+                format!("(^|/){}$", escaped)
+            });
+        }
+        (!patterns.is_empty()).then(|| patterns.join("|"))
+    }
+
+    /// Glob matching the check's files, e.g. `*.{py,pyi}` or
+    /// `{*.sh,*.bash,bin/*}`.
+    pub fn files_glob(&self) -> Option<String> {
+        if self.paths.is_empty() {
+            return match self.extensions {
+                [] => None,
+                [ext] => Some(format!("*.{}", ext)),
+                exts => Some(format!("*.{{{}}}", exts.join(","))),
+            };
+        }
+        let mut globs: Vec<String> = self
+            .extensions
+            .iter()
+            .map(|ext| format!("*.{}", ext))
+            .collect();
+        for path in self.paths {
+            match path.strip_suffix('/') {
+                Some(dir) => globs.push(format!("{}/*", dir)),
+                None => globs.extend([path.to_string(), format!("**/{}", path)]),
+            }
+        }
+        Some(format!("{{{}}}", globs.join(",")))
+    }
+
+    /// The command with `{files}` replaced by `files`.
+    pub fn command_with_files(&self, files: &str) -> String {
+        self.command.replace(FILES_PLACEHOLDER, files)
+    }
+}
+
+const RUBY_EXTENSIONS: &[&str] = &["rb", "rake", "ru", "gemspec"];
+const PYTHON_EXTENSIONS: &[&str] = &["py", "pyi"];
+const JS_EXTENSIONS: &[&str] = &["js", "jsx", "mjs", "cjs", "ts", "tsx"];
+const PRETTIER_EXTENSIONS: &[&str] = &[
+    "js", "jsx", "mjs", "cjs", "ts", "tsx", "json", "css", "scss", "md", "yml", "yaml",
+];
+const SHELL_EXTENSIONS: &[&str] = &["sh", "bash"];
+/// Scripts without an extension, like the ones zackstrap scaffolds.
+const SHELL_DIRS: &[&str] = &["bin/"];
+
+/// The checks run by the generated pre-commit and pre-push hooks for a
+/// project type, template and toolchain. Every hooks manager's scripts or
+/// config are generated from these, so they all run the same commands:
+/// pre-commit checks only look at staged files, and test suites run on
+/// pre-push.
+pub fn hook_checks(
+    project_type: &ProjectType,
+    template: &str,
+    toolchain: &Toolchain,
+) -> Vec<HookCheck> {
+    use HookKind::{PreCommit, PrePush};
+
+    const PRETTIER: HookCheck = HookCheck::new(
+        "prettier",
+        "Prettier",
+        "if command -v prettier >/dev/null 2>&1; then prettier --check {files} || true; fi",
+        PreCommit,
+    )
+    .on_staged(&["js", "ts", "json", "md", "yml", "yaml"]);
+
+    let mut checks = Vec::new();
+    match project_type {
+        ProjectType::Ruby => {
+            checks.push(
+                HookCheck::new(
+                    "rubocop",
+                    "RuboCop",
+                    "bundle exec rubocop --format simple --force-exclusion {files}",
+                    PreCommit,
+                )
+                .on_staged(RUBY_EXTENSIONS)
+                .or_paths(&["Gemfile", "Rakefile"]),
+            );
+            checks.push(PRETTIER);
+            if template == "gem" {
+                checks.push(
+                    HookCheck::new(
+                        "gem-build",
+                        "Build gem",
+                        "bundle exec gem build *.gemspec",
+                        PreCommit,
+                    )
+                    .on_staged(&["gemspec"]),
+                );
+            }
+            checks.push(match template {
+                "rails" => HookCheck::new(
+                    "rspec-full",
+                    "Full RSpec suite",
+                    "bundle exec rspec --format progress",
+                    PrePush,
+                ),
+                _ => HookCheck::new(
+                    "rspec-full",
+                    "Full RSpec suite",
+                    "if [ -f spec/spec_helper.rb ] || [ -f spec/rails_helper.rb ]; then bundle exec rspec --format progress; fi",
+                    PrePush,
+                ),
+            });
+            checks.push(match template {
+                "rails" => HookCheck::new(
+                    "bundle-audit",
+                    "Security audit",
+                    "bundle exec bundle audit --update",
+                    PrePush,
+                ),
+                _ => HookCheck::new(
+                    "bundle-audit",
+                    "Security audit",
+                    "bundle exec bundle audit --update || true",
+                    PrePush,
+                ),
+            });
+            checks.push(HookCheck::new(
+                "bundle-outdated",
+                "Outdated dependencies",
+                "bundle exec bundle outdated || true",
+                PrePush,
+            ));
+        }
+        ProjectType::Python => checks.extend(python_checks(template, &toolchain.python)),
+        ProjectType::Node => checks.extend(node_checks(template, &toolchain.node)),
+        ProjectType::Go => {
+            checks.push(
+                HookCheck::new(
+                    "gofmt",
+                    "gofmt",
+                    "unformatted=\"$(gofmt -l {files})\"; if [ -n \"$unformatted\" ]; then echo \"❌ These files need gofmt:\"; echo \"$unformatted\"; exit 1; fi",
+                    PreCommit,
+                )
+                .on_staged(&["go"]),
+            );
+            checks.push(
+                HookCheck::new(
+                    "go-vet",
+                    "go vet",
+                    "for f in {files}; do dirname \"$f\"; done | sort -u | { set --; while IFS= read -r d; do set -- \"$@\" \"./$d\"; done; [ $# -eq 0 ] || go vet \"$@\"; }",
+                    PreCommit,
+                )
+                .on_staged(&["go"]),
+            );
+            checks.push(
+                HookCheck::new(
+                    "golangci-lint",
+                    "golangci-lint",
+                    "if command -v golangci-lint >/dev/null 2>&1; then for f in {files}; do dirname \"$f\"; done | sort -u | { set --; while IFS= read -r d; do set -- \"$@\" \"./$d\"; done; [ $# -eq 0 ] || golangci-lint run \"$@\"; }; fi",
+                    PreCommit,
+                )
+                .on_staged(&["go"]),
+            );
+            if template == "cli" {
+                checks.push(
+                    HookCheck::new(
+                        "go-build",
+                        "Build CLI",
+                        "if [ -f go.mod ]; then go build -o /tmp/cli ./cmd/cli; fi",
+                        PreCommit,
+                    )
+                    .on_staged(&["go"]),
+                );
+            }
+            checks.push(
+                HookCheck::owned(
+                    "go-mod-tidy",
+                    "go mod tidy",
+                    for_each_go_module("go mod tidy && git diff --exit-code -- go.mod go.sum"),
+                    PrePush,
+                )
+                .serial(),
+            );
+            checks.push(HookCheck::owned(
+                "go-coverage",
+                "Go tests with coverage",
+                for_each_go_module(
+                    "go test -coverprofile=coverage.out ./... && go tool cover -func=coverage.out",
+                ),
+                PrePush,
+            ));
+            checks.push(HookCheck::owned(
+                "golangci-lint-all",
+                "golangci-lint (all packages)",
+                format!(
+                    "if command -v golangci-lint >/dev/null 2>&1; then {}; fi",
+                    for_each_go_module("golangci-lint run ./...")
+                ),
+                PrePush,
+            ));
+            if template == "web" {
+                checks.push(HookCheck::new(
+                    "go-build-platforms",
+                    "Cross-platform build",
+                    "if [ -f go.mod ]; then GOOS=linux GOARCH=amd64 go build -o /tmp/server-linux-amd64 ./cmd/server && GOOS=darwin GOARCH=amd64 go build -o /tmp/server-darwin-amd64 ./cmd/server && GOOS=windows GOARCH=amd64 go build -o /tmp/server-windows-amd64.exe ./cmd/server; fi",
+                    PrePush,
+                ));
+            }
+        }
+        ProjectType::Rust => {
+            // Firmware tests and examples do not build for the device target
+            let embedded = template == "embedded";
+            let clippy = if embedded {
+                "cargo clippy --all-features -- -D warnings"
+            } else {
+                "cargo clippy --all-targets --all-features -- -D warnings"
+            };
+            checks.push(
+                HookCheck::new("rustfmt", "rustfmt", "rustfmt --check {files}", PreCommit)
+                    .on_staged(&["rs"]),
+            );
+            checks.push(
+                HookCheck::new("cargo-check", "cargo check", "cargo check", PreCommit)
+                    .on_staged(&["rs"]),
+            );
+            checks.push(
+                HookCheck::new("cargo-clippy", "Clippy", clippy, PreCommit).on_staged(&["rs"]),
+            );
+            if template == "cli" {
+                checks.push(
+                    HookCheck::new("cargo-build", "Build CLI", "cargo build", PreCommit)
+                        .on_staged(&["rs"])
+                        .or_paths(&["Cargo.toml", "Cargo.lock"]),
+                );
+            }
+            if !embedded {
+                checks.push(HookCheck::new(
+                    "cargo-test-all",
+                    "Full Rust test suite",
+                    if template == "workspace" {
+                        "cargo test --workspace --all-features"
+                    } else {
+                        "cargo test --all-features"
+                    },
+                    PrePush,
+                ));
+            }
+            checks.push(HookCheck::new(
+                "cargo-clippy-all",
+                "Clippy (all checks)",
+                clippy,
+                PrePush,
+            ));
+            if !embedded {
+                checks.push(HookCheck::new(
+                    "cargo-llvm-cov",
+                    "Coverage",
+                    "if command -v cargo-llvm-cov >/dev/null 2>&1; then cargo llvm-cov --html; fi",
+                    PrePush,
+                ));
+            }
+            if matches!(template, "lib" | "workspace" | "embedded") {
+                checks.push(HookCheck::new(
+                    "cargo-deny",
+                    "cargo-deny",
+                    "if command -v cargo-deny >/dev/null 2>&1; then cargo deny check; fi",
+                    PrePush,
+                ));
+            }
+            checks.push(HookCheck::new(
+                "cargo-build-release",
+                "Release build",
+                "cargo build --release",
+                PrePush,
+            ));
+        }
+        ProjectType::Bash => {
+            checks.push(
+                HookCheck::new("shellcheck", "ShellCheck", "shellcheck {files}", PreCommit)
+                    .on_staged(&["sh", "bash", "bats"])
+                    .or_paths(SHELL_DIRS),
+            );
+            checks.push(
+                HookCheck::new(
+                    "shfmt",
+                    "shfmt",
+                    "if command -v shfmt >/dev/null 2>&1; then shfmt -d {files}; fi",
+                    PreCommit,
+                )
+                .on_staged(SHELL_EXTENSIONS)
+                .or_paths(SHELL_DIRS),
+            );
+            checks.push(
+                HookCheck::new(
+                    "bash-syntax",
+                    "Bash syntax",
+                    "for f in {files}; do bash -n \"$f\" || exit 1; done",
+                    PreCommit,
+                )
+                .on_staged(SHELL_EXTENSIONS)
+                .or_paths(SHELL_DIRS),
+            );
+            checks.push(HookCheck::new(
+                "shellcheck-full",
+                "ShellCheck",
+                "find . -type f \\( -path './bin/*' -o -name '*.sh' -o -name '*.bats' \\) -not -path './vendor/*' -exec shellcheck {} +",
+                PrePush,
+            ));
+            checks.push(HookCheck::new(
+                "bash-syntax-full",
+                "Bash syntax",
+                "find . -type f \\( -path './bin/*' -o -name '*.sh' \\) -not -path './vendor/*' -exec sh -c 'for f; do bash -n \"$f\" || exit 1; done' sh {} +",
+                PrePush,
+            ));
+            checks.push(HookCheck::new(
+                "shfmt-full",
+                "shfmt",
+                "if command -v shfmt >/dev/null 2>&1; then shfmt -d .; fi",
+                PrePush,
+            ));
+            checks.push(HookCheck::new(
+                "bats-full",
+                "Full BATS suite",
+                "if [ -d test ] && command -v bats >/dev/null 2>&1; then bats test/; fi",
+                PrePush,
+            ));
+            if template == "devops" {
+                checks.push(HookCheck::new(
+                    "todo-markers",
+                    "TODO/FIXME markers",
+                    "find . -name '*.sh' -not -path './vendor/*' -exec grep -Hn 'TODO\\|FIXME' {} + || true",
+                    PrePush,
+                ));
+            }
+        }
+        ProjectType::Basic => {
+            checks.push(PRETTIER);
+            checks.push(
+                HookCheck::new(
+                    "eslint",
+                    "ESLint",
+                    "if [ -n \"$(ls eslint.config.* .eslintrc* 2>/dev/null)\" ]; then npx eslint {files} || true; fi",
+                    PreCommit,
+                )
+                .on_staged(&["js", "jsx", "ts", "tsx"]),
+            );
+            checks.push(
+                HookCheck::new(
+                    "rubocop",
+                    "RuboCop",
+                    "if [ -f .rubocop.yml ]; then bundle exec rubocop --force-exclusion {files} || true; fi",
+                    PreCommit,
+                )
+                .on_staged(&["rb"]),
+            );
+            checks.push(
+                HookCheck::new(
+                    "black",
+                    "Black",
+                    "if [ -f pyproject.toml ]; then black --check {files} || true; fi",
+                    PreCommit,
+                )
+                .on_staged(&["py"]),
+            );
+            checks.push(HookCheck::new(
+                "tests",
+                "Available test suites",
+                "if [ -f package.json ] && grep -q '\"test\"' package.json; then npm test || true; fi; if [ -f Gemfile ]; then bundle exec rspec || true; fi; if [ -f pytest.ini ] || [ -f pyproject.toml ]; then pytest || true; fi; if [ -f Cargo.toml ]; then cargo test || true; fi; if [ -f go.mod ]; then go test ./... || true; fi",
+                PrePush,
+            ));
+        }
+    }
+    checks
+}
+
+/// Node checks run binaries and scripts through the project's package
+/// manager. Express, React and TypeScript projects always lint, format and
+/// build; other templates only when the project is set up for it.
+/// Runs `command` in the root module, or in every module of a go.work
+/// workspace, where the root usually has no go.mod of its own.
+fn for_each_go_module(command: &str) -> String {
+    format!(
+        "modules=\"$(go list -m -f '{{{{.Dir}}}}')\" && printf '%s\\n' \"$modules\" | while IFS= read -r dir; do (cd \"$dir\" && {command}) || exit 1; done"
+    )
+}
+
+fn node_checks(template: &str, toolchain: &NodeToolchain) -> Vec<HookCheck> {
+    use HookKind::{PreCommit, PrePush};
+
+    let package_manager = toolchain.package_manager;
+    let exec = package_manager.exec_prefix();
+    let typescript = toolchain.uses_typescript(template);
+    let strict = typescript || matches!(template, "express" | "react");
+    let when = |condition: &str, command: String| format!("if {}; then {}; fi", condition, command);
+
+    let eslint = format!("{}eslint {{files}}", exec);
+    let prettier = format!("{}prettier --check {{files}}", exec);
+    let tsc = format!("{}tsc --noEmit", exec);
+    let tests = match template {
+        "react" => package_manager.run_script("test", "--coverage --watchAll=false"),
+        _ => package_manager.run_script("test", "--coverage"),
+    };
+    let build = package_manager.run_script("build", "");
+    vec![
+        HookCheck::owned(
+            "eslint",
+            "ESLint",
+            if strict {
+                eslint
+            } else {
+                when(
+                    "[ -n \"$(ls eslint.config.* .eslintrc* 2>/dev/null)\" ]",
+                    eslint,
+                )
+            },
+            PreCommit,
+        )
+        .on_staged(JS_EXTENSIONS),
+        HookCheck::owned(
+            "prettier",
+            "Prettier",
+            if strict {
+                prettier
+            } else {
+                when("[ -f .prettierrc ] || [ -f .prettierrc.js ]", prettier)
+            },
+            PreCommit,
+        )
+        .on_staged(PRETTIER_EXTENSIONS),
+        HookCheck::owned(
+            "tsc",
+            "TypeScript",
+            if typescript {
+                tsc
+            } else {
+                when("[ -f tsconfig.json ]", tsc)
+            },
+            PreCommit,
+        )
+        .on_staged(&["ts", "tsx"]),
+        HookCheck::owned(
+            "tests-coverage",
+            "Full test suite",
+            match template {
+                "express" | "react" => tests,
+                _ => when("grep -q '\"test\"' package.json", tests),
+            },
+            PrePush,
+        ),
+        HookCheck::new(
+            "npm-audit",
+            "Security audit",
+            package_manager.audit(),
+            PrePush,
+        ),
+        HookCheck::owned(
+            "build",
+            "Build",
+            if strict {
+                build
+            } else {
+                when("grep -q '\"build\"' package.json", build)
+            },
+            PrePush,
+        ),
+    ]
+}
+
+/// Python checks, run through the package manager (e.g. `uv run ruff`) and
+/// with Ruff or Black and Flake8 depending on the linter.
+fn python_checks(template: &str, toolchain: &PythonToolchain) -> Vec<HookCheck> {
+    use HookKind::{PreCommit, PrePush};
+
+    let run = toolchain.package_manager.run_prefix();
+    let mut checks = Vec::new();
+    match toolchain.linter {
+        PythonLinter::Ruff => {
+            checks.push(
+                HookCheck::owned(
+                    "ruff-format",
+                    "Ruff format",
+                    format!("{}ruff format --check {{files}}", run),
+                    PreCommit,
+                )
+                .on_staged(PYTHON_EXTENSIONS),
+            );
+            checks.push(
+                HookCheck::owned(
+                    "ruff",
+                    "Ruff",
+                    format!("{}ruff check {{files}}", run),
+                    PreCommit,
+                )
+                .on_staged(PYTHON_EXTENSIONS),
+            );
+        }
+        PythonLinter::Flake8 => {
+            checks.push(
+                HookCheck::owned(
+                    "black",
+                    "Black",
+                    format!("{}black --check {{files}}", run),
+                    PreCommit,
+                )
+                .on_staged(PYTHON_EXTENSIONS),
+            );
+            checks.push(
+                HookCheck::owned(
+                    "flake8",
+                    "Flake8",
+                    format!("{}flake8 {{files}}", run),
+                    PreCommit,
+                )
+                .on_staged(&["py"]),
+            );
+        }
+    }
+    checks.push(
+        HookCheck::owned("mypy", "MyPy", format!("{}mypy {{files}}", run), PreCommit)
+            .on_staged(PYTHON_EXTENSIONS),
+    );
+
+    if let Some(lock_check) = toolchain.package_manager.lock_check() {
+        checks.push(HookCheck::new(
+            "lockfile",
+            "Lockfile up to date",
+            lock_check,
+            PrePush,
+        ));
+    }
+    if template == "django" {
+        checks.push(HookCheck::owned(
+            "tests-full",
+            "Full Django test suite",
+            format!("{}python manage.py test --parallel", run),
+            PrePush,
+        ));
+        checks.push(HookCheck::owned(
+            "django-check",
+            "Django security check",
+            format!("{}python manage.py check --deploy", run),
+            PrePush,
+        ));
+        checks.push(HookCheck::owned(
+            "coverage",
+            "Coverage report",
+            format!(
+                "{run}coverage run --source='.' manage.py test && {run}coverage report",
+                run = run
+            ),
+            PrePush,
+        ));
+    } else {
+        checks.push(HookCheck::owned(
+            "tests-full",
+            "Full Pytest suite",
+            format!("{}pytest --verbose", run),
+            PrePush,
+        ));
+        checks.push(HookCheck::owned(
+            "coverage",
+            "Coverage report",
+            format!(
+                "{run}coverage run -m pytest && {run}coverage report",
+                run = run
+            ),
+            PrePush,
+        ));
+    }
+    checks
+}
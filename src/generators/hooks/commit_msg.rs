@@ -0,0 +1,304 @@
+//! Commit message policy enforced by the generated commit-msg hooks.
+//!
+//! The policy is read from the `[commit-msg]` table of `.zackstrap.toml`.
+//! [`CommitMsgPolicy::check`] validates a message in Rust, and
+//! [`CommitMsgPolicy::shell_check`] renders the same rules as a POSIX shell
+//! snippet for the raw, pre-commit, lefthook and husky hooks.
+
+use super::shell_quote;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Conventional Commits types allowed by default.
+pub const DEFAULT_TYPES: [&str; 11] = [
+    "feat", "fix", "docs", "style", "refactor", "test", "chore", "perf", "ci", "build", "revert",
+];
+
+/// Subject prefixes git and its tooling generate for merge, revert and
+/// autosquash commits.
+pub const AUTOMATED_PREFIXES: [&str; 5] = ["Merge ", "Revert \"", "fixup! ", "squash! ", "amend! "];
+
+/// Rules for commit messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CommitMsgPolicy {
+    /// Allowed commit types, e.g. `feat` in `feat(api): add endpoint`.
+    pub types: Vec<String>,
+    /// Allowed scopes. Empty allows any scope.
+    pub scopes: Vec<String>,
+    /// Reject subjects without a `(scope)`.
+    pub require_scope: bool,
+    /// Extended regex a ticket reference must match somewhere in the
+    /// message, e.g. `[A-Z]+-[0-9]+`.
+    pub ticket_pattern: Option<String>,
+    /// Minimum subject length in characters.
+    pub min_subject_length: usize,
+    /// Maximum subject length in characters.
+    pub max_subject_length: Option<usize>,
+    /// Maximum length of each body line in characters.
+    pub body_line_length: Option<usize>,
+    /// Accept merge, revert, `fixup!`, `squash!` and `amend!` commits as
+    /// git writes them.
+    pub skip_automated: bool,
+}
+
+impl Default for CommitMsgPolicy {
+    fn default() -> Self {
+        Self {
+            types: DEFAULT_TYPES.iter().map(|t| t.to_string()).collect(),
+            scopes: Vec::new(),
+            require_scope: false,
+            ticket_pattern: None,
+            min_subject_length: 10,
+            max_subject_length: None,
+            body_line_length: None,
+            skip_automated: true,
+        }
+    }
+}
+
+/// A commit message split into its subject and body, ignoring git's
+/// comment lines and anything below the `git commit -v` scissors line.
+struct ParsedMessage<'a> {
+    subject: &'a str,
+    body: Vec<&'a str>,
+    text: Vec<&'a str>,
+}
+
+fn parse_message(message: &str) -> ParsedMessage<'_> {
+    let text: Vec<&str> = message
+        .lines()
+        .take_while(|line| !is_scissors(line))
+        .filter(|line| !line.starts_with('#'))
+        .collect();
+    let subject_index = text.iter().position(|line| !line.trim().is_empty());
+    match subject_index {
+        Some(index) => ParsedMessage {
+            subject: text[index],
+            body: text[index + 1..].to_vec(),
+            text,
+        },
+        None => ParsedMessage {
+            subject: "",
+            body: Vec::new(),
+            text,
+        },
+    }
+}
+
+fn is_scissors(line: &str) -> bool {
+    line.strip_prefix("# ")
+        .and_then(|rest| rest.split_once(" >8 "))
+        .is_some_and(|(left, right)| {
+            !left.is_empty() && left.chars().all(|c| c == '-') && right.chars().all(|c| c == '-')
+        })
+}
+
+impl CommitMsgPolicy {
+    /// Check that the policy's patterns compile.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.types.is_empty() {
+            return Err("types must not be empty".to_string());
+        }
+        Regex::new(&self.subject_pattern()).map_err(|e| e.to_string())?;
+        if let Some(pattern) = &self.ticket_pattern {
+            Regex::new(pattern).map_err(|e| format!("ticket-pattern: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Extended regex a subject must match, shared by the Rust check and the
+    /// shell hooks.
+    pub fn subject_pattern(&self) -> String {
+        let types = alternation(&self.types);
+        let scope = if self.scopes.is_empty() {
+            r"\([^)]+\)".to_string()
+        } else {
+            format!(r"\({}\)", alternation(&self.scopes))
+        };
+        let optional = if self.require_scope { "" } else { "?" };
+        format!(r"^{}({}){}!?: .+", types, scope, optional)
+    }
+
+    /// Whether `message` is a merge, revert or autosquash commit the
+    /// policy lets through unchanged.
+    pub fn is_automated(&self, message: &str) -> bool {
+        let subject = parse_message(message).subject;
+        self.skip_automated
+            && AUTOMATED_PREFIXES
+                .iter()
+                .any(|prefix| subject.starts_with(prefix))
+    }
+
+    /// Every rule `message` breaks, in the order the hook reports them.
+    pub fn check(&self, message: &str) -> Vec<String> {
+        let parsed = parse_message(message);
+        if parsed.subject.is_empty() {
+            return vec![empty_message()];
+        }
+        if self.is_automated(message) {
+            return Vec::new();
+        }
+
+        let mut violations = Vec::new();
+        match Regex::new(&self.subject_pattern()) {
+            Ok(re) if re.is_match(parsed.subject) => {}
+            Ok(_) => violations.push(self.format_message()),
+            Err(e) => violations.push(format!("Invalid commit-msg policy: {}", e)),
+        }
+
+        let length = parsed.subject.chars().count();
+        if length < self.min_subject_length {
+            violations.push(too_short(self.min_subject_length));
+        }
+        if let Some(max) = self.max_subject_length {
+            if length > max {
+                violations.push(too_long(max));
+            }
+        }
+
+        if let Some(pattern) = &self.ticket_pattern {
+            match Regex::new(pattern) {
+                Ok(re) if parsed.text.iter().any(|line| re.is_match(line)) => {}
+                Ok(_) => violations.push(missing_ticket(pattern)),
+                Err(e) => violations.push(format!("Invalid ticket pattern: {}", e)),
+            }
+        }
+
+        if let Some(max) = self.body_line_length {
+            if parsed.body.iter().any(|line| line.chars().count() > max) {
+                violations.push(body_too_wide(max));
+            }
+        }
+
+        violations
+    }
+
+    /// POSIX shell snippet that enforces the policy on the message stored in
+    /// `msg_file`, exiting non-zero with the same messages as [`check`].
+    ///
+    /// [`check`]: CommitMsgPolicy::check
+    pub fn shell_check(&self, msg_file: &str) -> String {
+        let mut script = format!(
+            r#"msg="$(sed -e '/^# -* >8 -*$/,$d' -e '/^#/d' "{file}")"
+subject="$(printf '%s\n' "$msg" | sed -e '/^[[:space:]]*$/d' | head -n 1)"
+if [ -z "$subject" ]; then
+    echo {empty}
+    exit 1
+fi
+"#,
+            file = msg_file,
+            empty = shell_quote(&format!("❌ {}", empty_message())),
+        );
+
+        if self.skip_automated {
+            let patterns: Vec<String> = AUTOMATED_PREFIXES
+                .iter()
+                .map(|prefix| format!("{}*", shell_quote(prefix)))
+                .collect();
+            script.push_str(&format!(
+                "case \"$subject\" in\n    {})\n        exit 0\n        ;;\nesac\n",
+                patterns.join(" | ")
+            ));
+        }
+
+        script.push_str(&format!(
+            r#"status=0
+if ! printf '%s\n' "$subject" | grep -qE {pattern}; then
+    echo {format}
+    status=1
+fi
+if [ ${{#subject}} -lt {min} ]; then
+    echo {short}
+    status=1
+fi
+"#,
+            pattern = shell_quote(&self.subject_pattern()),
+            format = shell_quote(&format!("❌ {}", self.format_message())),
+            min = self.min_subject_length,
+            short = shell_quote(&format!("❌ {}", too_short(self.min_subject_length))),
+        ));
+
+        if let Some(max) = self.max_subject_length {
+            script.push_str(&format!(
+                "if [ ${{#subject}} -gt {max} ]; then\n    echo {long}\n    status=1\nfi\n",
+                max = max,
+                long = shell_quote(&format!("❌ {}", too_long(max))),
+            ));
+        }
+
+        if let Some(pattern) = &self.ticket_pattern {
+            script.push_str(&format!(
+                "if ! printf '%s\\n' \"$msg\" | grep -qE {quoted}; then\n    echo {message}\n    status=1\nfi\n",
+                quoted = shell_quote(pattern),
+                message = shell_quote(&format!("❌ {}", missing_ticket(pattern))),
+            ));
+        }
+
+        if let Some(max) = self.body_line_length {
+            script.push_str(&format!(
+                "if ! printf '%s\\n' \"$msg\" | awk -v max={max} 'NF && !seen {{ seen = 1; next }} seen && length > max {{ exit 1 }}'; then\n    echo {wide}\n    status=1\nfi\n",
+                max = max,
+                wide = shell_quote(&format!("❌ {}", body_too_wide(max))),
+            ));
+        }
+
+        script.push_str("exit $status\n");
+        script
+    }
+
+    fn format_message(&self) -> String {
+        let mut message = format!(
+            "Commit message must follow conventional commits format: <type>({}<scope>{}): <description> (types: {}",
+            if self.require_scope { "" } else { "[" },
+            if self.require_scope { "" } else { "]" },
+            self.types.join(", ")
+        );
+        if !self.scopes.is_empty() {
+            message.push_str(&format!("; scopes: {}", self.scopes.join(", ")));
+        }
+        message.push(')');
+        message
+    }
+}
+
+fn empty_message() -> String {
+    "Commit message cannot be empty".to_string()
+}
+
+fn too_short(min: usize) -> String {
+    format!("Commit subject must be at least {} characters long", min)
+}
+
+fn too_long(max: usize) -> String {
+    format!("Commit subject must be at most {} characters long", max)
+}
+
+fn missing_ticket(pattern: &str) -> String {
+    format!(
+        "Commit message must reference a ticket matching {}",
+        pattern
+    )
+}
+
+fn body_too_wide(max: usize) -> String {
+    format!("Commit body lines must be at most {} characters long", max)
+}
+
+/// `(a|b|c)` with regex metacharacters escaped, valid in both Rust regex
+/// and POSIX extended regex.
+fn alternation(words: &[String]) -> String {
+    let escaped: Vec<String> = words
+        .iter()
+        .map(|word| {
+            word.chars()
+                .map(|c| match c {
+                    '.' | '[' | ']' | '{' | '}' | '(' | ')' | '*' | '+' | '?' | '^' | '$' | '|'
+                    | '\\' => format!("\\{}", c),
+                    _ => c.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+    format!("({})", escaped.join("|"))
+}
@@ -0,0 +1,197 @@
+//! Optional hooks around the commit lifecycle: `prepare-commit-msg` fills in
+//! the ticket id from the branch name, and `post-merge` / `post-checkout`
+//! reinstall dependencies when a lockfile changed.
+//!
+//! Each script is a POSIX shell snippet. Hook arguments are passed in as
+//! shell expressions because every hooks manager exposes them differently
+//! (`$1` for raw and husky hooks, `{1}` for lefthook, environment variables
+//! for pre-commit).
+
+use super::checks::HookKind;
+use super::commit_msg::CommitMsgPolicy;
+use crate::generators::toolchain::NodePackageManager;
+use crate::generators::ProjectType;
+
+/// Ticket id looked for in the branch name when the commit-msg policy does
+/// not set a `ticket-pattern`, e.g. `ABC-123` in `feature/ABC-123-search`.
+pub const DEFAULT_TICKET_PATTERN: &str = "[A-Z][A-Z0-9]+-[0-9]+";
+
+/// Trailer key used for the ticket id added by `prepare-commit-msg`.
+pub const TICKET_TRAILER: &str = "Refs";
+
+/// The script for a hook that does not run checks: `commit-msg` and the
+/// lifecycle hooks. `args` are the expressions for git's first three hook
+/// arguments. Returns `None` for `pre-commit` and `pre-push`.
+pub fn hook_script(
+    kind: HookKind,
+    project_type: &ProjectType,
+    policy: &CommitMsgPolicy,
+    args: [&str; 3],
+) -> Option<String> {
+    match kind {
+        HookKind::PreCommit | HookKind::PrePush => None,
+        HookKind::CommitMsg => Some(policy.shell_check(args[0])),
+        HookKind::PrepareCommitMsg => Some(prepare_commit_msg_script(policy, args[0], args[1])),
+        HookKind::PostMerge => Some(post_merge_script(project_type)),
+        HookKind::PostCheckout => Some(post_checkout_script(
+            project_type,
+            args[0],
+            args[1],
+            args[2],
+        )),
+    }
+}
+
+/// A command that reinstalls dependencies when one of its lockfiles changes.
+/// The command runs in the lockfile's directory, with `$lockfile` set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyRefresh {
+    pub lockfiles: &'static [&'static str],
+    pub command: &'static str,
+}
+
+const BUNDLE: DependencyRefresh = DependencyRefresh {
+    lockfiles: &["Gemfile.lock"],
+    command: "bundle install",
+};
+const UV: DependencyRefresh = DependencyRefresh {
+    lockfiles: &["uv.lock"],
+    command: "uv sync",
+};
+const POETRY: DependencyRefresh = DependencyRefresh {
+    lockfiles: &["poetry.lock"],
+    command: "poetry install",
+};
+const PDM: DependencyRefresh = DependencyRefresh {
+    lockfiles: &["pdm.lock"],
+    command: "pdm install",
+};
+const NPM: DependencyRefresh = node_refresh(NodePackageManager::Npm);
+const PNPM: DependencyRefresh = node_refresh(NodePackageManager::Pnpm);
+const YARN: DependencyRefresh = node_refresh(NodePackageManager::Yarn);
+const BUN: DependencyRefresh = node_refresh(NodePackageManager::Bun);
+
+const fn node_refresh(package_manager: NodePackageManager) -> DependencyRefresh {
+    DependencyRefresh {
+        lockfiles: package_manager.lockfiles(),
+        command: package_manager.frozen_install(),
+    }
+}
+const PIP: DependencyRefresh = DependencyRefresh {
+    lockfiles: &["requirements.txt", "requirements-dev.txt"],
+    command: "pip install -r \"$(basename \"$lockfile\")\"",
+};
+const GO_MOD: DependencyRefresh = DependencyRefresh {
+    lockfiles: &["go.sum"],
+    command: "go mod download",
+};
+const CARGO: DependencyRefresh = DependencyRefresh {
+    lockfiles: &["Cargo.lock"],
+    command: "cargo fetch",
+};
+
+/// Dependency refreshes for a project type. Each Python or Node package
+/// manager only reacts to its own lockfile, so those projects watch all of
+/// them. Basic and Bash projects have no package manager of their own, so
+/// they watch every known lockfile.
+pub fn dependency_refreshes(project_type: &ProjectType) -> Vec<DependencyRefresh> {
+    match project_type {
+        ProjectType::Ruby => vec![BUNDLE],
+        ProjectType::Python => vec![PIP, UV, POETRY, PDM],
+        ProjectType::Node => vec![NPM, PNPM, YARN, BUN],
+        ProjectType::Go => vec![GO_MOD],
+        ProjectType::Rust => vec![CARGO],
+        ProjectType::Basic | ProjectType::Bash => {
+            vec![
+                BUNDLE, NPM, PNPM, YARN, BUN, PIP, UV, POETRY, PDM, GO_MOD, CARGO,
+            ]
+        }
+    }
+}
+
+/// Add a `Refs: <ticket>` trailer when the branch name contains a ticket id
+/// and the message does not mention it yet. Merges, squashes and amended
+/// commits (`source` of `merge`, `squash` or `commit`) are left alone.
+pub fn prepare_commit_msg_script(policy: &CommitMsgPolicy, msg_file: &str, source: &str) -> String {
+    let pattern = policy
+        .ticket_pattern
+        .as_deref()
+        .unwrap_or(DEFAULT_TICKET_PATTERN);
+    format!(
+        r#"case "{source}" in
+    merge | squash | commit)
+        exit 0
+        ;;
+esac
+branch="$(git symbolic-ref --quiet --short HEAD 2>/dev/null || true)"
+ticket="$(printf '%s\n' "$branch" | grep -oE '{pattern}' | head -n 1)"
+if [ -n "$ticket" ] && ! grep -qF "$ticket" "{file}"; then
+    git interpret-trailers --in-place --if-exists addIfDifferent --trailer "{trailer}: $ticket" "{file}"
+fi
+"#,
+        source = source,
+        pattern = pattern.replace('\'', r"'\''"),
+        file = msg_file,
+        trailer = TICKET_TRAILER,
+    )
+}
+
+/// Reinstall dependencies whose lockfiles changed in the merge.
+pub fn post_merge_script(project_type: &ProjectType) -> String {
+    format!(
+        "changed_files=\"$(git diff-tree -r --name-only --no-commit-id ORIG_HEAD HEAD)\"\n{}",
+        refresh_changed(project_type)
+    )
+}
+
+/// Reinstall dependencies whose lockfiles differ between the previous and
+/// new HEAD. File checkouts (`flag` of 0) and fresh clones are skipped.
+pub fn post_checkout_script(
+    project_type: &ProjectType,
+    previous: &str,
+    new: &str,
+    flag: &str,
+) -> String {
+    format!(
+        r#"# Only branch checkouts that move HEAD; a zero id means a fresh clone
+case "{previous}" in
+    *[!0]*) ;;
+    *) exit 0 ;;
+esac
+if [ "{flag}" != "1" ] || [ "{previous}" = "{new}" ]; then
+    exit 0
+fi
+changed_files="$(git diff --name-only "{previous}" "{new}")"
+{refresh}"#,
+        previous = previous,
+        new = new,
+        flag = flag,
+        refresh = refresh_changed(project_type),
+    )
+}
+
+/// Run each refresh command for the lockfiles listed in `$changed_files`
+/// that still exist. A failed install only warns: git ignores these hooks'
+/// exit status.
+fn refresh_changed(project_type: &ProjectType) -> String {
+    dependency_refreshes(project_type)
+        .iter()
+        .map(|refresh| {
+            let names: Vec<String> = refresh
+                .lockfiles
+                .iter()
+                .map(|name| name.replace('.', r"\."))
+                .collect();
+            format!(
+                r#"printf '%s\n' "$changed_files" | grep -E '(^|/)({names})$' | while read -r lockfile; do
+    [ -f "$lockfile" ] || continue
+    echo "📦 $lockfile changed, reinstalling dependencies..."
+    (cd "$(dirname "$lockfile")" && {command}) || echo "⚠️  Could not reinstall dependencies for $lockfile"
+done
+"#,
+                names = names.join("|"),
+                command = refresh.command,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,348 @@
+use super::checks::{hook_checks, HookCheck, HookKind};
+use super::commit_msg::CommitMsgPolicy;
+use super::lifecycle::hook_script;
+use super::runner::HooksConfig;
+use super::{CHECK_HELPERS, COMMITTED_HOOKS_DIR, STAGED_FILES_PREAMBLE};
+use crate::config::MANIFEST_FILE;
+use crate::error::ZackstrapError;
+use crate::generators::merge::MergeStrategy;
+use crate::generators::toolchain::Toolchain;
+use crate::generators::{ConfigGenerator, ProjectType};
+use serde_json::{json, Value};
+
+/// Husky release pinned in the generated `package.json`.
+const HUSKY_VERSION: &str = "^9.1.7";
+
+/// Which tool installs and runs the generated git hooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum HooksManager {
+    /// Bash scripts written straight into `.git/hooks/` (not committed)
+    #[default]
+    Raw,
+    /// Raw scripts in a committed `.githooks/` directory, wired up with
+    /// `git config core.hooksPath`
+    Githooks,
+    /// `.pre-commit-config.yaml` for the pre-commit framework
+    PreCommit,
+    /// `lefthook.yml`
+    Lefthook,
+    /// `.husky/` scripts plus a `prepare` script in `package.json`
+    Husky,
+    /// Shims in `.git/hooks/` calling `zackstrap hook-run`, with the checks
+    /// configured in `.zackstrap.toml`
+    Native,
+}
+
+impl HooksManager {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HooksManager::Raw => "raw",
+            HooksManager::Githooks => "githooks",
+            HooksManager::PreCommit => "pre-commit",
+            HooksManager::Lefthook => "lefthook",
+            HooksManager::Husky => "husky",
+            HooksManager::Native => "native",
+        }
+    }
+
+    /// Command teammates run once after cloning to activate the hooks.
+    /// Husky is installed by the project's Node package manager.
+    pub fn install_hint(&self, toolchain: &Toolchain) -> Option<&'static str> {
+        match self {
+            HooksManager::Raw => None,
+            HooksManager::Githooks => Some("just install-hooks"),
+            HooksManager::PreCommit => Some("pre-commit install"),
+            HooksManager::Lefthook => Some("lefthook install"),
+            HooksManager::Husky => Some(toolchain.node.package_manager.install()),
+            HooksManager::Native => None,
+        }
+    }
+}
+
+/// Everything a hooks manager's configuration is rendered from.
+#[derive(Debug, Clone)]
+pub struct HookPlan {
+    pub project_type: ProjectType,
+    pub template: String,
+    /// Hooks to install, in order.
+    pub kinds: Vec<HookKind>,
+    /// Checks run by the selected `pre-commit` and `pre-push` hooks.
+    pub checks: Vec<HookCheck>,
+    pub policy: CommitMsgPolicy,
+}
+
+impl HookPlan {
+    pub fn new(
+        project_type: &ProjectType,
+        template: &str,
+        toolchain: &Toolchain,
+        kinds: &[HookKind],
+        policy: CommitMsgPolicy,
+    ) -> Self {
+        let checks = hook_checks(project_type, template, toolchain)
+            .into_iter()
+            .filter(|check| kinds.contains(&check.hook))
+            .collect();
+        Self {
+            project_type: project_type.clone(),
+            template: template.to_string(),
+            kinds: kinds.to_vec(),
+            checks,
+            policy,
+        }
+    }
+
+    fn checks_for(&self, kind: HookKind) -> impl Iterator<Item = &HookCheck> {
+        self.checks.iter().filter(move |check| check.hook == kind)
+    }
+
+    /// The script for a selected hook that does not run checks, reading
+    /// git's hook arguments from `args`.
+    pub fn script(&self, kind: HookKind, args: [&str; 3]) -> Option<String> {
+        hook_script(kind, &self.project_type, &self.policy, args)
+    }
+}
+
+/// Job id and display name for hooks that run a single script.
+fn script_job(kind: HookKind) -> (&'static str, &'static str) {
+    match kind {
+        HookKind::PrepareCommitMsg => ("ticket-trailer", "Ticket reference from branch name"),
+        HookKind::PostMerge | HookKind::PostCheckout => {
+            ("refresh-dependencies", "Reinstall changed dependencies")
+        }
+        _ => ("conventional-commit", "Conventional commit message"),
+    }
+}
+
+/// Render `.pre-commit-config.yaml` with one local hook per check. Checks
+/// limited to staged files get a `files` pattern and receive the matching
+/// files as arguments.
+pub fn render_pre_commit_config(plan: &HookPlan) -> String {
+    let mut hooks: Vec<Value> = plan
+        .checks
+        .iter()
+        .map(|check| {
+            let mut hook = json!({
+                "id": check.id,
+                "name": check.name,
+                "entry": "bash -c",
+                "language": "system",
+                "stages": [check.hook.as_str()],
+            });
+            if check.takes_files() {
+                hook["args"] = json!([check.command_with_files("\"$@\""), "--"]);
+                hook["pass_filenames"] = json!(true);
+            } else {
+                hook["args"] = json!([check.command]);
+                hook["pass_filenames"] = json!(false);
+            }
+            match check.files_regex() {
+                Some(regex) => hook["files"] = json!(regex),
+                None => hook["always_run"] = json!(true),
+            }
+            hook
+        })
+        .collect();
+
+    // pre-commit passes the message file as an argument and everything else
+    // through environment variables
+    for &kind in &plan.kinds {
+        let (id, name) = script_job(kind);
+        let hook = match kind {
+            HookKind::CommitMsg | HookKind::PrepareCommitMsg => plan
+                .script(kind, ["$1", "$PRE_COMMIT_COMMIT_MSG_SOURCE", ""])
+                .map(|script| {
+                    json!({
+                        "id": id,
+                        "name": name,
+                        "entry": "bash -c",
+                        "args": [script, kind.as_str()],
+                        "language": "system",
+                        "always_run": true,
+                        "stages": [kind.as_str()],
+                    })
+                }),
+            _ => plan
+                .script(
+                    kind,
+                    [
+                        "$PRE_COMMIT_FROM_REF",
+                        "$PRE_COMMIT_TO_REF",
+                        "$PRE_COMMIT_CHECKOUT_TYPE",
+                    ],
+                )
+                .map(|script| {
+                    json!({
+                        "id": id,
+                        "name": name,
+                        "entry": "bash -c",
+                        "args": [script],
+                        "language": "system",
+                        "pass_filenames": false,
+                        "always_run": true,
+                        "stages": [kind.as_str()],
+                    })
+                }),
+        };
+        hooks.extend(hook);
+    }
+
+    let config = json!({
+        "default_install_hook_types": plan.kinds.iter().map(HookKind::as_str).collect::<Vec<_>>(),
+        "repos": [{ "repo": "local", "hooks": hooks }],
+    });
+
+    format!(
+        "# Generated by zackstrap. Install with: pre-commit install\n{}",
+        serde_yaml::to_string(&config).expect("hook config serializes")
+    )
+}
+
+/// Render `lefthook.yml`, running each hook's jobs in order.
+pub fn render_lefthook_config(plan: &HookPlan) -> String {
+    let mut config = serde_json::Map::new();
+    for &kind in &plan.kinds {
+        if let Some(script) = plan.script(kind, ["{1}", "{2}", "{3}"]) {
+            let (id, _) = script_job(kind);
+            config.insert(
+                kind.as_str().to_string(),
+                json!({ "jobs": [{ "name": id, "run": script }] }),
+            );
+            continue;
+        }
+
+        let jobs: Vec<Value> = plan
+            .checks_for(kind)
+            .map(|check| {
+                let mut job = json!({
+                    "name": check.id,
+                    "run": check.command_with_files("{staged_files}"),
+                });
+                if let Some(glob) = check.files_glob() {
+                    job["glob"] = json!(glob);
+                }
+                job
+            })
+            .collect();
+        if !jobs.is_empty() {
+            config.insert(
+                kind.as_str().to_string(),
+                json!({ "piped": true, "jobs": jobs }),
+            );
+        }
+    }
+
+    format!(
+        "# Generated by zackstrap. Install with: lefthook install\n{}",
+        serde_yaml::to_string(&Value::Object(config)).expect("hook config serializes")
+    )
+}
+
+/// Render the body of a `.husky/<hook>` script, running the same checks as
+/// the raw hook.
+pub fn render_husky_hook(kind: HookKind, plan: &HookPlan) -> String {
+    let mut script = String::from("# Generated by zackstrap\n");
+    if let Some(body) = plan.script(kind, ["$1", "$2", "$3"]) {
+        script.push_str(&body);
+        return script;
+    }
+    script.push_str(&render_check_script(kind, plan, ""));
+    script
+}
+
+/// Render the checks `kind` runs as POSIX shell, shared by the raw, githooks
+/// and husky hooks. Each check becomes a `check_<id>` function run in a
+/// subshell, so it can `exit` without ending the hook. Pre-commit checks
+/// limited to staged files get those files as arguments (`"$@"`) and are
+/// skipped when none are staged. Every check runs even after one fails;
+/// the hook then prints a summary and fails. `setup` runs before the first
+/// check.
+pub fn render_check_script(kind: HookKind, plan: &HookPlan, setup: &str) -> String {
+    let mut script = String::new();
+    if kind == HookKind::PreCommit {
+        script.push_str(STAGED_FILES_PREAMBLE);
+    }
+    script.push_str(CHECK_HELPERS);
+    if !setup.is_empty() {
+        script.push('\n');
+        script.push_str(setup);
+    }
+    for check in plan.checks_for(kind) {
+        let function = format!("check_{}", check.id.replace('-', "_"));
+        script.push_str(&format!(
+            "\n# {}\n{}() (\n    {}\n)\n",
+            check.name,
+            function,
+            check.command_with_files("\"$@\"")
+        ));
+        match check.files_regex().filter(|_| kind == HookKind::PreCommit) {
+            Some(regex) => script.push_str(&format!(
+                "run_staged_check {} \"{}\" '{}'\n",
+                function, check.name, regex
+            )),
+            None => script.push_str(&format!("run_check {} \"{}\"\n", function, check.name)),
+        }
+    }
+    script.push_str("\nfinish_checks\n");
+    script
+}
+
+impl ConfigGenerator {
+    /// Write the committed configuration for a hooks manager other than
+    /// `raw`, running the same checks as the raw hook scripts.
+    pub async fn generate_hooks_manager_files(
+        &self,
+        manager: HooksManager,
+        plan: &HookPlan,
+    ) -> Result<(), ZackstrapError> {
+        match manager {
+            HooksManager::Raw => Ok(()),
+            HooksManager::Githooks => {
+                // The hook scripts themselves are written by GitHooksGenerator
+                let content = format!(
+                    "# Use the committed hooks in {dir}/\ninstall-hooks:\n    git config core.hooksPath \"$(git rev-parse --show-prefix){dir}\"\n",
+                    dir = COMMITTED_HOOKS_DIR
+                );
+                self.emit_merged_file("justfile", &content, MergeStrategy::ManagedBlock, false)
+                    .await
+            }
+            HooksManager::PreCommit => {
+                let content = render_pre_commit_config(plan);
+                self.emit_file(".pre-commit-config.yaml", &content, false, false)
+                    .await
+            }
+            HooksManager::Lefthook => {
+                let content = render_lefthook_config(plan);
+                self.emit_file("lefthook.yml", &content, false, false).await
+            }
+            HooksManager::Husky => {
+                for &kind in &plan.kinds {
+                    let content = render_husky_hook(kind, plan);
+                    self.emit_file(&format!(".husky/{}", kind.as_str()), &content, false, false)
+                        .await?;
+                }
+                let package_json = json!({
+                    "scripts": { "prepare": "husky" },
+                    "devDependencies": { "husky": HUSKY_VERSION },
+                });
+                let content = format!(
+                    "{}\n",
+                    serde_json::to_string_pretty(&package_json).expect("package.json serializes")
+                );
+                self.emit_merged_file("package.json", &content, MergeStrategy::Json, false)
+                    .await
+            }
+            HooksManager::Native => {
+                // The shims themselves are written by GitHooksGenerator
+                let content = HooksConfig::render(&plan.project_type, &plan.template);
+                self.emit_merged_file(
+                    MANIFEST_FILE,
+                    &content,
+                    MergeStrategy::Toml(&["hooks"]),
+                    false,
+                )
+                .await
+            }
+        }
+    }
+}
@@ -0,0 +1,645 @@
+use crate::error::ZackstrapError;
+use crate::generators::common::WrittenPaths;
+use crate::generators::git::GitRepo;
+use crate::generators::toolchain::{PythonPackageManager, Toolchain};
+use crate::generators::ProjectType;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+pub mod chain;
+pub mod checks;
+pub mod commit_msg;
+pub mod lifecycle;
+pub mod managers;
+pub mod runner;
+
+pub use chain::{HookState, HookStatus, UninstallOutcome};
+pub use checks::HookKind;
+pub use commit_msg::CommitMsgPolicy;
+pub use managers::{HookPlan, HooksManager};
+pub use runner::HookRunner;
+
+/// Versioned hooks directory used by `--hooks-manager githooks`.
+pub const COMMITTED_HOOKS_DIR: &str = ".githooks";
+
+pub struct GitHooksGenerator {
+    target_dir: PathBuf,
+    hooks_path: Option<PathBuf>,
+    commit_msg_policy: CommitMsgPolicy,
+    hook_kinds: Vec<HookKind>,
+    toolchain: Toolchain,
+    native: bool,
+    dry_run: bool,
+    written: WrittenPaths,
+}
+
+impl GitHooksGenerator {
+    pub fn new(target_dir: PathBuf) -> Self {
+        Self {
+            target_dir,
+            hooks_path: None,
+            commit_msg_policy: CommitMsgPolicy::default(),
+            hook_kinds: HookKind::DEFAULT.to_vec(),
+            toolchain: Toolchain::default(),
+            native: false,
+            dry_run: false,
+            written: WrittenPaths::default(),
+        }
+    }
+
+    /// Preview the hooks instead of writing them. The repository is still
+    /// looked up, so a missing one is reported just like a real run.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Install shims calling `zackstrap hook-run` instead of bash scripts.
+    pub fn with_native_runner(mut self) -> Self {
+        self.native = true;
+        self
+    }
+
+    /// Install only these hooks instead of `HookKind::DEFAULT`.
+    pub fn with_hook_kinds(mut self, kinds: &[HookKind]) -> Self {
+        self.hook_kinds = kinds.to_vec();
+        self
+    }
+
+    /// Run the checks with these tools (e.g. `uv run ruff`) instead of the
+    /// defaults.
+    pub fn with_toolchain(mut self, toolchain: Toolchain) -> Self {
+        self.toolchain = toolchain;
+        self
+    }
+
+    /// Record the hooks this generator writes in `written`.
+    pub fn with_written_paths(mut self, written: WrittenPaths) -> Self {
+        self.written = written;
+        self
+    }
+
+    /// Enforce `policy` in the generated commit-msg hook.
+    pub fn with_commit_msg_policy(mut self, policy: CommitMsgPolicy) -> Self {
+        self.commit_msg_policy = policy;
+        self
+    }
+
+    /// Write hooks into `hooks_path` (relative to the target directory)
+    /// instead of the repository's configured hooks directory.
+    pub fn with_hooks_path(mut self, hooks_path: impl Into<PathBuf>) -> Self {
+        self.hooks_path = Some(hooks_path.into());
+        self
+    }
+
+    /// Directory the hooks are written to: an explicit hooks path, then
+    /// git's `core.hooksPath`, then the repository's common hooks directory
+    /// (shared by all worktrees, and inside `.git/modules` for submodules).
+    pub async fn hooks_dir(&self) -> Result<PathBuf, ZackstrapError> {
+        let hooks_dir = self.resolve_hooks_dir()?;
+        fs::create_dir_all(&hooks_dir).await?;
+        Ok(hooks_dir)
+    }
+
+    /// Like [`hooks_dir`](Self::hooks_dir), without creating the directory.
+    fn resolve_hooks_dir(&self) -> Result<PathBuf, ZackstrapError> {
+        match &self.hooks_path {
+            Some(path) => Ok(self.target_dir.join(path)),
+            None => {
+                let repo = self.repo()?;
+                Ok(match self.configured_hooks_path() {
+                    // Relative hook paths are resolved from the work tree root
+                    Some(path) => repo.work_tree.join(path),
+                    None => repo.hooks_dir(),
+                })
+            }
+        }
+    }
+
+    /// The repository containing the target directory.
+    pub fn repo(&self) -> Result<GitRepo, ZackstrapError> {
+        GitRepo::discover(&self.target_dir).ok_or(ZackstrapError::GitNotInitialized)
+    }
+
+    /// The repository's `core.hooksPath`, if one is set.
+    pub fn configured_hooks_path(&self) -> Option<PathBuf> {
+        self.repo().ok()?;
+        let output = std::process::Command::new("git")
+            .args(["config", "--get", "core.hooksPath"])
+            .current_dir(&self.target_dir)
+            .output()
+            .ok()?;
+        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (output.status.success() && !value.is_empty()).then(|| PathBuf::from(value))
+    }
+
+    /// Point git at the committed hooks directory `hooks_path` (relative to
+    /// the target directory) with `git config core.hooksPath`.
+    pub fn configure_hooks_path(&self, hooks_path: &str) -> Result<(), ZackstrapError> {
+        let repo = self.repo()?;
+        let target_dir = std::fs::canonicalize(&self.target_dir)?;
+        let value = match target_dir.strip_prefix(&repo.work_tree) {
+            Ok(prefix) => prefix.join(hooks_path),
+            Err(_) => target_dir.join(hooks_path),
+        };
+
+        let status = std::process::Command::new("git")
+            .arg("config")
+            .arg("core.hooksPath")
+            .arg(&value)
+            .current_dir(&self.target_dir)
+            .status()?;
+        if !status.success() {
+            return Err(ZackstrapError::GitConfigFailed(
+                "core.hooksPath".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Generate the selected raw hooks for any project type.
+    pub async fn generate_hooks(
+        &self,
+        project_type: &ProjectType,
+        template: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        if self.dry_run {
+            return self.preview_hooks(project_type, template, force).await;
+        }
+
+        let hooks_dir = self.hooks_dir().await?;
+        for &kind in &self.hook_kinds {
+            let content = self.hook_content(kind, project_type, template);
+            let hook_path = hooks_dir.join(kind.as_str());
+            self.write_hook_file(&hook_path, &content, force).await?;
+            self.written.record(&chain::script_path(&hook_path));
+            self.written.record(&hook_path);
+        }
+        Ok(())
+    }
+
+    async fn preview_hooks(
+        &self,
+        project_type: &ProjectType,
+        template: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        let hooks_dir = self.resolve_hooks_dir()?;
+        // Show paths from the work tree root, e.g. `.git/hooks/pre-commit`
+        let root = match &self.hooks_path {
+            Some(_) => self.target_dir.clone(),
+            None => self.repo()?.work_tree,
+        };
+        for &kind in &self.hook_kinds {
+            let content = self.hook_content(kind, project_type, template);
+            chain::preview_chained_hook(&hooks_dir.join(kind.as_str()), &content, force, &root)
+                .await;
+        }
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub async fn generate_ruby_hooks(
+        &self,
+        template: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        self.generate_hooks(&ProjectType::Ruby, template, force)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn generate_python_hooks(
+        &self,
+        template: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        self.generate_hooks(&ProjectType::Python, template, force)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn generate_node_hooks(
+        &self,
+        template: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        self.generate_hooks(&ProjectType::Node, template, force)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn generate_go_hooks(
+        &self,
+        template: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        self.generate_hooks(&ProjectType::Go, template, force).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn generate_rust_hooks(
+        &self,
+        template: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        self.generate_hooks(&ProjectType::Rust, template, force)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn generate_bash_hooks(
+        &self,
+        template: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        self.generate_hooks(&ProjectType::Bash, template, force)
+            .await
+    }
+
+    #[allow(dead_code)]
+    pub async fn generate_basic_hooks(&self, force: bool) -> Result<(), ZackstrapError> {
+        self.generate_hooks(&ProjectType::Basic, "default", force)
+            .await
+    }
+
+    /// The raw script for one hook.
+    fn hook_content(&self, kind: HookKind, project_type: &ProjectType, template: &str) -> String {
+        if self.native {
+            return runner::shim_script(kind);
+        }
+        match kind {
+            HookKind::PreCommit | HookKind::PrePush => {
+                self.check_hook(kind, project_type, template)
+            }
+            HookKind::CommitMsg => self.get_commit_msg_hook(),
+            _ => self.get_lifecycle_hook(kind, project_type),
+        }
+    }
+
+    async fn write_hook_file(
+        &self,
+        path: &Path,
+        content: &str,
+        force: bool,
+    ) -> Result<(), ZackstrapError> {
+        chain::install_chained_hook(path, content, force).await
+    }
+
+    /// Remove zackstrap's hooks, restoring any hook that existed before
+    /// they were installed. Returns each hook's outcome; nothing is touched
+    /// when the hooks directory does not exist.
+    pub async fn uninstall_hooks(
+        &self,
+    ) -> Result<Vec<(&'static str, UninstallOutcome)>, ZackstrapError> {
+        let hooks_dir = self.resolve_hooks_dir()?;
+        let mut outcomes = Vec::new();
+        for kind in HookKind::ALL {
+            let hook = kind.as_str();
+            let outcome = if hooks_dir.is_dir() {
+                chain::uninstall_chained_hook(&hooks_dir.join(hook)).await?
+            } else {
+                UninstallOutcome::NotInstalled
+            };
+            outcomes.push((hook, outcome));
+        }
+        Ok(outcomes)
+    }
+
+    /// Report whether each hook is zackstrap's, edited since it was
+    /// installed, or another tool's.
+    pub async fn hook_states(&self) -> Result<Vec<(HookKind, HookState)>, ZackstrapError> {
+        let hooks_dir = self.resolve_hooks_dir()?;
+        let mut states = Vec::new();
+        for kind in HookKind::ALL {
+            let state = chain::hook_state(&hooks_dir.join(kind.as_str())).await?;
+            states.push((kind, state));
+        }
+        Ok(states)
+    }
+
+    /// Run the installed `kind` hook from the work tree root, the way git
+    /// would. Message hooks default to the last commit message in
+    /// `COMMIT_EDITMSG` when no arguments are given.
+    pub fn run_hook(&self, kind: HookKind, args: &[String]) -> Result<(), ZackstrapError> {
+        let repo = self.repo()?;
+        let hook_path = self.resolve_hooks_dir()?.join(kind.as_str());
+        if !hook_path.is_file() {
+            return Err(ZackstrapError::HookNotInstalled(kind.as_str().to_string()));
+        }
+
+        let mut command = std::process::Command::new(&hook_path);
+        command
+            .args(args)
+            .current_dir(&repo.work_tree)
+            .stdin(std::process::Stdio::null());
+        if args.is_empty() && matches!(kind, HookKind::CommitMsg | HookKind::PrepareCommitMsg) {
+            command.arg(repo.git_dir.join("COMMIT_EDITMSG"));
+        }
+
+        let status = command.status()?;
+        if !status.success() {
+            return Err(ZackstrapError::HookFailed(kind.as_str().to_string()));
+        }
+        Ok(())
+    }
+
+    /// A `pre-commit` or `pre-push` hook: the project's checks after
+    /// [`hook_setup`](Self::hook_setup).
+    fn check_hook(&self, kind: HookKind, project_type: &ProjectType, template: &str) -> String {
+        let plan = HookPlan::new(
+            project_type,
+            template,
+            &self.toolchain,
+            &[kind],
+            self.commit_msg_policy.clone(),
+        );
+        let title = hook_title(project_type, template);
+        let (label, banner) = match kind {
+            HookKind::PreCommit => (
+                "Pre-commit",
+                format!("🔍 Running {} pre-commit checks on staged files...", title),
+            ),
+            _ => (
+                "Pre-push",
+                format!("🚀 Running {} pre-push checks...", title),
+            ),
+        };
+        format!(
+            "#!/bin/bash\n# {title} {label} Hook\nset -e\n\necho \"{banner}\"\n{checks}\necho \"✅ {title} {kind} checks passed!\"\n",
+            title = title,
+            label = label,
+            banner = banner,
+            checks = managers::render_check_script(
+                kind,
+                &plan,
+                &self.hook_setup(kind, project_type)
+            ),
+            kind = kind.as_str(),
+        )
+    }
+
+    /// Commands the raw hooks run before their checks: making sure the tools
+    /// are installed and dependencies are in place.
+    fn hook_setup(&self, kind: HookKind, project_type: &ProjectType) -> String {
+        let pre_commit = kind == HookKind::PreCommit;
+        match project_type {
+            ProjectType::Ruby if pre_commit => format!(
+                r#"{}
+# Install dependencies if needed
+if [ ! -d "vendor/bundle" ]; then
+    echo "📦 Installing dependencies..."
+    bundle install
+fi
+"#,
+                require_command("bundle")
+            ),
+            ProjectType::Python => match self.toolchain.python.package_manager {
+                PythonPackageManager::Pip => {
+                    let mut setup = String::new();
+                    if pre_commit {
+                        setup.push_str(&require_command("python"));
+                        setup.push('\n');
+                    }
+                    setup.push_str(
+                        r#"# Activate virtual environment if it exists
+if [ -f "venv/bin/activate" ]; then
+    echo "🐍 Activating virtual environment..."
+    source venv/bin/activate
+elif [ -f ".venv/bin/activate" ]; then
+    echo "🐍 Activating virtual environment..."
+    source .venv/bin/activate
+fi
+"#,
+                    );
+                    if pre_commit {
+                        setup.push_str(
+                            r#"
+# Install dependencies if needed
+if [ -f "requirements.txt" ]; then
+    echo "📦 Installing dependencies..."
+    pip install -r requirements.txt
+fi
+
+if [ -f "requirements-dev.txt" ]; then
+    echo "📦 Installing dev dependencies..."
+    pip install -r requirements-dev.txt
+fi
+"#,
+                        );
+                    }
+                    setup
+                }
+                package_manager => require_command(package_manager.as_str()),
+            },
+            ProjectType::Node => {
+                format!("{}\n{}", require_command("node"), self.node_environment())
+            }
+            ProjectType::Go if pre_commit => require_command("go"),
+            ProjectType::Rust if pre_commit => require_command("cargo"),
+            ProjectType::Bash if pre_commit => require_command("shellcheck"),
+            _ => String::new(),
+        }
+    }
+
+    /// Require the package manager and install dependencies on first use.
+    fn node_environment(&self) -> String {
+        let package_manager = self.toolchain.node.package_manager;
+        format!(
+            r#"{require}
+# Install dependencies if needed
+if [ ! -d "node_modules" ]; then
+    echo "📦 Installing dependencies..."
+    {install}
+fi
+"#,
+            require = require_command(package_manager.as_str()),
+            install = package_manager.install(),
+        )
+    }
+
+    // Common hooks
+    fn get_commit_msg_hook(&self) -> String {
+        format!(
+            r#"#!/bin/bash
+# Commit Message Hook
+# Policy comes from the [commit-msg] table in .zackstrap.toml
+set -e
+
+{}"#,
+            self.commit_msg_policy.shell_check("$1")
+        )
+    }
+
+    fn get_lifecycle_hook(&self, kind: HookKind, project_type: &ProjectType) -> String {
+        let (title, banner) = match kind {
+            HookKind::PrepareCommitMsg => ("Prepare Commit Message Hook", ""),
+            HookKind::PostMerge => (
+                "Post-merge Hook",
+                "echo \"🔄 Checking for dependency changes after merge...\"\n\n",
+            ),
+            _ => (
+                "Post-checkout Hook",
+                "echo \"🔄 Checking for dependency changes after checkout...\"\n\n",
+            ),
+        };
+        let script = lifecycle::hook_script(
+            kind,
+            project_type,
+            &self.commit_msg_policy,
+            ["$1", "$2", "$3"],
+        )
+        .unwrap_or_default();
+        format!("#!/bin/bash\n# {}\nset -e\n\n{}{}", title, banner, script)
+    }
+}
+
+/// Shell helpers shared by every pre-commit hook: unstaged changes are
+/// stashed as a patch (and re-applied on exit) so partially-staged files are
+/// checked exactly as they will be committed, and `staged_files` /
+/// `run_staged_check` limit checks to staged files matching a regex.
+pub(crate) const STAGED_FILES_PREAMBLE: &str = r#"
+# Stash unstaged changes so checks only see what is being committed
+unstaged_patch=""
+restore_unstaged() {
+    if [ -n "$unstaged_patch" ]; then
+        if git apply --whitespace=nowarn "$unstaged_patch"; then
+            rm -f "$unstaged_patch"
+        else
+            echo "⚠️  Could not restore unstaged changes; they are saved in $unstaged_patch"
+        fi
+    fi
+}
+if ! git diff --quiet --no-ext-diff; then
+    # A unique file, so a patch left behind by a failed restore is never overwritten
+    unstaged_patch="$(mktemp "$(git rev-parse --git-dir)/zackstrap-unstaged.XXXXXX")"
+    git diff --binary --no-color --no-ext-diff > "$unstaged_patch"
+    git checkout -- .
+    trap restore_unstaged EXIT
+    echo "📦 Stashed unstaged changes in $unstaged_patch"
+fi
+
+# Staged files (added, copied or modified) matching an extended regex
+staged_files() {
+    git diff --cached --name-only --diff-filter=ACM | grep -E "$1" || true
+}
+
+# Run a check function with the staged files matching a regex as its
+# arguments, split on newlines so paths with spaces stay whole. Skipped when
+# no such files are staged.
+run_staged_check() {
+    check="$1"
+    name="$2"
+    staged="$(staged_files "$3")"
+    if [ -z "$staged" ]; then
+        record_check "$name" SKIP -
+        return 0
+    fi
+    set -f
+    IFS='
+'
+    # shellcheck disable=SC2086
+    set -- $staged
+    unset IFS
+    set +f
+    run_check "$check" "$name" "$@"
+}
+"#;
+
+/// Runs check functions (`check_<id>`) without stopping at the first
+/// failure, then prints a summary like `zackstrap hook-run` and fails the
+/// hook if any check failed.
+pub(crate) const CHECK_HELPERS: &str = r#"
+# Results of the checks run so far, printed by finish_checks
+check_results=""
+check_width=5
+check_failures=0
+
+# Record a check's result (PASS, FAIL or SKIP) and time for the summary
+record_check() {
+    check_results="$check_results$1|$2|$3
+"
+    [ ${#1} -le "$check_width" ] || check_width=${#1}
+}
+
+# Run a check function with any further arguments, showing its name first.
+# A failure is recorded and the remaining checks still run.
+run_check() {
+    check="$1"
+    name="$2"
+    shift 2
+    echo "▶ $name"
+    started="$(date +%s)"
+    if "$check" "$@"; then
+        result=PASS
+    else
+        result=FAIL
+        check_failures=$((check_failures + 1))
+    fi
+    record_check "$name" "$result" "$(($(date +%s) - started))s"
+}
+
+# Print every check's result, failing the hook if any check failed
+finish_checks() {
+    echo
+    printf "  %-${check_width}s  %-6s  %5s\n" Check Result Time
+    printf '%s' "$check_results" | while IFS='|' read -r name result time; do
+        printf "  %-${check_width}s  %-6s  %5s\n" "$name" "$result" "$time"
+    done
+    if [ "$check_failures" -gt 0 ]; then
+        echo
+        echo "❌ $check_failures check(s) failed"
+        exit 1
+    fi
+}
+"#;
+
+/// Banner name of a hook, e.g. `Python Django`.
+fn hook_title(project_type: &ProjectType, template: &str) -> &'static str {
+    match (project_type, template) {
+        (ProjectType::Ruby, "rails") => "Ruby Rails",
+        (ProjectType::Ruby, "sinatra") => "Ruby Sinatra",
+        (ProjectType::Ruby, "gem") => "Ruby Gem",
+        (ProjectType::Ruby, _) => "Ruby",
+        (ProjectType::Python, "django") => "Python Django",
+        (ProjectType::Python, "flask") => "Python Flask",
+        (ProjectType::Python, _) => "Python",
+        (ProjectType::Node, "express") => "Node.js Express",
+        (ProjectType::Node, "react") => "Node.js React",
+        (ProjectType::Node, "next") => "Next.js",
+        (ProjectType::Node, "vite") => "Vite",
+        (ProjectType::Node, "nest") => "NestJS",
+        (ProjectType::Node, "library") => "TypeScript library",
+        (ProjectType::Node, _) => "Node.js",
+        (ProjectType::Go, "web") => "Go web",
+        (ProjectType::Go, "cli") => "Go CLI",
+        (ProjectType::Go, _) => "Go",
+        (ProjectType::Rust, "web") => "Rust web",
+        (ProjectType::Rust, "cli") => "Rust CLI",
+        (ProjectType::Rust, "embedded") => "Rust embedded",
+        (ProjectType::Rust, _) => "Rust",
+        (ProjectType::Bash, "devops") => "Bash DevOps",
+        (ProjectType::Bash, "cli") => "Bash CLI",
+        (ProjectType::Bash, _) => "Bash",
+        (ProjectType::Basic, _) => "Basic",
+    }
+}
+
+/// Abort the hook when `program` is not installed.
+fn require_command(program: &str) -> String {
+    format!(
+        r#"# Check if {program} is available
+if ! command -v {program} &> /dev/null; then
+    echo "❌ {program} not found. Please install {program}."
+    exit 1
+fi
+"#,
+        program = program
+    )
+}
+
+/// Single-quote `value` for the shell.
+pub(crate) fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
@@ -0,0 +1,579 @@
+//! Native hook runner behind `zackstrap hook-run`, used by
+//! `--hooks-manager native`.
+//!
+//! The installed hooks are one-line shims; the check pipeline runs here
+//! instead of in generated bash: staged file filtering, tool discovery,
+//! running the checks concurrently and timing them. Checks come from the
+//! project type's defaults plus the `[hooks]` table of `.zackstrap.toml`.
+
+use super::checks::{hook_checks, HookCheck, HookKind, FILES_PLACEHOLDER};
+use super::shell_quote;
+use crate::error::ZackstrapError;
+use crate::generators::git::{git, GitRepo};
+use crate::generators::toolchain::Toolchain;
+use crate::generators::ProjectType;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::process::Command;
+use tokio::sync::Semaphore;
+use tokio::task::{self, JoinSet};
+
+/// Marker line identifying a native hook shim.
+pub const SHIM_MARKER: &str = "# zackstrap native hook";
+
+/// Words that start shell syntax rather than name a program, so the check's
+/// tool cannot be discovered up front.
+const SHELL_WORDS: &[&str] = &[
+    "if", "for", "while", "case", "[", "[[", "test", "!", "{", "(", "cd", "export", "exec",
+];
+
+/// The `[hooks]` table of `.zackstrap.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct HooksConfig {
+    /// Project type whose checks run. Detected from the project when unset.
+    pub language: Option<ProjectType>,
+    /// Template whose checks run (defaults to `default`).
+    pub template: Option<String>,
+    /// Ids of default checks to leave out, e.g. `cargo-build`.
+    pub skip: Vec<String>,
+    /// Extra checks run after the defaults.
+    pub checks: Vec<CustomCheck>,
+    /// Checks run at the same time (defaults to the number of CPUs; `1`
+    /// runs them one after another).
+    pub jobs: Option<usize>,
+}
+
+/// A check declared in `[[hooks.checks]]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CustomCheck {
+    pub id: String,
+    /// Display name (defaults to the id).
+    pub name: Option<String>,
+    /// Shell command; `{files}` expands to the matching staged files.
+    pub command: String,
+    /// `pre-commit` (default) or `pre-push`.
+    #[serde(default = "default_check_hook")]
+    pub hook: HookKind,
+    /// Only run when staged files with these extensions exist.
+    #[serde(default)]
+    pub extensions: Vec<String>,
+    /// Run alone, before the other checks, because the command rewrites
+    /// files they read.
+    #[serde(default)]
+    pub serial: bool,
+}
+
+fn default_check_hook() -> HookKind {
+    HookKind::PreCommit
+}
+
+impl HooksConfig {
+    /// Check that custom checks are usable.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.jobs == Some(0) {
+            return Err("jobs must be at least 1".to_string());
+        }
+        for (index, check) in self.checks.iter().enumerate() {
+            if check.id.is_empty() || check.command.trim().is_empty() {
+                return Err(format!("checks[{}] needs an id and a command", index));
+            }
+            if !matches!(check.hook, HookKind::PreCommit | HookKind::PrePush) {
+                return Err(format!(
+                    "check {} runs on {}; only pre-commit and pre-push run checks",
+                    check.id,
+                    check.hook.as_str()
+                ));
+            }
+            if self.checks[..index]
+                .iter()
+                .any(|other| other.id == check.id)
+            {
+                return Err(format!("duplicate check id {}", check.id));
+            }
+        }
+        Ok(())
+    }
+
+    /// The `[hooks]` table written by `--hooks-manager native`.
+    pub fn render(project_type: &ProjectType, template: &str) -> String {
+        format!(
+            "[hooks]\nlanguage = \"{}\"\ntemplate = \"{}\"\n",
+            project_type.as_str(),
+            template
+        )
+    }
+}
+
+/// A check the runner executes, from the built-in list or the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunnerCheck {
+    pub id: String,
+    pub name: String,
+    pub command: String,
+    pub hook: HookKind,
+    pub extensions: Vec<String>,
+    /// File names and root directories matched besides `extensions`.
+    pub paths: Vec<String>,
+    /// Runs alone, before the other checks.
+    pub serial: bool,
+}
+
+impl From<&HookCheck> for RunnerCheck {
+    fn from(check: &HookCheck) -> Self {
+        Self {
+            id: check.id.to_string(),
+            name: check.name.to_string(),
+            command: check.command.to_string(),
+            hook: check.hook,
+            extensions: check.extensions.iter().map(|ext| ext.to_string()).collect(),
+            paths: check.paths.iter().map(|path| path.to_string()).collect(),
+            serial: check.serial,
+        }
+    }
+}
+
+impl From<&CustomCheck> for RunnerCheck {
+    fn from(check: &CustomCheck) -> Self {
+        Self {
+            id: check.id.clone(),
+            name: check.name.clone().unwrap_or_else(|| check.id.clone()),
+            command: check.command.clone(),
+            hook: check.hook,
+            extensions: check.extensions.clone(),
+            paths: Vec::new(),
+            serial: check.serial,
+        }
+    }
+}
+
+impl RunnerCheck {
+    /// Whether the check only runs when matching files are staged.
+    pub fn on_staged(&self) -> bool {
+        !self.extensions.is_empty() || !self.paths.is_empty()
+    }
+
+    /// Whether `path` has one of the check's extensions or matches one of
+    /// its paths.
+    pub fn matches(&self, path: &str) -> bool {
+        let file = Path::new(path);
+        let by_extension = file
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| self.extensions.iter().any(|e| e == ext));
+        by_extension
+            || self.paths.iter().any(|pattern| {
+                if pattern.ends_with('/') {
+                    path.starts_with(pattern.as_str())
+                } else {
+                    file.file_name().and_then(|name| name.to_str()) == Some(pattern)
+                }
+            })
+    }
+
+    /// The program the command starts with, when it is not shell syntax.
+    pub fn program(&self) -> Option<&str> {
+        let first = self.command.split_whitespace().next()?;
+        if SHELL_WORDS.contains(&first) || first.contains('=') {
+            return None;
+        }
+        Some(first)
+    }
+}
+
+/// How a check ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    Passed,
+    Failed,
+    /// The check's program is not installed.
+    Missing(String),
+    /// No staged files match the check.
+    Skipped,
+}
+
+/// The result of one check.
+#[derive(Debug, Clone)]
+pub struct CheckOutcome {
+    pub check: RunnerCheck,
+    pub status: CheckStatus,
+    pub duration: Duration,
+    /// Combined stdout and stderr.
+    pub output: String,
+}
+
+impl CheckOutcome {
+    /// Whether the check makes the hook fail.
+    pub fn failed(&self) -> bool {
+        matches!(self.status, CheckStatus::Failed | CheckStatus::Missing(_))
+    }
+}
+
+/// Runs the checks of a `pre-commit` or `pre-push` hook.
+pub struct HookRunner {
+    repo: GitRepo,
+    kind: HookKind,
+    checks: Vec<RunnerCheck>,
+    jobs: usize,
+    progress: Option<MultiProgress>,
+}
+
+impl HookRunner {
+    /// The checks `kind` runs for a project: the built-in checks for the
+    /// toolchain found in the work tree minus the skipped ones, then the
+    /// manifest's own.
+    pub fn new(
+        repo: GitRepo,
+        kind: HookKind,
+        project_type: &ProjectType,
+        config: &HooksConfig,
+    ) -> Self {
+        let template = config.template.as_deref().unwrap_or("default");
+        let toolchain = Toolchain::detect(&repo.work_tree);
+        let built_in = hook_checks(project_type, template, &toolchain);
+        let checks = built_in
+            .iter()
+            .filter(|check| !config.skip.iter().any(|id| id == check.id))
+            .map(RunnerCheck::from)
+            .chain(config.checks.iter().map(RunnerCheck::from))
+            .filter(|check| check.hook == kind)
+            .collect();
+        let jobs = config.jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        });
+        Self {
+            repo,
+            kind,
+            checks,
+            jobs,
+            progress: None,
+        }
+    }
+
+    /// Show a spinner per running check in `progress`.
+    pub fn with_progress(mut self, progress: MultiProgress) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// The checks [`run`](Self::run) executes, in order.
+    #[allow(dead_code)]
+    pub fn checks(&self) -> &[RunnerCheck] {
+        &self.checks
+    }
+
+    /// Run the checks concurrently, at most `jobs` at a time, and return
+    /// every outcome in check order; a failure does not stop the others.
+    /// Serial checks run first, one at a time, so the rest see the files
+    /// they rewrite. Pre-commit checks see only the staged content:
+    /// unstaged changes are set aside for the duration of the run.
+    pub async fn run(&self) -> Result<Vec<CheckOutcome>, ZackstrapError> {
+        let (_stash, staged) = if self.kind == HookKind::PreCommit {
+            (
+                UnstagedStash::save(&self.repo)?,
+                staged_files(&self.repo.work_tree)?,
+            )
+        } else {
+            (None, Vec::new())
+        };
+
+        let mut outcomes: Vec<Option<CheckOutcome>> = vec![None; self.checks.len()];
+        let mut runnable = Vec::new();
+        for (index, check) in self.checks.iter().enumerate() {
+            match self.prepare(check, &staged) {
+                Ok(files) => runnable.push((index, files)),
+                Err(status) => {
+                    outcomes[index] = Some(CheckOutcome {
+                        check: check.clone(),
+                        status,
+                        duration: Duration::ZERO,
+                        output: String::new(),
+                    })
+                }
+            }
+        }
+        let (serial, parallel): (Vec<_>, Vec<_>) = runnable
+            .into_iter()
+            .partition(|(index, _)| self.checks[*index].serial);
+
+        let mut batch = CheckBatch::new(self.jobs);
+        for (index, files) in serial {
+            batch.spawn(self, index, files);
+            batch.join(self, &mut outcomes).await?;
+        }
+        for (index, files) in parallel {
+            batch.spawn(self, index, files);
+        }
+        batch.join(self, &mut outcomes).await?;
+        Ok(outcomes.into_iter().flatten().collect())
+    }
+
+    /// The staged files `check` runs on, or why it cannot run: no matching
+    /// staged files, or its program is not installed.
+    fn prepare(&self, check: &RunnerCheck, staged: &[String]) -> Result<Vec<String>, CheckStatus> {
+        let files: Vec<String> = staged
+            .iter()
+            .filter(|f| check.matches(f))
+            .cloned()
+            .collect();
+        if self.kind == HookKind::PreCommit && check.on_staged() && files.is_empty() {
+            return Err(CheckStatus::Skipped);
+        }
+        if let Some(program) = check.program() {
+            if find_program(program).is_none() {
+                return Err(CheckStatus::Missing(program.to_string()));
+            }
+        }
+        Ok(files)
+    }
+
+    fn spinner(&self, check: &RunnerCheck) -> Option<ProgressBar> {
+        let spinner = self.progress.as_ref()?.add(ProgressBar::new_spinner());
+        spinner.set_style(
+            ProgressStyle::with_template("  {spinner} {msg} {elapsed:.dim}")
+                .expect("spinner template is valid"),
+        );
+        spinner.set_message(format!("{} (queued)", check.name));
+        spinner.enable_steady_tick(Duration::from_millis(100));
+        Some(spinner)
+    }
+}
+
+/// Check tasks in flight, limited to `jobs` at a time.
+struct CheckBatch {
+    tasks: JoinSet<(usize, Result<CheckOutcome, ZackstrapError>)>,
+    /// The check index and spinner of each task, to report a panic.
+    running: HashMap<task::Id, (usize, Option<ProgressBar>)>,
+    semaphore: Arc<Semaphore>,
+}
+
+impl CheckBatch {
+    fn new(jobs: usize) -> Self {
+        Self {
+            tasks: JoinSet::new(),
+            running: HashMap::new(),
+            semaphore: Arc::new(Semaphore::new(jobs.max(1))),
+        }
+    }
+
+    fn spawn(&mut self, runner: &HookRunner, index: usize, files: Vec<String>) {
+        let check = runner.checks[index].clone();
+        let spinner = runner.spinner(&check);
+        let semaphore = Arc::clone(&self.semaphore);
+        let work_tree = runner.repo.work_tree.clone();
+        let task_spinner = spinner.clone();
+        let handle = self.tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("semaphore is open");
+            if let Some(spinner) = &task_spinner {
+                spinner.set_message(check.name.clone());
+            }
+            let outcome = run_check(&work_tree, check, &files).await;
+            if let Some(spinner) = task_spinner {
+                spinner.finish_and_clear();
+            }
+            (index, outcome)
+        });
+        self.running.insert(handle.id(), (index, spinner));
+    }
+
+    /// Wait for every spawned check. A check whose task panicked fails
+    /// with the panic as its output instead of aborting the hook.
+    async fn join(
+        &mut self,
+        runner: &HookRunner,
+        outcomes: &mut [Option<CheckOutcome>],
+    ) -> Result<(), ZackstrapError> {
+        while let Some(joined) = self.tasks.join_next_with_id().await {
+            match joined {
+                Ok((id, (index, outcome))) => {
+                    self.running.remove(&id);
+                    outcomes[index] = Some(outcome?);
+                }
+                Err(error) => {
+                    let (index, spinner) = self
+                        .running
+                        .remove(&error.id())
+                        .expect("every task is tracked");
+                    if let Some(spinner) = spinner {
+                        spinner.finish_and_clear();
+                    }
+                    outcomes[index] = Some(CheckOutcome {
+                        check: runner.checks[index].clone(),
+                        status: CheckStatus::Failed,
+                        duration: Duration::ZERO,
+                        output: error.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Run one check's command from the work tree root, with `{files}`
+/// expanded to `files`.
+async fn run_check(
+    work_tree: &Path,
+    check: RunnerCheck,
+    files: &[String],
+) -> Result<CheckOutcome, ZackstrapError> {
+    let quoted: Vec<String> = files.iter().map(|f| shell_quote(f)).collect();
+    let command = check.command.replace(FILES_PLACEHOLDER, &quoted.join(" "));
+
+    let start = Instant::now();
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .current_dir(work_tree)
+        .stdin(std::process::Stdio::null())
+        .output()
+        .await?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+
+    let status = if output.status.success() {
+        CheckStatus::Passed
+    } else {
+        CheckStatus::Failed
+    };
+    Ok(CheckOutcome {
+        check,
+        status,
+        duration: start.elapsed(),
+        output: text,
+    })
+}
+
+/// Files staged for commit (added, copied or modified), relative to the
+/// work tree root.
+pub fn staged_files(work_tree: &Path) -> Result<Vec<String>, ZackstrapError> {
+    let output = git(
+        work_tree,
+        &["diff", "--cached", "--name-only", "--diff-filter=ACM", "-z"],
+    )?;
+    Ok(String::from_utf8_lossy(&output)
+        .split('\0')
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Unstaged changes saved as a patch and removed from the work tree, put
+/// back when dropped.
+struct UnstagedStash {
+    work_tree: PathBuf,
+    patch: PathBuf,
+}
+
+impl UnstagedStash {
+    fn save(repo: &GitRepo) -> Result<Option<Self>, ZackstrapError> {
+        let work_tree = &repo.work_tree;
+        let diff = git(
+            work_tree,
+            &["diff", "--binary", "--no-color", "--no-ext-diff"],
+        )?;
+        if diff.is_empty() {
+            return Ok(None);
+        }
+
+        let patch = write_new_patch(&repo.git_dir, &diff)?;
+        git(work_tree, &["checkout", "--", "."])?;
+        println!("📦 Stashed unstaged changes in {}", patch.display());
+        Ok(Some(Self {
+            work_tree: work_tree.clone(),
+            patch,
+        }))
+    }
+}
+
+/// Write `diff` to a patch file in `git_dir` that did not exist before, so a
+/// patch left behind by a failed restore is never overwritten.
+fn write_new_patch(git_dir: &Path, diff: &[u8]) -> Result<PathBuf, ZackstrapError> {
+    use std::io::Write;
+
+    let pid = std::process::id();
+    let mut attempt = 0;
+    loop {
+        let patch = git_dir.join(format!("zackstrap-unstaged-{}-{}.patch", pid, attempt));
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&patch);
+        match file {
+            Ok(mut file) => {
+                file.write_all(diff)
+                    .map_err(|e| ZackstrapError::WriteFileError(patch.clone(), e))?;
+                return Ok(patch);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => attempt += 1,
+            Err(e) => return Err(ZackstrapError::WriteFileError(patch, e)),
+        }
+    }
+}
+
+impl Drop for UnstagedStash {
+    fn drop(&mut self) {
+        let restored = std::process::Command::new("git")
+            .args(["apply", "--whitespace=nowarn"])
+            .arg(&self.patch)
+            .current_dir(&self.work_tree)
+            .status()
+            .is_ok_and(|status| status.success());
+        if restored {
+            let _ = std::fs::remove_file(&self.patch);
+        } else {
+            println!(
+                "⚠️  Could not restore unstaged changes; they are saved in {}",
+                self.patch.display()
+            );
+        }
+    }
+}
+
+/// Look `program` up on `PATH`, or check it directly when it is a path.
+pub fn find_program(program: &str) -> Option<PathBuf> {
+    if program.contains('/') {
+        let path = PathBuf::from(program);
+        return is_executable(&path).then_some(path);
+    }
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join(program))
+        .find(|path| is_executable(path))
+}
+
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+/// The shim installed as each hook by `--hooks-manager native`.
+pub fn shim_script(kind: HookKind) -> String {
+    format!(
+        r#"#!/bin/sh
+{marker}
+# Checks are configured in the [hooks] table of .zackstrap.toml
+if ! command -v zackstrap >/dev/null 2>&1; then
+    echo "❌ zackstrap not found on PATH; install it with 'cargo install zackstrap'"
+    exit 1
+fi
+exec zackstrap hook-run {hook} "$@"
+"#,
+        marker = SHIM_MARKER,
+        hook = kind.as_str()
+    )
+}
@@ -0,0 +1,642 @@
+use serde_json::Value as JsonValue;
+use serde_yaml::{Mapping, Value as YamlValue};
+use toml_edit::{DocumentMut, Item, Table, Value};
+
+/// How generated content is combined with a file that already exists.
+#[derive(Debug, Clone, Copy)]
+pub enum MergeStrategy {
+    /// Merge the listed dotted TOML table paths (e.g. `tool.black`) into the
+    /// existing document, leaving every other table untouched.
+    Toml(&'static [&'static str]),
+    /// Like `Toml`, for manifests owned by another tool such as
+    /// `Cargo.toml`: zackstrap only contributes its tables, so `--force`
+    /// merges instead of replacing the file.
+    Manifest(&'static [&'static str]),
+    /// Deep-merge a YAML mapping: missing keys are added, sequences such as
+    /// `Exclude` lists are unioned, and untouched top-level sections keep
+    /// their original text (including comments).
+    Yaml,
+    /// Deep-merge a JSON object such as `package.json`: missing keys are
+    /// added, arrays are unioned and existing values win. Key order is kept.
+    Json,
+    /// Line-oriented files such as `.gitignore` or `.dockerignore`: the
+    /// generated lines live between zackstrap markers, and only that block
+    /// is ever inserted or replaced.
+    ManagedBlock,
+}
+
+/// First line of a zackstrap managed block.
+pub const BLOCK_START: &str = "# >>> zackstrap";
+/// Last line of a zackstrap managed block.
+pub const BLOCK_END: &str = "# <<< zackstrap";
+
+/// Result of merging generated content into an existing file.
+#[derive(Debug, Clone)]
+pub struct MergeOutcome {
+    pub content: String,
+    /// Settings where the existing file disagrees with zackstrap. The
+    /// existing value is always kept.
+    pub conflicts: Vec<String>,
+}
+
+impl MergeStrategy {
+    pub fn apply(&self, existing: &str, generated: &str) -> Result<MergeOutcome, String> {
+        match self {
+            MergeStrategy::Toml(owned) | MergeStrategy::Manifest(owned) => {
+                merge_toml(existing, generated, owned)
+            }
+            MergeStrategy::Yaml => merge_yaml(existing, generated),
+            MergeStrategy::Json => merge_json(existing, generated),
+            MergeStrategy::ManagedBlock => Ok(merge_managed_block(existing, generated)),
+        }
+    }
+
+    /// Content written when the file does not exist yet.
+    pub fn initial_content(&self, generated: &str) -> String {
+        match self {
+            MergeStrategy::ManagedBlock => managed_block(generated),
+            _ => generated.to_string(),
+        }
+    }
+
+    /// Whether `--force` may replace the whole file. Managed blocks never
+    /// touch lines outside their markers, and JSON and manifest merges only
+    /// contribute a few keys to a file owned by something else (e.g.
+    /// `package.json` or `Cargo.toml`).
+    pub fn allows_overwrite(&self) -> bool {
+        !matches!(
+            self,
+            MergeStrategy::ManagedBlock | MergeStrategy::Json | MergeStrategy::Manifest(_)
+        )
+    }
+}
+
+/// Merge the `owned` tables of `generated` into `existing`, preserving the
+/// existing document's comments, formatting and table order.
+///
+/// Missing tables and keys are added; keys already present keep their
+/// existing value and are reported as conflicts when they differ.
+pub fn merge_toml(existing: &str, generated: &str, owned: &[&str]) -> Result<MergeOutcome, String> {
+    let mut doc: DocumentMut = existing
+        .parse()
+        .map_err(|e| format!("invalid TOML: {}", e))?;
+    let source: DocumentMut = generated
+        .parse()
+        .map_err(|e| format!("invalid generated TOML: {}", e))?;
+
+    let mut conflicts = Vec::new();
+    let mut next_position = max_position(doc.as_table()) + 1;
+
+    for path in owned {
+        let segments: Vec<&str> = path.split('.').collect();
+        let Some(item) = lookup(source.as_table(), &segments) else {
+            continue;
+        };
+
+        let (last, parents) = segments.split_last().expect("non-empty table path");
+        let table = match parent_table(doc.as_table_mut(), parents) {
+            Ok(table) => table,
+            Err(segment) => {
+                conflicts.push(format!(
+                    "{}: existing value is not a table, skipping [{}]",
+                    segment, path
+                ));
+                continue;
+            }
+        };
+
+        match table.get_mut(last) {
+            None => {
+                let mut item = item.clone();
+                reposition(&mut item, &mut next_position);
+                table.insert(last, item);
+            }
+            Some(existing_item) => merge_item(
+                existing_item,
+                item,
+                path,
+                &mut next_position,
+                &mut conflicts,
+            ),
+        }
+    }
+
+    Ok(MergeOutcome {
+        content: doc.to_string(),
+        conflicts,
+    })
+}
+
+fn merge_item(
+    existing: &mut Item,
+    generated: &Item,
+    path: &str,
+    next_position: &mut usize,
+    conflicts: &mut Vec<String>,
+) {
+    match (existing.as_table_mut(), generated.as_table()) {
+        (Some(existing_table), Some(generated_table)) => {
+            for (key, item) in generated_table.iter() {
+                let child_path = format!("{}.{}", path, key);
+                match existing_table.get_mut(key) {
+                    None => {
+                        let mut item = item.clone();
+                        reposition(&mut item, next_position);
+                        existing_table.insert(key, item);
+                    }
+                    Some(existing_item) => {
+                        merge_item(existing_item, item, &child_path, next_position, conflicts)
+                    }
+                }
+            }
+        }
+        _ => match (existing.as_value(), generated.as_value()) {
+            (Some(a), Some(b)) if values_equal(a, b) => {}
+            (Some(a), Some(b)) => conflicts.push(format!(
+                "{}: keeping existing value {} (zackstrap default: {})",
+                path,
+                a.to_string().trim(),
+                b.to_string().trim()
+            )),
+            _ => conflicts.push(format!(
+                "{}: existing entry has a different type, keeping it",
+                path
+            )),
+        },
+    }
+}
+
+/// Walk (and create, as implicit tables) the parents of an owned table.
+/// Returns the offending key when an existing parent is not a table.
+fn parent_table<'a>(table: &'a mut Table, segments: &[&str]) -> Result<&'a mut Table, String> {
+    let Some((first, rest)) = segments.split_first() else {
+        return Ok(table);
+    };
+    let entry = table.entry(first).or_insert_with(|| {
+        let mut implicit = Table::new();
+        implicit.set_implicit(true);
+        Item::Table(implicit)
+    });
+    match entry.as_table_mut() {
+        Some(child) => parent_table(child, rest),
+        None => Err(first.to_string()),
+    }
+}
+
+fn lookup<'a>(table: &'a Table, segments: &[&str]) -> Option<&'a Item> {
+    let (first, rest) = segments.split_first()?;
+    let item = table.get(first)?;
+    if rest.is_empty() {
+        Some(item)
+    } else {
+        lookup(item.as_table()?, rest)
+    }
+}
+
+fn max_position(table: &Table) -> usize {
+    table
+        .iter()
+        .filter_map(|(_, item)| item.as_table())
+        .map(|t| t.position().unwrap_or(0).max(max_position(t)))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Place inserted tables after everything already in the document, in the
+/// order zackstrap generated them.
+fn reposition(item: &mut Item, next_position: &mut usize) {
+    if let Some(table) = item.as_table_mut() {
+        table.set_position(*next_position);
+        *next_position += 1;
+        for (_, child) in table.iter_mut() {
+            reposition(child, next_position);
+        }
+    }
+}
+
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.value() == b.value(),
+        (Value::Integer(a), Value::Integer(b)) => a.value() == b.value(),
+        (Value::Float(a), Value::Float(b)) => a.value() == b.value(),
+        (Value::Boolean(a), Value::Boolean(b)) => a.value() == b.value(),
+        (Value::Datetime(a), Value::Datetime(b)) => a.value() == b.value(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| values_equal(a, b))
+        }
+        (Value::InlineTable(a), Value::InlineTable(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, value)| b.get(key).is_some_and(|other| values_equal(value, other)))
+        }
+        _ => false,
+    }
+}
+
+/// Merge a generated YAML mapping into an existing YAML document.
+///
+/// Top-level sections are handled as text blocks: sections zackstrap does not
+/// touch are kept verbatim, new sections are appended using the generated
+/// text, and only sections that actually change are re-rendered (which drops
+/// comments inside that section alone).
+pub fn merge_yaml(existing: &str, generated: &str) -> Result<MergeOutcome, String> {
+    let existing_value =
+        parse_yaml_mapping(existing).map_err(|e| format!("invalid YAML: {}", e))?;
+    let generated_value =
+        parse_yaml_mapping(generated).map_err(|e| format!("invalid generated YAML: {}", e))?;
+
+    let mut conflicts = Vec::new();
+    let mut blocks = split_yaml_blocks(existing);
+    let generated_blocks = split_yaml_blocks(generated);
+
+    // Fall back to a full re-render when the layout is not plain block style
+    // (flow mappings, top-level sequences, multi-line keys, ...).
+    let block_keys: Vec<&str> = blocks.iter().filter_map(|b| b.key.as_deref()).collect();
+    let layout_understood = block_keys.len() == existing_value.len()
+        && existing_value
+            .keys()
+            .all(|k| k.as_str().is_some_and(|k| block_keys.contains(&k)));
+
+    if !layout_understood {
+        let merged = merge_yaml_value(
+            &YamlValue::Mapping(existing_value),
+            &YamlValue::Mapping(generated_value),
+            "",
+            &mut conflicts,
+        );
+        let content = serde_yaml::to_string(&merged).map_err(|e| e.to_string())?;
+        return Ok(MergeOutcome { content, conflicts });
+    }
+
+    let mut appended = String::new();
+    for (key, value) in &generated_value {
+        let Some(name) = key.as_str() else {
+            continue;
+        };
+
+        match existing_value.get(key) {
+            None => {
+                let text = generated_blocks
+                    .iter()
+                    .find(|b| b.key.as_deref() == Some(name))
+                    .map(|b| b.text.clone())
+                    .map(Ok)
+                    .unwrap_or_else(|| render_yaml_section(name, value))?;
+                appended.push_str(&text);
+            }
+            Some(current) => {
+                let merged = merge_yaml_value(current, value, name, &mut conflicts);
+                if merged != *current {
+                    let block = blocks
+                        .iter_mut()
+                        .find(|b| b.key.as_deref() == Some(name))
+                        .expect("layout checked above");
+                    block.text =
+                        format!("{}{}", block.leading(), render_yaml_section(name, &merged)?);
+                }
+            }
+        }
+    }
+
+    let mut content: String = blocks.into_iter().map(|b| b.text).collect();
+    if !appended.is_empty() {
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        if !content.is_empty() && !content.ends_with("\n\n") && !appended.starts_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(&appended);
+    }
+
+    Ok(MergeOutcome { content, conflicts })
+}
+
+fn parse_yaml_mapping(text: &str) -> Result<Mapping, serde_yaml::Error> {
+    match serde_yaml::from_str::<YamlValue>(text)? {
+        YamlValue::Null => Ok(Mapping::new()),
+        YamlValue::Mapping(mapping) => Ok(mapping),
+        _ => Err(serde::de::Error::custom(
+            "expected a mapping at the top level",
+        )),
+    }
+}
+
+fn render_yaml_section(key: &str, value: &YamlValue) -> Result<String, String> {
+    let mut section = Mapping::new();
+    section.insert(YamlValue::String(key.to_string()), value.clone());
+    serde_yaml::to_string(&section).map_err(|e| e.to_string())
+}
+
+fn merge_yaml_value(
+    existing: &YamlValue,
+    generated: &YamlValue,
+    path: &str,
+    conflicts: &mut Vec<String>,
+) -> YamlValue {
+    match (existing, generated) {
+        (YamlValue::Mapping(current), YamlValue::Mapping(defaults)) => {
+            let mut merged = current.clone();
+            for (key, value) in defaults {
+                let child_path = match key.as_str() {
+                    Some(name) if path.is_empty() => name.to_string(),
+                    Some(name) => format!("{}.{}", path, name),
+                    None => path.to_string(),
+                };
+                let entry = match current.get(key) {
+                    Some(existing) => merge_yaml_value(existing, value, &child_path, conflicts),
+                    None => value.clone(),
+                };
+                merged.insert(key.clone(), entry);
+            }
+            drop_opposed_entries(current, &mut merged, path, conflicts);
+            YamlValue::Mapping(merged)
+        }
+        (YamlValue::Sequence(current), YamlValue::Sequence(defaults)) => {
+            let mut merged = current.clone();
+            for item in defaults {
+                if !merged.contains(item) {
+                    merged.push(item.clone());
+                }
+            }
+            YamlValue::Sequence(merged)
+        }
+        (a, b) if a == b => a.clone(),
+        (a, b) => {
+            conflicts.push(format!(
+                "{}: keeping existing value {} (zackstrap default: {})",
+                path,
+                yaml_inline(a),
+                yaml_inline(b)
+            ));
+            a.clone()
+        }
+    }
+}
+
+/// Paired lists (golangci-lint's `linters.enable`/`linters.disable`) where
+/// the existing file's choice wins: entries the defaults would add to one
+/// list are dropped when the existing file puts them in the other.
+const OPPOSED_LISTS: &[(&str, &str)] = &[("enable", "disable"), ("disable", "enable")];
+
+fn drop_opposed_entries(
+    current: &serde_yaml::Mapping,
+    merged: &mut serde_yaml::Mapping,
+    path: &str,
+    conflicts: &mut Vec<String>,
+) {
+    for &(list, opposite) in OPPOSED_LISTS {
+        let Some(YamlValue::Sequence(chosen)) = current.get(opposite) else {
+            continue;
+        };
+        let kept = match current.get(list) {
+            Some(YamlValue::Sequence(items)) => items.as_slice(),
+            _ => &[],
+        };
+        let Some(YamlValue::Sequence(entries)) = merged.get_mut(list) else {
+            continue;
+        };
+        let list_path = if path.is_empty() {
+            list.to_string()
+        } else {
+            format!("{}.{}", path, list)
+        };
+        entries.retain(|item| {
+            let clash = !kept.contains(item) && chosen.contains(item);
+            if clash {
+                conflicts.push(format!(
+                    "{}: not adding {} (listed in {})",
+                    list_path,
+                    yaml_inline(item),
+                    opposite
+                ));
+            }
+            !clash
+        });
+        if entries.is_empty() && !current.contains_key(list) {
+            merged.remove(list);
+        }
+    }
+}
+
+fn yaml_inline(value: &YamlValue) -> String {
+    serde_yaml::to_string(value)
+        .map(|s| s.trim().replace('\n', " "))
+        .unwrap_or_default()
+}
+
+/// A top-level section of a YAML document, kept as raw text.
+struct YamlBlock {
+    key: Option<String>,
+    text: String,
+}
+
+impl YamlBlock {
+    /// Comment and blank lines that precede the section's key line.
+    fn leading(&self) -> &str {
+        let mut end = 0;
+        for line in self.text.split_inclusive('\n') {
+            let trimmed = line.trim();
+            if !(trimmed.is_empty() || trimmed.starts_with('#')) {
+                break;
+            }
+            end += line.len();
+        }
+        &self.text[..end]
+    }
+}
+
+fn split_yaml_blocks(text: &str) -> Vec<YamlBlock> {
+    let mut blocks = vec![YamlBlock {
+        key: None,
+        text: String::new(),
+    }];
+
+    for line in text.split_inclusive('\n') {
+        if let Some(key) = top_level_key(line) {
+            // Comments and blank lines directly above a key belong to it,
+            // except for the file header.
+            let current = blocks.last_mut().expect("at least one block");
+            let leading = match current.key {
+                Some(_) => current
+                    .text
+                    .split_off(trailing_comment_start(&current.text)),
+                None => String::new(),
+            };
+            blocks.push(YamlBlock {
+                key: Some(key),
+                text: leading,
+            });
+        }
+        blocks
+            .last_mut()
+            .expect("at least one block")
+            .text
+            .push_str(line);
+    }
+
+    blocks
+}
+
+/// Offset where the run of top-level comment and blank lines ending `text`
+/// starts.
+fn trailing_comment_start(text: &str) -> usize {
+    let mut offset = 0;
+    let mut run_start = None;
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() || line.starts_with('#') {
+            run_start.get_or_insert(offset);
+        } else {
+            run_start = None;
+        }
+        offset += line.len();
+    }
+    run_start.unwrap_or(text.len())
+}
+
+fn top_level_key(line: &str) -> Option<String> {
+    let first = line.chars().next()?;
+    if first.is_whitespace() || matches!(first, '#' | '-' | '{' | '[' | '?' | '%') {
+        return None;
+    }
+
+    if first == '\'' || first == '"' {
+        let end = line[1..].find(first)? + 1;
+        return line[end + 1..]
+            .trim_start()
+            .starts_with(':')
+            .then(|| line[1..end].to_string());
+    }
+
+    let bytes = line.as_bytes();
+    let colon = (0..bytes.len())
+        .find(|&i| bytes[i] == b':' && bytes.get(i + 1).is_none_or(|c| c.is_ascii_whitespace()))?;
+    Some(line[..colon].trim_end().to_string())
+}
+
+/// Deep-merge the generated JSON object into `existing`. The existing text
+/// is returned untouched when nothing needs to be added; otherwise the
+/// document is re-rendered with two-space indentation.
+pub fn merge_json(existing: &str, generated: &str) -> Result<MergeOutcome, String> {
+    let current: JsonValue =
+        serde_json::from_str(existing).map_err(|e| format!("invalid JSON: {}", e))?;
+    let defaults: JsonValue =
+        serde_json::from_str(generated).map_err(|e| format!("invalid generated JSON: {}", e))?;
+    if !current.is_object() {
+        return Err("expected an object at the top level".to_string());
+    }
+
+    let mut conflicts = Vec::new();
+    let merged = merge_json_value(&current, &defaults, "", &mut conflicts);
+    let content = if merged == current {
+        existing.to_string()
+    } else {
+        let mut rendered = serde_json::to_string_pretty(&merged).map_err(|e| e.to_string())?;
+        rendered.push('\n');
+        rendered
+    };
+
+    Ok(MergeOutcome { content, conflicts })
+}
+
+fn merge_json_value(
+    existing: &JsonValue,
+    generated: &JsonValue,
+    path: &str,
+    conflicts: &mut Vec<String>,
+) -> JsonValue {
+    match (existing, generated) {
+        (JsonValue::Object(current), JsonValue::Object(defaults)) => {
+            let mut merged = current.clone();
+            for (key, value) in defaults {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                let entry = match current.get(key) {
+                    Some(existing) => merge_json_value(existing, value, &child_path, conflicts),
+                    None => value.clone(),
+                };
+                merged.insert(key.clone(), entry);
+            }
+            JsonValue::Object(merged)
+        }
+        (JsonValue::Array(current), JsonValue::Array(defaults)) => {
+            let mut merged = current.clone();
+            for item in defaults {
+                if !merged.contains(item) {
+                    merged.push(item.clone());
+                }
+            }
+            JsonValue::Array(merged)
+        }
+        (a, b) if a == b => a.clone(),
+        (a, b) => {
+            conflicts.push(format!(
+                "{}: keeping existing value {} (zackstrap default: {})",
+                path, a, b
+            ));
+            a.clone()
+        }
+    }
+}
+
+/// Wrap `lines` in zackstrap's managed block markers.
+pub fn managed_block(lines: &str) -> String {
+    let mut block = format!(
+        "{} (managed block, edits inside will be replaced)\n",
+        BLOCK_START
+    );
+    for line in lines.lines() {
+        block.push_str(line);
+        block.push('\n');
+    }
+    block.push_str(BLOCK_END);
+    block.push('\n');
+    block
+}
+
+/// Insert or replace zackstrap's managed block in a line-oriented file,
+/// leaving every line outside the markers untouched.
+pub fn merge_managed_block(existing: &str, generated: &str) -> MergeOutcome {
+    let block = managed_block(generated);
+    let lines: Vec<&str> = existing.split_inclusive('\n').collect();
+
+    let start = lines.iter().position(|l| l.starts_with(BLOCK_START));
+    let end = start.and_then(|start| {
+        lines[start..]
+            .iter()
+            .position(|l| l.starts_with(BLOCK_END))
+            .map(|offset| start + offset)
+    });
+
+    let content = match (start, end) {
+        (Some(start), Some(end)) => {
+            let before: String = lines[..start].concat();
+            let after: String = lines[end + 1..].concat();
+            format!("{}{}{}", before, block, after)
+        }
+        _ => {
+            let mut content = existing.to_string();
+            if !content.is_empty() {
+                if !content.ends_with('\n') {
+                    content.push('\n');
+                }
+                content.push('\n');
+            }
+            content.push_str(&block);
+            content
+        }
+    };
+
+    let conflicts = match (start, end) {
+        (Some(_), None) => vec![format!(
+            "unterminated zackstrap block (missing '{}'), appending a new one",
+            BLOCK_END
+        )],
+        _ => Vec::new(),
+    };
+
+    MergeOutcome { content, conflicts }
+}
@@ -1,18 +1,24 @@
 use crate::error::ZackstrapError;
+use common::WrittenPaths;
 use std::path::PathBuf;
+use toolchain::Toolchain;
 
 // Module declarations
 pub mod bash;
 pub mod basic;
 pub mod common;
+pub mod git;
 pub mod go;
 pub mod hooks;
+pub mod merge;
 pub mod node;
 pub mod python;
 pub mod ruby;
 pub mod rust;
+pub mod toolchain;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, clap::ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ProjectType {
     Basic,
     Ruby,
@@ -23,10 +29,27 @@ pub enum ProjectType {
     Bash,
 }
 
+impl ProjectType {
+    /// The project type's command name, e.g. `ruby`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectType::Basic => "basic",
+            ProjectType::Ruby => "ruby",
+            ProjectType::Python => "python",
+            ProjectType::Node => "node",
+            ProjectType::Go => "go",
+            ProjectType::Rust => "rust",
+            ProjectType::Bash => "bash",
+        }
+    }
+}
+
 pub struct ConfigGenerator {
     target_dir: PathBuf,
     dry_run: bool,
     force: bool,
+    toolchain: Toolchain,
+    written: WrittenPaths,
 }
 
 impl ConfigGenerator {
@@ -36,6 +59,8 @@ impl ConfigGenerator {
             target_dir,
             dry_run: false,
             force: false,
+            toolchain: Toolchain::default(),
+            written: WrittenPaths::default(),
         }
     }
 
@@ -44,9 +69,24 @@ impl ConfigGenerator {
             target_dir,
             dry_run,
             force,
+            toolchain: Toolchain::default(),
+            written: WrittenPaths::default(),
         }
     }
 
+    /// Generate files for these tools instead of the defaults (pip and
+    /// Flake8 for Python).
+    pub fn with_toolchain(mut self, toolchain: Toolchain) -> Self {
+        self.toolchain = toolchain;
+        self
+    }
+
+    /// Record the files this generator writes in `written`.
+    pub fn with_written_paths(mut self, written: WrittenPaths) -> Self {
+        self.written = written;
+        self
+    }
+
     pub async fn detect_project_type(&self) -> Result<ProjectType, ZackstrapError> {
         // Check for Ruby project
         if self.target_dir.join("Gemfile").exists()
@@ -77,6 +117,8 @@ impl ConfigGenerator {
             || self.target_dir.join("package-lock.json").exists()
             || self.target_dir.join("yarn.lock").exists()
             || self.target_dir.join("pnpm-lock.yaml").exists()
+            || self.target_dir.join("bun.lock").exists()
+            || self.target_dir.join("bun.lockb").exists()
             || self.target_dir.join("node_modules").exists()
             || self.target_dir.join("index.js").exists()
             || self.target_dir.join("app.js").exists()
@@ -1012,16 +1054,17 @@ dev = [
 line-length = 88
 target-version = ['py312']
 
-[tool.flake8]
-max-line-length = 88
-extend-ignore = ["E203", "W503"]
-
 [tool.mypy]
 python_version = "3.12"
 warn_return_any = true
 warn_unused_configs = true
 strict = true
 
+[tool.pytest.ini_options]
+DJANGO_SETTINGS_MODULE = "myproject.settings"
+testpaths = ["tests"]
+addopts = "-ra"
+
 [tool.django-stubs]
 django_settings_module = "myproject.settings"
 "#
@@ -1056,16 +1099,16 @@ dev = [
 line-length = 88
 target-version = ['py312']
 
-[tool.flake8]
-max-line-length = 88
-extend-ignore = ["E203", "W503"]
-
 [tool.mypy]
 python_version = "3.12"
 warn_return_any = true
 warn_unused_configs = true
 strict = true
 
+[tool.pytest.ini_options]
+testpaths = ["tests"]
+addopts = "-ra"
+
 [tool.flask]
 app_name = "app"
 "#
@@ -1096,15 +1139,15 @@ dev = [
 line-length = 88
 target-version = ['py312']
 
-[tool.flake8]
-max-line-length = 88
-extend-ignore = ["E203", "W503"]
-
 [tool.mypy]
 python_version = "3.12"
 warn_return_any = true
 warn_unused_configs = true
 strict = true
+
+[tool.pytest.ini_options]
+testpaths = ["tests"]
+addopts = "-ra"
 "#
             }
         }
@@ -1,5 +1,16 @@
+use super::merge::MergeStrategy;
+use super::toolchain::{
+    find_config, EslintFormat, NodePackageManager, TsStrictness, FLAT_ESLINT_CONFIGS,
+    LEGACY_ESLINT_CONFIGS, NODE_WORKSPACE_GLOBS,
+};
 use crate::config::PackageJson;
 use crate::error::ZackstrapError;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// The flat config file zackstrap writes. `.mjs` is an ES module whether
+/// or not `package.json` sets `"type": "module"`.
+pub const FLAT_ESLINT_CONFIG: &str = "eslint.config.mjs";
 
 impl super::ConfigGenerator {
     #[allow(dead_code)]
@@ -13,8 +24,14 @@ impl super::ConfigGenerator {
 
         // Generate Node.js-specific configs
         self.generate_nvmrc().await?;
+        if self.toolchain.node.uses_typescript(template) {
+            self.generate_tsconfig(template).await?;
+        }
         self.generate_eslint_config(template).await?;
+        self.generate_build_tool_config(template).await?;
         self.generate_node_package_json(template).await?;
+        self.generate_package_manager_config().await?;
+        self.generate_node_gitignore(template).await?;
 
         // Overwrite the basic justfile with Node.js-specific one
         self.generate_node_justfile(template).await?;
@@ -27,7 +44,22 @@ impl super::ConfigGenerator {
         self.emit_file(".nvmrc", content, false, false).await
     }
 
+    async fn generate_tsconfig(&self, template: &str) -> Result<(), ZackstrapError> {
+        let content = tsconfig(template, self.toolchain.node.strictness);
+        self.emit_file("tsconfig.json", &content, false, false)
+            .await
+    }
+
     async fn generate_eslint_config(&self, template: &str) -> Result<(), ZackstrapError> {
+        if self.toolchain.node.eslint_format == EslintFormat::Flat {
+            return self.generate_eslint_flat_config(template).await;
+        }
+        if self.toolchain.node.uses_typescript(template) {
+            let content = typescript_eslint_config(template, self.toolchain.node.strictness);
+            return self
+                .emit_file(".eslintrc.json", &content, false, false)
+                .await;
+        }
         let content = match template {
             "express" => {
                 r#"{
@@ -86,16 +118,219 @@ impl super::ConfigGenerator {
             .await
     }
 
+    /// Write `eslint.config.mjs`, converted from the project's legacy
+    /// `.eslintrc.*` when it has one. ESLint only reads the first flat
+    /// config it finds, so a hand-written one under another name is kept.
+    async fn generate_eslint_flat_config(&self, template: &str) -> Result<(), ZackstrapError> {
+        if find_config(&self.target_dir, &FLAT_ESLINT_CONFIGS)
+            .is_some_and(|existing| existing != FLAT_ESLINT_CONFIG)
+        {
+            return Ok(());
+        }
+        let content = match find_config(&self.target_dir, &LEGACY_ESLINT_CONFIGS) {
+            Some(legacy) => migrated_eslint_config(&self.target_dir, legacy)?,
+            None => flat_eslint_config(
+                template,
+                self.toolchain.node.uses_typescript(template),
+                self.toolchain.node.strictness,
+            ),
+        };
+        self.emit_file(FLAT_ESLINT_CONFIG, &content, false, false)
+            .await
+    }
+
+    /// The bundler or framework CLI config of templates that need one.
+    async fn generate_build_tool_config(&self, template: &str) -> Result<(), ZackstrapError> {
+        match template {
+            "vite" => {
+                self.emit_file("vite.config.ts", VITE_CONFIG, false, false)
+                    .await
+            }
+            "library" => {
+                self.emit_file("tsup.config.ts", TSUP_CONFIG, false, false)
+                    .await
+            }
+            "nest" => {
+                self.emit_file("nest-cli.json", NEST_CLI_CONFIG, false, false)
+                    .await?;
+                self.emit_file("jest.config.json", NEST_JEST_CONFIG, false, false)
+                    .await
+            }
+            _ => Ok(()),
+        }
+    }
+
     async fn generate_node_package_json(&self, template: &str) -> Result<(), ZackstrapError> {
-        let package_json = PackageJson::from_template(template);
+        let mut package_json = PackageJson::from_template(template);
+        let typescript = self.toolchain.node.uses_typescript(template);
+        if typescript {
+            package_json = package_json.with_typescript(template);
+        }
+        if self.toolchain.node.eslint_format == EslintFormat::Flat {
+            package_json = package_json.with_flat_eslint(typescript);
+        }
+        package_json = package_json
+            .with_package_manager(self.toolchain.node.package_manager.package_manager_field());
+        if self.toolchain.node.workspaces {
+            package_json = match self.toolchain.node.package_manager {
+                NodePackageManager::Pnpm => package_json.with_workspaces(&[]),
+                _ => package_json.with_workspaces(&NODE_WORKSPACE_GLOBS),
+            };
+        }
         let content = package_json.to_string();
-        self.emit_file("package.json", &content, false, false).await
+        self.emit_merged_file("package.json", &content, MergeStrategy::Json, false)
+            .await
+    }
+
+    /// Package manager settings: `.npmrc` for npm and pnpm, `.yarnrc.yml`
+    /// for Yarn, and `pnpm-workspace.yaml` for pnpm monorepos (the others
+    /// read workspaces from `package.json`).
+    async fn generate_package_manager_config(&self) -> Result<(), ZackstrapError> {
+        match self.toolchain.node.package_manager {
+            NodePackageManager::Npm => {
+                self.emit_merged_file(
+                    ".npmrc",
+                    "engine-strict=true\n",
+                    MergeStrategy::ManagedBlock,
+                    false,
+                )
+                .await?
+            }
+            NodePackageManager::Pnpm => {
+                self.emit_merged_file(
+                    ".npmrc",
+                    "engine-strict=true\nauto-install-peers=true\n",
+                    MergeStrategy::ManagedBlock,
+                    false,
+                )
+                .await?;
+                if self.toolchain.node.workspaces {
+                    let globs: String = NODE_WORKSPACE_GLOBS
+                        .iter()
+                        .map(|glob| format!("  - \"{}\"\n", glob))
+                        .collect();
+                    let content = format!("packages:\n{}", globs);
+                    self.emit_merged_file(
+                        "pnpm-workspace.yaml",
+                        &content,
+                        MergeStrategy::Yaml,
+                        false,
+                    )
+                    .await?;
+                }
+            }
+            NodePackageManager::Yarn => {
+                // node_modules instead of Plug'n'Play, which most editors
+                // and tools need extra setup for
+                self.emit_merged_file(
+                    ".yarnrc.yml",
+                    "nodeLinker: node-modules\nenableTelemetry: false\n",
+                    MergeStrategy::Yaml,
+                    false,
+                )
+                .await?
+            }
+            NodePackageManager::Bun => {}
+        }
+        Ok(())
+    }
+
+    async fn generate_node_gitignore(&self, template: &str) -> Result<(), ZackstrapError> {
+        let base = r#"# Dependencies
+node_modules/
+
+# Logs
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+
+# Test coverage
+coverage/
+
+# Local environment
+.env
+.env.local
+
+# OS and editor files
+.DS_Store
+Thumbs.db
+*.swp
+*~
+"#;
+        let extra = match template {
+            "react" => "\n# Production build\n/build/\n",
+            "next" => "\n# Next.js build output\n.next/\nout/\nnext-env.d.ts\n",
+            _ => "\n# Build output\ndist/\n",
+        };
+        let typescript = if self.toolchain.node.uses_typescript(template) {
+            "\n# TypeScript incremental build info\n*.tsbuildinfo\n"
+        } else {
+            ""
+        };
+        let package_manager = match self.toolchain.node.package_manager {
+            NodePackageManager::Yarn => {
+                "\n# Yarn\n.pnp.*\n.yarn/*\n!.yarn/patches\n!.yarn/plugins\n!.yarn/releases\n!.yarn/sdks\n!.yarn/versions\n"
+            }
+            NodePackageManager::Pnpm => "\n# pnpm\n.pnpm-store/\npnpm-debug.log*\n",
+            NodePackageManager::Npm | NodePackageManager::Bun => "",
+        };
+        let content = format!("{}{}{}{}", base, extra, typescript, package_manager);
+        self.emit_merged_file(".gitignore", &content, MergeStrategy::ManagedBlock, false)
+            .await
     }
 
     async fn generate_node_justfile(&self, template: &str) -> Result<(), ZackstrapError> {
+        let package_manager = self.toolchain.node.package_manager;
+        let script = |name: &str| package_manager.run_script(name, "");
         let content = match template {
+            "next" => framework_justfile(
+                "Next.js",
+                &[
+                    ("dev", "Start development server"),
+                    ("build", "Build for production"),
+                    ("start", "Start production server"),
+                    ("lint", "Run linting"),
+                ],
+                package_manager,
+            ),
+            "vite" => framework_justfile(
+                "Vite",
+                &[
+                    ("dev", "Start development server"),
+                    ("build", "Build for production"),
+                    ("preview", "Preview the production build"),
+                    ("test", "Run tests"),
+                    ("lint", "Run linting"),
+                ],
+                package_manager,
+            ),
+            "nest" => framework_justfile(
+                "NestJS",
+                &[
+                    ("dev", "Start development server in watch mode"),
+                    ("start", "Start server"),
+                    ("build", "Build project"),
+                    ("test", "Run tests"),
+                    ("lint", "Run linting"),
+                ],
+                package_manager,
+            ),
+            "library" => framework_justfile(
+                "TypeScript library",
+                &[
+                    ("dev", "Rebuild on change"),
+                    (
+                        "build",
+                        "Build ESM and CommonJS bundles with type declarations",
+                    ),
+                    ("test", "Run tests"),
+                    ("lint", "Run linting"),
+                ],
+                package_manager,
+            ),
             "express" => {
-                r#"# Express.js project justfile
+                format!(
+                    r#"# Express.js project justfile
 default:
     @echo "Available Express.js commands:"
     @just --list
@@ -103,36 +338,44 @@ default:
 # Start development server
 dev:
     @echo "Starting Express.js development server..."
-    @npm run dev
+    @{dev}
 
 # Start production server
 start:
     @echo "Starting Express.js production server..."
-    @npm start
+    @{start}
 
 # Run tests
 test:
     @echo "Running Express.js tests..."
-    @npm test
+    @{test}
 
 # Run linting
 lint:
     @echo "Running ESLint..."
-    @npm run lint
+    @{lint}
 
 # Install dependencies
 install:
     @echo "Installing Node.js dependencies..."
-    @npm install
+    @{install}
 
 # Build project
 build:
     @echo "Building Express.js project..."
-    @npm run build
-"#
+    @{build}
+"#,
+                    dev = script("dev"),
+                    start = script("start"),
+                    test = script("test"),
+                    lint = script("lint"),
+                    install = package_manager.install(),
+                    build = script("build"),
+                )
             }
             "react" => {
-                r#"# React project justfile
+                format!(
+                    r#"# React project justfile
 default:
     @echo "Available React commands:"
     @just --list
@@ -140,36 +383,44 @@ default:
 # Start development server
 dev:
     @echo "Starting React development server..."
-    @npm start
+    @{start}
 
 # Build for production
 build:
     @echo "Building React project for production..."
-    @npm run build
+    @{build}
 
 # Run tests
 test:
     @echo "Running React tests..."
-    @npm test
+    @{test}
 
 # Run linting
 lint:
     @echo "Running ESLint..."
-    @npm run lint
+    @{lint}
 
 # Install dependencies
 install:
     @echo "Installing Node.js dependencies..."
-    @npm install
+    @{install}
 
 # Eject (use with caution)
 eject:
     @echo "Ejecting React app..."
-    @npm run eject
-"#
+    @{eject}
+"#,
+                    start = script("start"),
+                    test = script("test"),
+                    lint = script("lint"),
+                    install = package_manager.install(),
+                    build = script("build"),
+                    eject = script("eject"),
+                )
             }
             _ => {
-                r#"# Node.js project justfile
+                format!(
+                    r#"# Node.js project justfile
 default:
     @echo "Available Node.js commands:"
     @just --list
@@ -177,35 +428,442 @@ default:
 # Start development server
 dev:
     @echo "Starting Node.js development server..."
-    @npm run dev
+    @{dev}
 
 # Start production server
 start:
     @echo "Starting Node.js production server..."
-    @npm start
+    @{start}
 
 # Run tests
 test:
     @echo "Running Node.js tests..."
-    @npm test
+    @{test}
 
 # Run linting
 lint:
     @echo "Running ESLint..."
-    @npm run lint
+    @{lint}
 
 # Install dependencies
 install:
     @echo "Installing Node.js dependencies..."
-    @npm install
+    @{install}
 
 # Build project
 build:
     @echo "Building Node.js project..."
-    @npm run build
-"#
+    @{build}
+"#,
+                    dev = script("dev"),
+                    start = script("start"),
+                    test = script("test"),
+                    lint = script("lint"),
+                    install = package_manager.install(),
+                    build = script("build"),
+                )
             }
         };
-        self.emit_file("justfile", content, false, true).await
+        if self.toolchain.node.uses_typescript(template) {
+            let content = format!(
+                "{}{}",
+                content,
+                typecheck_recipe(package_manager.exec_prefix())
+            );
+            return self.emit_file("justfile", &content, false, true).await;
+        }
+        self.emit_file("justfile", &content, false, true).await
+    }
+}
+
+fn typecheck_recipe(exec: &str) -> String {
+    format!(
+        r#"
+# Type-check without emitting
+typecheck:
+    @echo "Running TypeScript check..."
+    @{exec}tsc --noEmit
+"#,
+        exec = exec
+    )
+}
+
+/// Justfile for a framework template whose recipes each run the
+/// `package.json` script of the same name.
+fn framework_justfile(
+    title: &str,
+    recipes: &[(&str, &str)],
+    package_manager: NodePackageManager,
+) -> String {
+    let mut content = format!(
+        r#"# {title} project justfile
+default:
+    @echo "Available {title} commands:"
+    @just --list
+"#,
+        title = title
+    );
+    for (name, description) in recipes {
+        content.push_str(&format!(
+            r#"
+# {description}
+{name}:
+    @echo "Running {name}..."
+    @{command}
+"#,
+            description = description,
+            name = name,
+            command = package_manager.run_script(name, ""),
+        ));
+    }
+    content.push_str(&format!(
+        r#"
+# Install dependencies
+install:
+    @echo "Installing Node.js dependencies..."
+    @{install}
+"#,
+        install = package_manager.install()
+    ));
+    content
+}
+
+const VITE_CONFIG: &str = r#"import react from "@vitejs/plugin-react";
+import { defineConfig } from "vite";
+
+export default defineConfig({
+  plugins: [react()],
+});
+"#;
+
+/// Dual ESM/CommonJS build with declarations, matching the `exports` map
+/// in the library's `package.json`.
+const TSUP_CONFIG: &str = r#"import { defineConfig } from "tsup";
+
+export default defineConfig({
+  entry: ["src/index.ts"],
+  format: ["esm", "cjs"],
+  dts: true,
+  sourcemap: true,
+  clean: true,
+});
+"#;
+
+const NEST_JEST_CONFIG: &str = r#"{
+  "moduleFileExtensions": ["js", "json", "ts"],
+  "rootDir": "src",
+  "testRegex": ".*\\.spec\\.ts$",
+  "transform": {
+    "^.+\\.(t|j)s$": "ts-jest"
+  },
+  "testEnvironment": "node"
+}
+"#;
+
+const NEST_CLI_CONFIG: &str = r#"{
+  "$schema": "https://json.schemastore.org/nest-cli",
+  "collection": "@nestjs/schematics",
+  "sourceRoot": "src",
+  "compilerOptions": {
+    "deleteOutDir": true
+  }
+}
+"#;
+
+/// `tsconfig.json` for a template. React apps leave emitting to their
+/// bundler and Next.js; NestJS compiles decorators to CommonJS; other Node
+/// apps compile `src/` to `dist/`, and libraries also emit declarations.
+fn tsconfig(template: &str, strictness: TsStrictness) -> String {
+    let mut options = match template {
+        "react" | "vite" => json!({
+            "target": "ES2020",
+            "lib": ["DOM", "DOM.Iterable", "ES2020"],
+            "jsx": "react-jsx",
+            "module": "ESNext",
+            "moduleResolution": "Bundler",
+            "noEmit": true,
+            "isolatedModules": true,
+        }),
+        "next" => json!({
+            "target": "ES2017",
+            "lib": ["dom", "dom.iterable", "esnext"],
+            "allowJs": true,
+            "jsx": "preserve",
+            "module": "esnext",
+            "moduleResolution": "bundler",
+            "noEmit": true,
+            "incremental": true,
+            "isolatedModules": true,
+            "plugins": [{ "name": "next" }],
+            "paths": { "@/*": ["./src/*"] },
+        }),
+        "nest" => json!({
+            "target": "ES2021",
+            "module": "commonjs",
+            "declaration": true,
+            "removeComments": true,
+            "emitDecoratorMetadata": true,
+            "experimentalDecorators": true,
+            "allowSyntheticDefaultImports": true,
+            "outDir": "dist",
+            "baseUrl": "./",
+            "incremental": true,
+            "sourceMap": true,
+        }),
+        _ => json!({
+            "target": "ES2022",
+            "module": "NodeNext",
+            "moduleResolution": "NodeNext",
+            "outDir": "dist",
+            "rootDir": "src",
+            "sourceMap": true,
+        }),
+    };
+    let extra = match template {
+        "library" => json!({ "declaration": true, "declarationMap": true }),
+        "vite" => json!({ "types": ["vite/client"] }),
+        _ => json!({}),
+    };
+    let common = json!({
+        "esModuleInterop": true,
+        "forceConsistentCasingInFileNames": true,
+        "resolveJsonModule": true,
+        "skipLibCheck": true,
+    });
+    let strict = match strictness {
+        TsStrictness::Loose => json!({ "strict": false }),
+        TsStrictness::Strict => json!({ "strict": true }),
+        TsStrictness::Strictest => json!({
+            "strict": true,
+            "noUncheckedIndexedAccess": true,
+            "noImplicitOverride": true,
+            "noImplicitReturns": true,
+            "noFallthroughCasesInSwitch": true,
+            "exactOptionalPropertyTypes": true,
+            "noUnusedLocals": true,
+            "noUnusedParameters": true,
+        }),
+    };
+    for part in [extra, common, strict] {
+        if let (Value::Object(options), Value::Object(part)) = (&mut options, part) {
+            options.extend(part);
+        }
     }
+    let config = match template {
+        "next" => json!({
+            "compilerOptions": options,
+            "include": ["next-env.d.ts", "**/*.ts", "**/*.tsx", ".next/types/**/*.ts"],
+            "exclude": ["node_modules"],
+        }),
+        _ => json!({
+            "compilerOptions": options,
+            "include": ["src"],
+        }),
+    };
+    format!("{:#}\n", config)
+}
+
+/// Type-aware ESLint config using the project's `tsconfig.json`. The
+/// strictest preset switches to typescript-eslint's strict rule set.
+fn typescript_eslint_config(template: &str, strictness: TsStrictness) -> String {
+    if template == "next" {
+        let config = json!({
+            "root": true,
+            "extends": ["next/core-web-vitals", "next/typescript"],
+        });
+        return format!("{:#}\n", config);
+    }
+    let react = matches!(template, "react" | "vite");
+    let mut extends = vec![
+        "eslint:recommended",
+        match strictness {
+            TsStrictness::Strictest => "plugin:@typescript-eslint/strict-type-checked",
+            _ => "plugin:@typescript-eslint/recommended-type-checked",
+        },
+    ];
+    let mut plugins = vec!["@typescript-eslint"];
+    if react {
+        extends.extend(["plugin:react/recommended", "plugin:react-hooks/recommended"]);
+        plugins.extend(["react", "react-hooks"]);
+    }
+    let mut config = json!({
+        "root": true,
+        "env": { "node": true, "es2022": true },
+        "extends": extends,
+        "plugins": plugins,
+        "parser": "@typescript-eslint/parser",
+        "parserOptions": {
+            "ecmaVersion": "latest",
+            "sourceType": "module",
+            "project": true,
+        },
+        "ignorePatterns": ["dist/", "build/", "coverage/"],
+        "rules": {},
+        // Tool configs such as vite.config.ts are outside tsconfig.json's `src`
+        "overrides": [{
+            "files": ["*.config.ts", "*.config.mts"],
+            "extends": ["plugin:@typescript-eslint/disable-type-checked"],
+        }],
+    });
+    match template {
+        "react" | "vite" => {
+            config["env"]["browser"] = json!(true);
+            config["parserOptions"]["ecmaFeatures"] = json!({ "jsx": true });
+            config["settings"] = json!({ "react": { "version": "detect" } });
+            config["rules"] = json!({ "react/react-in-jsx-scope": "off" });
+        }
+        "express" => config["rules"] = json!({ "no-console": "off" }),
+        "nest" => config["env"]["jest"] = json!(true),
+        _ => {}
+    }
+    format!("{:#}\n", config)
+}
+
+/// Flat ESLint config for a template: `@eslint/js` recommended rules, the
+/// template's globals, typescript-eslint's type-checked rules for
+/// TypeScript and the React plugins for React. Next.js ships its rules as
+/// an eslintrc config, loaded through `FlatCompat` as Next.js itself does.
+fn flat_eslint_config(template: &str, typescript: bool, strictness: TsStrictness) -> String {
+    if template == "next" {
+        return NEXT_FLAT_ESLINT_CONFIG.to_string();
+    }
+    let mut imports = vec![
+        r#"import js from "@eslint/js";"#,
+        r#"import globals from "globals";"#,
+    ];
+    let mut configs = vec![
+        r#"  { ignores: ["dist/", "build/", "coverage/"] },"#.to_string(),
+        "  js.configs.recommended,".to_string(),
+    ];
+    if typescript {
+        imports.push(r#"import tseslint from "typescript-eslint";"#);
+        configs.push(match strictness {
+            TsStrictness::Strictest => "  ...tseslint.configs.strictTypeChecked,".to_string(),
+            _ => "  ...tseslint.configs.recommendedTypeChecked,".to_string(),
+        });
+    }
+    if matches!(template, "react" | "vite") {
+        imports.push(r#"import react from "eslint-plugin-react";"#);
+        imports.push(r#"import reactHooks from "eslint-plugin-react-hooks";"#);
+        configs.push("  react.configs.flat.recommended,".to_string());
+        configs.push(r#"  react.configs.flat["jsx-runtime"],"#.to_string());
+        configs.push(
+            r#"  {
+    plugins: { "react-hooks": reactHooks },
+    rules: reactHooks.configs.recommended.rules,
+    settings: { react: { version: "detect" } },
+  },"#
+            .to_string(),
+        );
+    }
+
+    let globals = match template {
+        "react" | "default" => "{ ...globals.browser, ...globals.node }",
+        "vite" => "globals.browser",
+        "nest" => "{ ...globals.node, ...globals.jest }",
+        _ => "globals.node",
+    };
+    let parser_options = if typescript {
+        r#"
+      parserOptions: {
+        projectService: true,
+        tsconfigRootDir: import.meta.dirname,
+      },"#
+    } else {
+        ""
+    };
+    let rules = match template {
+        "express" => r#"{ "no-console": "off" }"#,
+        _ => "{}",
+    };
+    configs.push(format!(
+        r#"  {{
+    languageOptions: {{
+      ecmaVersion: "latest",
+      sourceType: "module",
+      globals: {globals},{parser_options}
+    }},
+    rules: {rules},
+  }},"#,
+        globals = globals,
+        parser_options = parser_options,
+        rules = rules,
+    ));
+    if typescript {
+        // Plain JavaScript files, like this config, and tool configs such as
+        // vite.config.ts are outside tsconfig.json's `src`
+        configs.push(
+            r#"  {
+    files: ["**/*.{js,mjs,cjs}", "*.config.{ts,mts}"],
+    ...tseslint.configs.disableTypeChecked,
+  },"#
+            .to_string(),
+        );
+    }
+
+    format!(
+        "{imports}\n\nexport default [\n{configs}\n];\n",
+        imports = imports.join("\n"),
+        configs = configs.join("\n"),
+    )
+}
+
+const NEXT_FLAT_ESLINT_CONFIG: &str = r#"import { FlatCompat } from "@eslint/eslintrc";
+
+const compat = new FlatCompat({
+  baseDirectory: import.meta.dirname,
+});
+
+export default [
+  { ignores: [".next/", "out/", "next-env.d.ts"] },
+  ...compat.extends("next/core-web-vitals", "next/typescript"),
+];
+"#;
+
+/// Flat config wrapping a legacy `.eslintrc.*` in `FlatCompat`, ESLint's
+/// own migration helper. JSON and YAML configs are inlined so the legacy
+/// file can be deleted; JavaScript configs are loaded from where they are.
+fn migrated_eslint_config(dir: &Path, legacy: &str) -> Result<String, ZackstrapError> {
+    let (setup, config) = if legacy.ends_with(".js") || legacy.ends_with(".cjs") {
+        (
+            r#"import { createRequire } from "node:module";
+"#,
+            format!(
+                r#"createRequire(import.meta.url)("./{legacy}")"#,
+                legacy = legacy
+            ),
+        )
+    } else {
+        let path = dir.join(legacy);
+        let content = std::fs::read_to_string(&path)?;
+        let config: Value = serde_yaml::from_str(&content).map_err(|e| {
+            ZackstrapError::MergeError(path, format!("cannot convert to flat config: {}", e))
+        })?;
+        ("", format!("{:#}", config).replace('\n', "\n  "))
+    };
+    Ok(format!(
+        r#"// Converted from {legacy}. Once ESLint 9 lints the project cleanly,
+// {cleanup}.
+import {{ FlatCompat }} from "@eslint/eslintrc";
+import js from "@eslint/js";
+{setup}
+const compat = new FlatCompat({{
+  baseDirectory: import.meta.dirname,
+  recommendedConfig: js.configs.recommended,
+  allConfig: js.configs.all,
+}});
+
+export default [
+  ...compat.config({config}),
+];
+"#,
+        legacy = legacy,
+        cleanup = if setup.is_empty() {
+            format!("delete {}", legacy)
+        } else {
+            format!("move the contents of {} here and delete it", legacy)
+        },
+        setup = setup,
+        config = config,
+    ))
 }
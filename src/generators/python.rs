@@ -1,5 +1,22 @@
+use super::merge::MergeStrategy;
+use super::toolchain::{PythonLinter, PythonPackageManager, PythonToolchain};
 use crate::error::ZackstrapError;
 
+/// Tables in `pyproject.toml` that zackstrap manages; `[project]`,
+/// `[build-system]` and the dev dependencies belong to the user once the
+/// file exists.
+const PYPROJECT_OWNED_TABLES: &[&str] = &[
+    "tool.black",
+    "tool.ruff",
+    "tool.ruff.lint",
+    "tool.uv",
+    "tool.pdm",
+    "tool.mypy",
+    "tool.pytest.ini_options",
+    "tool.django-stubs",
+    "tool.flask",
+];
+
 impl super::ConfigGenerator {
     #[allow(dead_code)]
     pub async fn generate_python(&self) -> Result<(), ZackstrapError> {
@@ -16,8 +33,13 @@ impl super::ConfigGenerator {
         // Generate Python-specific configs
         self.generate_python_version().await?;
         self.generate_pyproject_toml(template).await?;
-        self.generate_flake8_config().await?;
-        self.generate_requirements_dev().await?;
+        if self.toolchain.python.linter == PythonLinter::Flake8 {
+            self.generate_flake8_config().await?;
+        }
+        if self.toolchain.python.package_manager == PythonPackageManager::Pip {
+            self.generate_requirements_dev().await?;
+        }
+        self.generate_python_gitignore(template).await?;
 
         // Overwrite the basic justfile with Python-specific one
         self.generate_python_justfile(template).await?;
@@ -32,137 +54,17 @@ impl super::ConfigGenerator {
     }
 
     async fn generate_pyproject_toml(&self, template: &str) -> Result<(), ZackstrapError> {
-        let content = match template {
-            "django" => {
-                r#"[build-system]
-requires = ["setuptools>=61.0", "wheel"]
-build-backend = "setuptools.build_meta"
-
-[project]
-name = "django-project"
-version = "0.1.0"
-description = "A Django web application"
-authors = [{name = "Developer", email = "dev@example.com"}]
-readme = "README.md"
-requires-python = ">=3.12"
-dependencies = [
-    "django>=4.2",
-    "djangorestframework>=3.14",
-]
-
-[project.optional-dependencies]
-dev = [
-    "pytest>=7.0",
-    "pytest-django>=4.5",
-    "black>=23.0",
-    "flake8>=6.0",
-    "mypy>=1.0",
-]
-
-[tool.black]
-line-length = 88
-target-version = ['py312']
-
-[tool.flake8]
-max-line-length = 88
-extend-ignore = ["E203", "W503"]
-
-[tool.mypy]
-python_version = "3.12"
-warn_return_any = true
-warn_unused_configs = true
-strict = true
-
-[tool.django-stubs]
-django_settings_module = "myproject.settings"
-"#
-            }
-            "flask" => {
-                r#"[build-system]
-requires = ["setuptools>=61.0", "wheel"]
-build-backend = "setuptools.build_meta"
-
-[project]
-name = "flask-project"
-version = "0.1.0"
-description = "A Flask web application"
-authors = [{name = "Developer", email = "dev@example.com"}]
-readme = "README.md"
-requires-python = ">=3.12"
-dependencies = [
-    "flask>=3.0",
-    "flask-sqlalchemy>=3.0",
-]
-
-[project.optional-dependencies]
-dev = [
-    "pytest>=7.0",
-    "pytest-flask>=1.2",
-    "black>=23.0",
-    "flake8>=6.0",
-    "mypy>=1.0",
-]
-
-[tool.black]
-line-length = 88
-target-version = ['py312']
-
-[tool.flake8]
-max-line-length = 88
-extend-ignore = ["E203", "W503"]
-
-[tool.mypy]
-python_version = "3.12"
-warn_return_any = true
-warn_unused_configs = true
-strict = true
-
-[tool.flask]
-app_name = "app"
-"#
-            }
-            _ => {
-                r#"[build-system]
-requires = ["setuptools>=61.0", "wheel"]
-build-backend = "setuptools.build_meta"
-
-[project]
-name = "python-project"
-version = "0.1.0"
-description = "A Python project"
-authors = [{name = "Developer", email = "dev@example.com"}]
-readme = "README.md"
-requires-python = ">=3.12"
-dependencies = []
-
-[project.optional-dependencies]
-dev = [
-    "pytest>=7.0",
-    "black>=23.0",
-    "flake8>=6.0",
-    "mypy>=1.0",
-]
-
-[tool.black]
-line-length = 88
-target-version = ['py312']
-
-[tool.flake8]
-max-line-length = 88
-extend-ignore = ["E203", "W503"]
-
-[tool.mypy]
-python_version = "3.12"
-warn_return_any = true
-warn_unused_configs = true
-strict = true
-"#
-            }
-        };
-        self.emit_file("pyproject.toml", content, false, false)
-            .await
+        let content = pyproject_toml(template, &self.toolchain.python);
+        self.emit_merged_file(
+            "pyproject.toml",
+            &content,
+            MergeStrategy::Toml(PYPROJECT_OWNED_TABLES),
+            false,
+        )
+        .await
     }
 
+    /// `.flake8`, since Flake8 does not read `pyproject.toml`.
     async fn generate_flake8_config(&self) -> Result<(), ZackstrapError> {
         let content = r#"[flake8]
 max-line-length = 88
@@ -172,21 +74,83 @@ exclude = .git,__pycache__,build,dist,.venv,venv
         self.emit_file(".flake8", content, false, false).await
     }
 
+    /// Pinned dev dependencies for pip projects; the other package managers
+    /// pin them in their lockfile.
     async fn generate_requirements_dev(&self) -> Result<(), ZackstrapError> {
-        let content = r#"# Development dependencies
-pytest==7.4.4
-black==23.12.1
-flake8==6.1.0
-mypy==1.8.0
-pytest-cov==4.1.0
-"#;
-        self.emit_file("requirements-dev.txt", content, false, false)
+        let linters = match self.toolchain.python.linter {
+            PythonLinter::Ruff => "ruff==0.6.9\n",
+            PythonLinter::Flake8 => "black==23.12.1\nflake8==6.1.0\n",
+        };
+        let content = format!(
+            "# Development dependencies\npytest==7.4.4\n{}mypy==1.8.0\npytest-cov==4.1.0\n",
+            linters
+        );
+        self.emit_file("requirements-dev.txt", &content, false, false)
             .await
     }
 
-    async fn generate_python_justfile(&self, template: &str) -> Result<(), ZackstrapError> {
-        let content = match template {
+    async fn generate_python_gitignore(&self, template: &str) -> Result<(), ZackstrapError> {
+        let toolchain = &self.toolchain.python;
+        let ruff_cache = match toolchain.linter {
+            PythonLinter::Ruff => ".ruff_cache/\n",
+            PythonLinter::Flake8 => "",
+        };
+        let base = format!(
+            r#"# Byte-compiled files
+__pycache__/
+*.py[cod]
+
+# Virtual environments
+.venv/
+venv/
+
+# Packaging
+build/
+dist/
+*.egg-info/
+
+# Tooling caches
+.pytest_cache/
+.mypy_cache/
+{ruff_cache}.coverage
+htmlcov/
+
+# OS and editor files
+.DS_Store
+Thumbs.db
+*.swp
+*~
+"#,
+            ruff_cache = ruff_cache
+        );
+        let extra = match template {
             "django" => {
+                r#"
+# Django
+db.sqlite3
+/media/
+/staticfiles/
+local_settings.py
+.env
+"#
+            }
+            "flask" => "\n# Flask\ninstance/\n.env\n",
+            _ => "",
+        };
+        let package_manager = match toolchain.package_manager {
+            PythonPackageManager::Pdm => "\n# PDM\n.pdm-python\n.pdm-build/\n",
+            _ => "",
+        };
+        let content = format!("{}{}{}", base, package_manager, extra);
+        self.emit_merged_file(".gitignore", &content, MergeStrategy::ManagedBlock, false)
+            .await
+    }
+
+    async fn generate_python_justfile(&self, template: &str) -> Result<(), ZackstrapError> {
+        let toolchain = &self.toolchain.python;
+        let run = toolchain.package_manager.run_prefix();
+        let recipes = match template {
+            "django" => format!(
                 r#"# Django project justfile
 default:
     @echo "Available Django commands:"
@@ -195,36 +159,31 @@ default:
 # Start Django development server
 server:
     @echo "Starting Django development server..."
-    @python manage.py runserver
+    @{run}python manage.py runserver
 
 # Run Django tests
 test:
     @echo "Running Django tests..."
-    @python manage.py test
+    @{run}python manage.py test
 
 # Run migrations
 migrate:
     @echo "Running Django migrations..."
-    @python manage.py migrate
+    @{run}python manage.py migrate
 
 # Create superuser
 createsuperuser:
     @echo "Creating Django superuser..."
-    @python manage.py createsuperuser
+    @{run}python manage.py createsuperuser
 
 # Collect static files
 collectstatic:
     @echo "Collecting static files..."
-    @python manage.py collectstatic
-
-# Install dependencies
-install:
-    @echo "Installing Python dependencies..."
-    @pip install -r requirements.txt
-    @pip install -r requirements-dev.txt
-"#
-            }
-            "flask" => {
+    @{run}python manage.py collectstatic
+"#,
+                run = run
+            ),
+            "flask" => format!(
                 r#"# Flask project justfile
 default:
     @echo "Available Flask commands:"
@@ -233,22 +192,22 @@ default:
 # Start Flask development server
 server:
     @echo "Starting Flask development server..."
-    @python app.py
+    @{run}python app.py
 
 # Run Flask tests
 test:
     @echo "Running Flask tests..."
-    @pytest
-
-# Install dependencies
-install:
-    @echo "Installing Python dependencies..."
-    @pip install -r requirements.txt
-    @pip install -r requirements-dev.txt
-"#
-            }
+    @{run}pytest
+"#,
+                run = run
+            ),
             _ => {
-                r#"# Python project justfile
+                let (format, lint) = match toolchain.linter {
+                    PythonLinter::Ruff => ("ruff format .", "ruff check ."),
+                    PythonLinter::Flake8 => ("black .", "flake8 ."),
+                };
+                format!(
+                    r#"# Python project justfile
 default:
     @echo "Available Python commands:"
     @just --list
@@ -256,31 +215,185 @@ default:
 # Run tests
 test:
     @echo "Running Python tests..."
-    @pytest
+    @{run}pytest
 
 # Format code
 format:
     @echo "Formatting Python code..."
-    @black .
+    @{run}{format}
 
 # Lint code
 lint:
     @echo "Linting Python code..."
-    @flake8 .
+    @{run}{lint}
 
 # Type check
 typecheck:
     @echo "Type checking Python code..."
-    @mypy .
-
-# Install dependencies
-install:
-    @echo "Installing Python dependencies..."
-    @pip install -r requirements.txt
-    @pip install -r requirements-dev.txt
-"#
+    @{run}mypy .
+"#,
+                    run = run,
+                    format = format,
+                    lint = lint
+                )
             }
         };
-        self.emit_file("justfile", content, false, true).await
+        let content = format!("{}\n{}", recipes, install_recipes(toolchain));
+        self.emit_file("justfile", &content, false, true).await
+    }
+}
+
+/// The `install` recipe, plus a `lock` recipe for package managers with a
+/// lockfile.
+fn install_recipes(toolchain: &PythonToolchain) -> String {
+    let install = match toolchain.package_manager {
+        PythonPackageManager::Pip => {
+            "    @pip install -r requirements.txt\n    @pip install -r requirements-dev.txt\n"
+        }
+        PythonPackageManager::Uv => "    @uv sync\n",
+        PythonPackageManager::Poetry => "    @poetry install\n",
+        PythonPackageManager::Pdm => "    @pdm install\n",
+    };
+    let mut recipes = format!(
+        "# Install dependencies\ninstall:\n    @echo \"Installing Python dependencies...\"\n{}",
+        install
+    );
+    if let Some(lockfile) = toolchain.package_manager.lockfile() {
+        recipes.push_str(&format!(
+            "\n# Update the lockfile\nlock:\n    @echo \"Updating {}...\"\n    @{} lock\n",
+            lockfile,
+            toolchain.package_manager.as_str()
+        ));
+    }
+    recipes
+}
+
+/// Development dependencies as (name, version specifier) pairs.
+fn dev_dependencies(template: &str, linter: PythonLinter) -> Vec<(&'static str, &'static str)> {
+    let mut deps = vec![("pytest", ">=7.0")];
+    match template {
+        "django" => deps.push(("pytest-django", ">=4.5")),
+        "flask" => deps.push(("pytest-flask", ">=1.2")),
+        _ => {}
+    }
+    match linter {
+        PythonLinter::Ruff => deps.push(("ruff", ">=0.6")),
+        PythonLinter::Flake8 => deps.extend([("black", ">=23.0"), ("flake8", ">=6.0")]),
     }
+    deps.extend([("mypy", ">=1.0"), ("pytest-cov", ">=4.1")]);
+    deps
+}
+
+/// `pyproject.toml` for a template: the build backend and dev dependency
+/// layout follow the package manager, the lint tables follow the linter.
+fn pyproject_toml(template: &str, toolchain: &PythonToolchain) -> String {
+    let build_system = match toolchain.package_manager {
+        PythonPackageManager::Pip => {
+            "requires = [\"setuptools>=61.0\", \"wheel\"]\nbuild-backend = \"setuptools.build_meta\""
+        }
+        PythonPackageManager::Uv => "requires = [\"hatchling\"]\nbuild-backend = \"hatchling.build\"",
+        PythonPackageManager::Poetry => {
+            "requires = [\"poetry-core>=2.0.0,<3.0.0\"]\nbuild-backend = \"poetry.core.masonry.api\""
+        }
+        PythonPackageManager::Pdm => "requires = [\"pdm-backend\"]\nbuild-backend = \"pdm.backend\"",
+    };
+    let (name, description, dependencies) = match template {
+        "django" => (
+            "django-project",
+            "A Django web application",
+            "[\n    \"django>=4.2\",\n    \"djangorestframework>=3.14\",\n]",
+        ),
+        "flask" => (
+            "flask-project",
+            "A Flask web application",
+            "[\n    \"flask>=3.0\",\n    \"flask-sqlalchemy>=3.0\",\n]",
+        ),
+        _ => ("python-project", "A Python project", "[]"),
+    };
+
+    let dev = dev_dependencies(template, toolchain.linter);
+    let dev_dependencies = match toolchain.package_manager {
+        PythonPackageManager::Poetry => {
+            let deps: String = dev
+                .iter()
+                .map(|(name, version)| format!("{} = \"{}\"\n", name, version))
+                .collect();
+            format!("[tool.poetry.group.dev.dependencies]\n{}", deps)
+        }
+        package_manager => {
+            let deps: String = dev
+                .iter()
+                .map(|(name, version)| format!("    \"{}{}\",\n", name, version))
+                .collect();
+            let table = match package_manager {
+                PythonPackageManager::Pip => "project.optional-dependencies",
+                _ => "dependency-groups",
+            };
+            format!("[{}]\ndev = [\n{}]\n", table, deps)
+        }
+    };
+    let package_manager = match toolchain.package_manager {
+        PythonPackageManager::Uv => "\n[tool.uv]\ndefault-groups = [\"dev\"]\n",
+        PythonPackageManager::Pdm => "\n[tool.pdm]\ndistribution = true\n",
+        _ => "",
+    };
+    let linter = match toolchain.linter {
+        PythonLinter::Ruff => {
+            let select = match template {
+                "django" => r#"["E", "W", "F", "I", "B", "UP", "DJ"]"#,
+                _ => r#"["E", "W", "F", "I", "B", "UP"]"#,
+            };
+            format!(
+                "[tool.ruff]\nline-length = 88\ntarget-version = \"py312\"\n\n[tool.ruff.lint]\nselect = {}\n",
+                select
+            )
+        }
+        PythonLinter::Flake8 => {
+            "[tool.black]\nline-length = 88\ntarget-version = ['py312']\n".to_string()
+        }
+    };
+    let pytest = match template {
+        "django" => "DJANGO_SETTINGS_MODULE = \"myproject.settings\"\ntestpaths = [\"tests\"]\naddopts = \"-ra\"",
+        _ => "testpaths = [\"tests\"]\naddopts = \"-ra\"",
+    };
+    let extra = match template {
+        "django" => "\n[tool.django-stubs]\ndjango_settings_module = \"myproject.settings\"\n",
+        "flask" => "\n[tool.flask]\napp_name = \"app\"\n",
+        _ => "",
+    };
+
+    format!(
+        r#"[build-system]
+{build_system}
+
+[project]
+name = "{name}"
+version = "0.1.0"
+description = "{description}"
+authors = [{{name = "Developer", email = "dev@example.com"}}]
+readme = "README.md"
+requires-python = ">=3.12"
+dependencies = {dependencies}
+
+{dev_dependencies}{package_manager}
+{linter}
+[tool.mypy]
+python_version = "3.12"
+warn_return_any = true
+warn_unused_configs = true
+strict = true
+
+[tool.pytest.ini_options]
+{pytest}
+{extra}"#,
+        build_system = build_system,
+        name = name,
+        description = description,
+        dependencies = dependencies,
+        dev_dependencies = dev_dependencies,
+        package_manager = package_manager,
+        linter = linter,
+        pytest = pytest,
+        extra = extra,
+    )
 }
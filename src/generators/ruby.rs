@@ -1,3 +1,4 @@
+use super::merge::MergeStrategy;
 use crate::config::PackageJson;
 use crate::error::ZackstrapError;
 
@@ -16,6 +17,7 @@ impl super::ConfigGenerator {
         self.generate_node_version().await?;
         self.generate_rubocop_config_with_template(template).await?;
         self.generate_package_json_with_template(template).await?;
+        self.generate_ruby_gitignore(template).await?;
 
         // Overwrite the basic justfile with Ruby-specific one
         self.generate_ruby_justfile(template).await?;
@@ -125,7 +127,8 @@ Layout/LineLength:
 "#
             }
         };
-        self.emit_file(".rubocop.yml", content, false, false).await
+        self.emit_merged_file(".rubocop.yml", content, MergeStrategy::Yaml, false)
+            .await
     }
 
     async fn generate_package_json_with_template(
@@ -140,6 +143,46 @@ Layout/LineLength:
         self.emit_file("package.json", &content, false, false).await
     }
 
+    async fn generate_ruby_gitignore(&self, template: &str) -> Result<(), ZackstrapError> {
+        let base = r#"# Bundler
+/.bundle/
+/vendor/bundle/
+
+# Frontend tooling
+/node_modules/
+
+# Test coverage
+/coverage/
+
+# OS and editor files
+.DS_Store
+Thumbs.db
+*.swp
+*~
+"#;
+        let extra = match template {
+            "rails" => {
+                r#"
+# Rails
+/log/*
+/tmp/*
+!/log/.keep
+!/tmp/.keep
+/storage/*
+/public/assets
+/config/master.key
+/.env*
+"#
+            }
+            "sinatra" => "\n# Local environment\n/.env\n/log/\n/tmp/\n",
+            "gem" => "\n# Built gems\n/pkg/\n*.gem\n",
+            _ => "",
+        };
+        let content = format!("{}{}", base, extra);
+        self.emit_merged_file(".gitignore", &content, MergeStrategy::ManagedBlock, false)
+            .await
+    }
+
     async fn generate_ruby_justfile(&self, template: &str) -> Result<(), ZackstrapError> {
         let content = match template {
             "rails" => {
@@ -1,4 +1,76 @@
+use super::merge::MergeStrategy;
+use super::toolchain::RustToolchain;
 use crate::error::ZackstrapError;
+use std::fs;
+use toml_edit::DocumentMut;
+
+/// Tables in `.cargo/config.toml` that zackstrap manages.
+const CARGO_CONFIG_OWNED_TABLES: &[&str] = &["alias", "build", "target"];
+
+/// Tables in `Cargo.toml` that zackstrap manages, for a package, a
+/// workspace root it did not create, and the `workspace` template.
+const CARGO_LINTS_OWNED_TABLES: &[&str] = &["lints", "profile"];
+const CARGO_WORKSPACE_LINTS_OWNED_TABLES: &[&str] = &["workspace.lints", "profile"];
+const CARGO_WORKSPACE_OWNED_TABLES: &[&str] = &["workspace", "profile"];
+
+/// Top-level tables Cargo reads from `.cargo/config.toml`.
+const CARGO_CONFIG_TABLES: &[&str] = &[
+    "alias",
+    "build",
+    "cache",
+    "cargo-new",
+    "credential-alias",
+    "doc",
+    "env",
+    "future-incompat-report",
+    "http",
+    "install",
+    "net",
+    "patch",
+    "profile",
+    "registries",
+    "registry",
+    "resolver",
+    "source",
+    "target",
+    "term",
+    "unstable",
+];
+
+/// Keys of the `[build]` table.
+const CARGO_BUILD_KEYS: &[&str] = &[
+    "jobs",
+    "rustc",
+    "rustc-wrapper",
+    "rustc-workspace-wrapper",
+    "rustdoc",
+    "target",
+    "target-dir",
+    "build-dir",
+    "rustflags",
+    "rustdocflags",
+    "incremental",
+    "dep-info-basedir",
+    "pipelining",
+];
+
+/// Keys of a `[target.<triple>]` or `[target.'cfg(..)']` table, besides
+/// `links` overrides, which are tables.
+const CARGO_TARGET_KEYS: &[&str] = &["linker", "runner", "rustflags", "rustdocflags"];
+
+/// Linux hosts that get the `--linker` setting.
+const LINKER_TARGETS: &[&str] = &["x86_64-unknown-linux-gnu", "aarch64-unknown-linux-gnu"];
+
+/// Tables in `rust-toolchain.toml` that zackstrap manages.
+const RUST_TOOLCHAIN_OWNED_TABLES: &[&str] = &["toolchain"];
+
+/// Target of the `embedded` template: Cortex-M4F/M7F microcontrollers.
+const EMBEDDED_TARGET: &str = "thumbv7em-none-eabihf";
+
+/// Templates that pin a toolchain and manage lints, licenses and releases.
+fn is_crate_template(template: &str) -> bool {
+    matches!(template, "lib" | "workspace" | "embedded")
+}
 
 impl super::ConfigGenerator {
     #[allow(dead_code)]
@@ -13,7 +85,14 @@ impl super::ConfigGenerator {
         // Generate Rust-specific configs
         self.generate_rustfmt_config().await?;
         self.generate_clippy_config().await?;
-        self.generate_cargo_config().await?;
+        self.generate_cargo_config(template).await?;
+        self.generate_cargo_manifest(template).await?;
+        if is_crate_template(template) {
+            self.generate_rust_toolchain(template).await?;
+            self.generate_deny_config().await?;
+            self.generate_release_config(template).await?;
+        }
+        self.generate_rust_gitignore(template).await?;
 
         // Overwrite the basic justfile with Rust-specific one
         self.generate_rust_justfile(template).await?;
@@ -47,45 +126,204 @@ max-fn-params-bools = 3
         self.emit_file(".clippy.toml", content, false, false).await
     }
 
-    async fn generate_cargo_config(&self) -> Result<(), ZackstrapError> {
-        let content = r#"[build]
-# Set the target directory
-target = "target"
+    /// `.cargo/config.toml`, with only portable settings unless the
+    /// toolchain opts into machine-specific ones. Invalid keys already in
+    /// the file are reported, since merging keeps them.
+    async fn generate_cargo_config(&self, template: &str) -> Result<(), ZackstrapError> {
+        let path = self.target_dir.join(".cargo/config.toml");
+        let content = cargo_config(template, &self.toolchain.rust);
+        if let Some(problem) = cargo_config_problems(&content).into_iter().next() {
+            return Err(ZackstrapError::InvalidManifest(path, problem));
+        }
+        if path.exists() && !self.force {
+            use colored::*;
+            for problem in cargo_config_problems(&fs::read_to_string(&path)?) {
+                println!(
+                    "  {} .cargo/config.toml: {} (fix it or regenerate with --force)",
+                    "[INVALID]".yellow(),
+                    problem
+                );
+            }
+        }
+        self.emit_merged_file(
+            ".cargo/config.toml",
+            &content,
+            MergeStrategy::Toml(CARGO_CONFIG_OWNED_TABLES),
+            false,
+        )
+        .await
+    }
 
-[target.x86_64-unknown-linux-gnu]
-rustflags = ["-C", "target-cpu=native"]
+    async fn generate_rust_toolchain(&self, template: &str) -> Result<(), ZackstrapError> {
+        let content = match template {
+            "embedded" => format!(
+                r#"[toolchain]
+channel = "stable"
+components = ["rustfmt", "clippy", "llvm-tools"]
+targets = ["{}"]
+"#,
+                EMBEDDED_TARGET
+            ),
+            _ => r#"[toolchain]
+channel = "stable"
+components = ["rustfmt", "clippy"]
+"#
+            .to_string(),
+        };
+        self.emit_merged_file(
+            "rust-toolchain.toml",
+            &content,
+            MergeStrategy::Toml(RUST_TOOLCHAIN_OWNED_TABLES),
+            false,
+        )
+        .await
+    }
 
-[profile.dev]
-# Development profile settings
-opt-level = 0
-debug = true
-split-debuginfo = "unpacked"
+    /// Profiles, and lint levels for crate templates, in `Cargo.toml`. The
+    /// `workspace` template writes the workspace root itself; other
+    /// templates only merge into a manifest that `cargo new` already
+    /// created, using `[workspace.lints]` in a workspace root.
+    async fn generate_cargo_manifest(&self, template: &str) -> Result<(), ZackstrapError> {
+        let lints = |table| {
+            if is_crate_template(template) {
+                format!("{}\n", cargo_lints(template, table))
+            } else {
+                String::new()
+            }
+        };
 
-[profile.release]
-# Release profile settings
-opt-level = 3
-debug = false
-lto = true
-codegen-units = 1
+        if template == "workspace" {
+            let content = format!(
+                r#"[workspace]
+members = ["crates/*"]
+resolver = "2"
 
-[profile.test]
-# Test profile settings
-opt-level = 0
-debug = true
+[workspace.package]
+edition = "2021"
+license = "MIT OR Apache-2.0"
+
+{}{}"#,
+                lints("workspace.lints"),
+                cargo_profiles(template)
+            );
+            return self
+                .emit_merged_file(
+                    "Cargo.toml",
+                    &content,
+                    MergeStrategy::Manifest(CARGO_WORKSPACE_OWNED_TABLES),
+                    false,
+                )
+                .await;
+        }
+
+        let manifest = self.target_dir.join("Cargo.toml");
+        if !manifest.exists() {
+            return Ok(());
+        }
+        let existing = fs::read_to_string(&manifest)?;
+        let workspace_root = existing
+            .parse::<DocumentMut>()
+            .is_ok_and(|doc| doc.contains_key("workspace"));
+        let (table, owned) = if workspace_root {
+            ("workspace.lints", CARGO_WORKSPACE_LINTS_OWNED_TABLES)
+        } else {
+            ("lints", CARGO_LINTS_OWNED_TABLES)
+        };
+        let content = format!("{}{}", lints(table), cargo_profiles(template));
+        self.emit_merged_file(
+            "Cargo.toml",
+            &content,
+            MergeStrategy::Manifest(owned),
+            false,
+        )
+        .await
+    }
 
-[profile.bench]
-# Benchmark profile settings
-opt-level = 3
-debug = false
-lto = true
-codegen-units = 1
+    async fn generate_deny_config(&self) -> Result<(), ZackstrapError> {
+        let content = r#"# cargo-deny configuration: https://embarkstudios.github.io/cargo-deny/
+
+[graph]
+all-features = true
+
+[advisories]
+version = 2
+yanked = "deny"
+
+[licenses]
+version = 2
+allow = [
+    "MIT",
+    "Apache-2.0",
+    "Apache-2.0 WITH LLVM-exception",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "ISC",
+    "Unicode-3.0",
+    "Zlib",
+]
+confidence-threshold = 0.8
+
+[bans]
+multiple-versions = "warn"
+wildcards = "deny"
+
+[sources]
+unknown-registry = "deny"
+unknown-git = "deny"
 "#;
-        self.emit_file(".cargo/config.toml", content, false, false)
+        self.emit_file("deny.toml", content, false, false).await
+    }
+
+    async fn generate_release_config(&self, template: &str) -> Result<(), ZackstrapError> {
+        let extra = match template {
+            // Members are versioned and tagged together
+            "workspace" => "shared-version = true\nconsolidate-commits = true\n",
+            // Firmware is flashed, not published to crates.io
+            "embedded" => "publish = false\n",
+            _ => "",
+        };
+        let content = format!(
+            r#"# cargo-release configuration: https://github.com/crate-ci/cargo-release
+allow-branch = ["main"]
+{extra}tag-name = "v{{{{version}}}}"
+pre-release-commit-message = "Release {{{{version}}}}"
+"#,
+            extra = extra
+        );
+        self.emit_file("release.toml", &content, false, false).await
+    }
+
+    async fn generate_rust_gitignore(&self, template: &str) -> Result<(), ZackstrapError> {
+        let base = r#"# Build output
+/target/
+
+# Backup files generated by rustfmt
+**/*.rs.bk
+
+# Coverage reports
+*.profraw
+lcov.info
+
+# OS and editor files
+.DS_Store
+Thumbs.db
+*.swp
+*~
+"#;
+        let extra = match template {
+            "web" => "\n# Local environment\n.env\n",
+            _ => "",
+        };
+        let content = format!("{}{}", base, extra);
+        self.emit_merged_file(".gitignore", &content, MergeStrategy::ManagedBlock, false)
             .await
     }
 
     async fn generate_rust_justfile(&self, template: &str) -> Result<(), ZackstrapError> {
         let content = match template {
+            "lib" => LIB_JUSTFILE,
+            "workspace" => WORKSPACE_JUSTFILE,
+            "embedded" => EMBEDDED_JUSTFILE,
             "web" => {
                 r#"# Rust web project justfile
 default:
@@ -239,3 +477,370 @@ install:
         self.emit_file("justfile", content, false, true).await
     }
 }
+
+/// `.cargo/config.toml` for a template: portable aliases, the device
+/// target for `embedded`, and the toolchain's opt-in performance settings.
+/// Native CPU flags do not apply when cross-compiling firmware.
+fn cargo_config(template: &str, rust: &RustToolchain) -> String {
+    let native_cpu = rust.native_cpu && template != "embedded";
+    let native_flags = if native_cpu {
+        r#""-C", "target-cpu=native""#
+    } else {
+        ""
+    };
+
+    // Firmware tests and examples do not build for the device target
+    let lint = if template == "embedded" {
+        "clippy --all-features -- -D warnings"
+    } else {
+        "clippy --all-targets --all-features -- -D warnings"
+    };
+    let mut content = format!(
+        r#"# Cargo configuration; build profiles live in Cargo.toml
+[alias]
+lint = "{}"
+"#,
+        lint
+    );
+
+    let mut build = Vec::new();
+    if template == "embedded" {
+        build.push(format!(
+            "# Cross-compile for the microcontroller by default\ntarget = \"{}\"",
+            EMBEDDED_TARGET
+        ));
+    }
+    if rust.sccache {
+        build.push(
+            "# Cache compiled crates across projects\nrustc-wrapper = \"sccache\"".to_string(),
+        );
+    }
+    // Target rustflags replace `build.rustflags`, so with a linker the
+    // native flags go in the linker's tables instead
+    if native_cpu && rust.linker.is_none() {
+        build.push(format!(
+            "# Binaries only run on CPUs like this one\nrustflags = [{}]",
+            native_flags
+        ));
+    }
+    if !build.is_empty() {
+        content.push_str(&format!("\n[build]\n{}\n", build.join("\n")));
+    }
+
+    if template == "embedded" {
+        content.push_str(&format!(
+            r#"
+[target.{target}]
+# Flash and run with probe-rs; set your chip (see `probe-rs chip list`)
+runner = "probe-rs run --chip nRF52840_xxAA"
+rustflags = ["-C", "link-arg=-Tlink.x"]
+"#,
+            target = EMBEDDED_TARGET
+        ));
+    }
+    if let Some(linker) = rust.linker {
+        let flags = if native_cpu {
+            format!(
+                r#""-C", "link-arg=-fuse-ld={}", {}"#,
+                linker.as_str(),
+                native_flags
+            )
+        } else {
+            format!(r#""-C", "link-arg=-fuse-ld={}""#, linker.as_str())
+        };
+        for target in LINKER_TARGETS {
+            content.push_str(&format!(
+                r#"
+[target.{target}]
+# Link with {linker} through clang
+linker = "clang"
+rustflags = [{flags}]
+"#,
+                target = target,
+                linker = linker.as_str(),
+                flags = flags
+            ));
+        }
+    }
+    content
+}
+
+/// Keys in a `.cargo/config.toml` that Cargo does not accept, such as a
+/// `build.target` that is not a target triple.
+pub fn cargo_config_problems(content: &str) -> Vec<String> {
+    let doc = match content.parse::<DocumentMut>() {
+        Ok(doc) => doc,
+        Err(e) => return vec![format!("invalid TOML: {}", e)],
+    };
+    let mut problems = Vec::new();
+    for (key, item) in doc.iter() {
+        if !CARGO_CONFIG_TABLES.contains(&key) {
+            problems.push(format!("unknown table [{}]", key));
+            continue;
+        }
+        let Some(table) = item.as_table_like() else {
+            problems.push(format!("{} must be a table", key));
+            continue;
+        };
+        match key {
+            "build" => {
+                for (name, _) in table.iter() {
+                    if !CARGO_BUILD_KEYS.contains(&name) {
+                        problems.push(format!("unknown key build.{}", name));
+                    }
+                }
+                let targets = match table.get("target") {
+                    Some(target) => match target.as_array() {
+                        Some(targets) => targets.iter().filter_map(|t| t.as_str()).collect(),
+                        None => target.as_str().into_iter().collect(),
+                    },
+                    None => Vec::new(),
+                };
+                for target in targets {
+                    if !is_target_triple(target) {
+                        problems.push(format!(
+                            "build.target = \"{}\" is not a target triple (use build.target-dir for the output directory)",
+                            target
+                        ));
+                    }
+                }
+            }
+            "target" => {
+                for (name, target) in table.iter() {
+                    if !name.starts_with("cfg(") && !is_target_triple(name) {
+                        problems.push(format!("[target.{}] is not a target triple or cfg()", name));
+                    }
+                    let Some(target) = target.as_table_like() else {
+                        problems.push(format!("target.{} must be a table", name));
+                        continue;
+                    };
+                    for (key, value) in target.iter() {
+                        if !CARGO_TARGET_KEYS.contains(&key) && !value.is_table_like() {
+                            problems.push(format!("unknown key target.{}.{}", name, key));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    problems
+}
+
+/// `arch-vendor-os[-env]` triples, or a custom target's JSON spec.
+fn is_target_triple(target: &str) -> bool {
+    target.ends_with(".json")
+        || (target.split('-').count() >= 2
+            && target
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+}
+
+/// Release profile for `Cargo.toml`; the other profiles keep Cargo's
+/// defaults.
+fn cargo_profiles(template: &str) -> &'static str {
+    match template {
+        "embedded" => {
+            r#"[profile.release]
+# Debug info stays on the host and costs no flash
+debug = true
+lto = true
+codegen-units = 1
+opt-level = "s"
+"#
+        }
+        _ => {
+            r#"[profile.release]
+lto = true
+codegen-units = 1
+"#
+        }
+    }
+}
+
+/// `[lints]`-style tables for `Cargo.toml`, under `table` (`lints` or
+/// `workspace.lints`). Firmware needs `unsafe` for registers, so the
+/// `embedded` template asks for it to be documented instead of forbidden.
+fn cargo_lints(template: &str, table: &str) -> String {
+    let (rust, clippy) = match template {
+        "embedded" => (
+            r#"unsafe_op_in_unsafe_fn = "deny""#,
+            r#"all = { level = "warn", priority = -1 }
+undocumented_unsafe_blocks = "warn""#,
+        ),
+        "lib" => (
+            r#"unsafe_code = "forbid"
+missing_docs = "warn""#,
+            r#"all = { level = "warn", priority = -1 }
+pedantic = { level = "warn", priority = -1 }"#,
+        ),
+        _ => (
+            r#"unsafe_code = "forbid""#,
+            r#"all = { level = "warn", priority = -1 }
+pedantic = { level = "warn", priority = -1 }"#,
+        ),
+    };
+    format!(
+        "[{table}.rust]\n{rust}\n\n[{table}.clippy]\n{clippy}\n",
+        table = table,
+        rust = rust,
+        clippy = clippy
+    )
+}
+
+const LIB_JUSTFILE: &str = r#"# Rust library justfile
+default:
+    @echo "Available Rust library commands:"
+    @just --list
+
+# Build the library
+build:
+    @echo "Building Rust library..."
+    @cargo build --all-features
+
+# Run tests with nextest, then doctests (nextest does not run them)
+test:
+    @echo "Running Rust tests..."
+    @cargo nextest run --all-features
+    @cargo test --doc --all-features
+
+# Run tests with coverage
+test-coverage:
+    @echo "Running Rust tests with coverage..."
+    @cargo llvm-cov nextest --html
+
+# Run linting
+lint:
+    @echo "Running Clippy..."
+    @cargo clippy --all-targets --all-features -- -D warnings
+
+# Format code
+fmt:
+    @echo "Formatting Rust code..."
+    @cargo fmt
+
+# Build the documentation
+doc:
+    @echo "Building documentation..."
+    @cargo doc --no-deps --all-features
+
+# Check advisories, licenses and banned dependencies
+deny:
+    @echo "Running cargo-deny..."
+    @cargo deny check
+
+# Prepare a release (dry run unless --execute is passed)
+release level="patch" *args:
+    @cargo release {{level}} {{args}}
+
+# Install dependencies
+install:
+    @echo "Installing Rust dependencies..."
+    @cargo build
+"#;
+
+const WORKSPACE_JUSTFILE: &str = r#"# Rust workspace justfile
+default:
+    @echo "Available Rust workspace commands:"
+    @just --list
+
+# Build every crate
+build:
+    @echo "Building Rust workspace..."
+    @cargo build --workspace
+
+# Build every crate for release
+build-release:
+    @echo "Building Rust workspace for release..."
+    @cargo build --workspace --release
+
+# Run tests with nextest, then doctests (nextest does not run them)
+test:
+    @echo "Running Rust tests..."
+    @cargo nextest run --workspace --all-features
+    @cargo test --workspace --doc --all-features
+
+# Run tests with coverage
+test-coverage:
+    @echo "Running Rust tests with coverage..."
+    @cargo llvm-cov nextest --workspace --html
+
+# Run linting
+lint:
+    @echo "Running Clippy..."
+    @cargo clippy --workspace --all-targets --all-features -- -D warnings
+
+# Format code
+fmt:
+    @echo "Formatting Rust code..."
+    @cargo fmt --all
+
+# Build the documentation
+doc:
+    @echo "Building documentation..."
+    @cargo doc --workspace --no-deps --all-features
+
+# Check advisories, licenses and banned dependencies
+deny:
+    @echo "Running cargo-deny..."
+    @cargo deny check
+
+# Prepare a release of all crates (dry run unless --execute is passed)
+release level="patch" *args:
+    @cargo release --workspace {{level}} {{args}}
+
+# Install dependencies
+install:
+    @echo "Installing Rust dependencies..."
+    @cargo build --workspace
+"#;
+
+const EMBEDDED_JUSTFILE: &str = r#"# Rust embedded project justfile
+default:
+    @echo "Available Rust embedded commands:"
+    @just --list
+
+# Build the firmware
+build:
+    @echo "Building firmware..."
+    @cargo build
+
+# Build the firmware for release
+build-release:
+    @echo "Building firmware for release..."
+    @cargo build --release
+
+# Flash and run on the board with probe-rs
+flash:
+    @echo "Flashing firmware..."
+    @cargo run --release
+
+# Show the firmware size (needs cargo-binutils)
+size:
+    @cargo size --release
+
+# Run linting
+lint:
+    @echo "Running Clippy..."
+    @cargo clippy --all-features -- -D warnings
+
+# Format code
+fmt:
+    @echo "Formatting Rust code..."
+    @cargo fmt
+
+# Build the documentation
+doc:
+    @echo "Building documentation..."
+    @cargo doc --no-deps
+
+# Check advisories, licenses and banned dependencies
+deny:
+    @echo "Running cargo-deny..."
+    @cargo deny check
+
+# Install dependencies
+install:
+    @echo "Installing Rust dependencies..."
+    @cargo build
+"#;
@@ -0,0 +1,605 @@
+//! Per-language tool choices that change several generated files at once:
+//!
+//! - Python: the package manager decides the build backend, where dev
+//!   dependencies live, the justfile recipes and the hook commands.
+//! - Node: the package manager decides how scripts, binaries and installs
+//!   are run; TypeScript adds `tsconfig.json`, type-aware ESLint and
+//!   `@types/*` packages.
+//! - Rust: opt-in build settings (native CPU, a faster linker, sccache).
+//! - Go: the module path and the golangci-lint preset.
+//!
+//! Choices not given on the command line are detected from the project's
+//! existing files, so regenerating a project or installing its hooks later
+//! keeps using the same tools.
+
+use std::path::Path;
+use toml_edit::DocumentMut;
+
+/// Tool choices for every language zackstrap generates.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Toolchain {
+    pub python: PythonToolchain,
+    pub node: NodeToolchain,
+    pub rust: RustToolchain,
+    pub go: GoToolchain,
+}
+
+impl Toolchain {
+    /// Detect the tools already used in `dir`.
+    pub fn detect(dir: &Path) -> Self {
+        Self {
+            python: PythonToolchain::detect(dir),
+            node: NodeToolchain::detect(dir),
+            rust: RustToolchain::detect(dir),
+            go: GoToolchain::default(),
+        }
+    }
+}
+
+/// Installs Python dependencies and runs the project's tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum PythonPackageManager {
+    /// uv, with a `uv.lock` and a `dev` dependency group
+    Uv,
+    /// Poetry, with a `poetry.lock` and a `dev` dependency group
+    Poetry,
+    /// PDM, with a `pdm.lock` and a `dev` dependency group
+    Pdm,
+    /// pip with setuptools and pinned `requirements-dev.txt`
+    #[default]
+    Pip,
+}
+
+impl PythonPackageManager {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PythonPackageManager::Uv => "uv",
+            PythonPackageManager::Poetry => "poetry",
+            PythonPackageManager::Pdm => "pdm",
+            PythonPackageManager::Pip => "pip",
+        }
+    }
+
+    /// Prefix that runs a tool inside the project's environment, e.g.
+    /// `uv run `. pip projects rely on an activated virtualenv instead.
+    pub fn run_prefix(&self) -> &'static str {
+        match self {
+            PythonPackageManager::Uv => "uv run ",
+            PythonPackageManager::Poetry => "poetry run ",
+            PythonPackageManager::Pdm => "pdm run ",
+            PythonPackageManager::Pip => "",
+        }
+    }
+
+    /// The lockfile the package manager maintains, if any.
+    pub fn lockfile(&self) -> Option<&'static str> {
+        match self {
+            PythonPackageManager::Uv => Some("uv.lock"),
+            PythonPackageManager::Poetry => Some("poetry.lock"),
+            PythonPackageManager::Pdm => Some("pdm.lock"),
+            PythonPackageManager::Pip => None,
+        }
+    }
+
+    /// Command that fails when the lockfile is out of date with
+    /// `pyproject.toml`.
+    pub fn lock_check(&self) -> Option<&'static str> {
+        match self {
+            PythonPackageManager::Uv => Some("uv lock --check"),
+            PythonPackageManager::Poetry => Some("poetry check --lock"),
+            PythonPackageManager::Pdm => Some("pdm lock --check"),
+            PythonPackageManager::Pip => None,
+        }
+    }
+}
+
+/// Lints and formats Python code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum PythonLinter {
+    /// Ruff for both linting and formatting, configured in `pyproject.toml`
+    Ruff,
+    /// Flake8 configured in `.flake8`, with Black for formatting
+    #[default]
+    Flake8,
+}
+
+impl PythonLinter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PythonLinter::Ruff => "ruff",
+            PythonLinter::Flake8 => "flake8",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PythonToolchain {
+    pub package_manager: PythonPackageManager,
+    pub linter: PythonLinter,
+}
+
+impl PythonToolchain {
+    /// Detect the package manager from its lockfile or its `[tool.*]` table
+    /// in `pyproject.toml`, and Ruff from its config. Anything else is pip
+    /// with Flake8.
+    pub fn detect(dir: &Path) -> Self {
+        let pyproject = std::fs::read_to_string(dir.join("pyproject.toml"))
+            .ok()
+            .and_then(|content| content.parse::<DocumentMut>().ok());
+        let has_tool = |name: &str| {
+            pyproject
+                .as_ref()
+                .and_then(|doc| doc.get("tool")?.get(name))
+                .is_some()
+        };
+
+        const MANAGERS: [PythonPackageManager; 3] = [
+            PythonPackageManager::Uv,
+            PythonPackageManager::Poetry,
+            PythonPackageManager::Pdm,
+        ];
+        let package_manager = MANAGERS
+            .into_iter()
+            .find(|pm| pm.lockfile().is_some_and(|lock| dir.join(lock).exists()))
+            .or_else(|| MANAGERS.into_iter().find(|pm| has_tool(pm.as_str())))
+            .unwrap_or_default();
+
+        let linter = if has_tool("ruff")
+            || dir.join("ruff.toml").exists()
+            || dir.join(".ruff.toml").exists()
+        {
+            PythonLinter::Ruff
+        } else {
+            PythonLinter::Flake8
+        };
+
+        Self {
+            package_manager,
+            linter,
+        }
+    }
+
+    /// Override the detected choices with the ones given explicitly.
+    pub fn with_overrides(
+        mut self,
+        package_manager: Option<PythonPackageManager>,
+        linter: Option<PythonLinter>,
+    ) -> Self {
+        if let Some(package_manager) = package_manager {
+            self.package_manager = package_manager;
+        }
+        if let Some(linter) = linter {
+            self.linter = linter;
+        }
+        self
+    }
+}
+
+/// How strictly `tsconfig.json` type-checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum TsStrictness {
+    /// `strict` off, for migrating JavaScript code
+    Loose,
+    /// `strict` on
+    #[default]
+    Strict,
+    /// `strict` plus checks such as `noUncheckedIndexedAccess` and
+    /// `exactOptionalPropertyTypes`, with ESLint's strict type-checked rules
+    Strictest,
+}
+
+impl TsStrictness {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TsStrictness::Loose => "loose",
+            TsStrictness::Strict => "strict",
+            TsStrictness::Strictest => "strictest",
+        }
+    }
+}
+
+/// Which configuration format the generated ESLint setup uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum EslintFormat {
+    /// `eslint.config.mjs` for ESLint 9
+    #[default]
+    Flat,
+    /// `.eslintrc.json` for ESLint 8
+    Legacy,
+}
+
+/// Flat config files ESLint 9 looks for, in its lookup order.
+pub const FLAT_ESLINT_CONFIGS: [&str; 4] = [
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+    "eslint.config.ts",
+];
+
+/// Legacy config files ESLint 8 looks for, in its lookup order.
+pub const LEGACY_ESLINT_CONFIGS: [&str; 6] = [
+    ".eslintrc.js",
+    ".eslintrc.cjs",
+    ".eslintrc.yaml",
+    ".eslintrc.yml",
+    ".eslintrc.json",
+    ".eslintrc",
+];
+
+/// The first of `names` that exists in `dir`.
+pub fn find_config(dir: &Path, names: &[&'static str]) -> Option<&'static str> {
+    names.iter().copied().find(|name| dir.join(name).exists())
+}
+
+/// Installs Node.js dependencies and runs the project's scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum NodePackageManager {
+    /// npm, with a `package-lock.json`
+    #[default]
+    Npm,
+    /// pnpm, with a `pnpm-lock.yaml`
+    Pnpm,
+    /// Yarn (Berry), with a `yarn.lock` and `.yarnrc.yml`
+    Yarn,
+    /// Bun, with a `bun.lock`
+    Bun,
+}
+
+impl NodePackageManager {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NodePackageManager::Npm => "npm",
+            NodePackageManager::Pnpm => "pnpm",
+            NodePackageManager::Yarn => "yarn",
+            NodePackageManager::Bun => "bun",
+        }
+    }
+
+    /// Lockfiles the package manager writes, newest format first.
+    pub const fn lockfiles(&self) -> &'static [&'static str] {
+        match self {
+            NodePackageManager::Npm => &["package-lock.json"],
+            NodePackageManager::Pnpm => &["pnpm-lock.yaml"],
+            NodePackageManager::Yarn => &["yarn.lock"],
+            NodePackageManager::Bun => &["bun.lock", "bun.lockb"],
+        }
+    }
+
+    /// The `packageManager` field of `package.json`, used by Corepack to
+    /// pick the version. Bun needs 1.2.15 or later for `bun audit` and the
+    /// text `bun.lock`.
+    pub fn package_manager_field(&self) -> &'static str {
+        match self {
+            NodePackageManager::Npm => "npm@10.9.0",
+            NodePackageManager::Pnpm => "pnpm@9.12.0",
+            NodePackageManager::Yarn => "yarn@4.5.0",
+            NodePackageManager::Bun => "bun@1.2.21",
+        }
+    }
+
+    pub fn install(&self) -> &'static str {
+        match self {
+            NodePackageManager::Npm => "npm install",
+            NodePackageManager::Pnpm => "pnpm install",
+            NodePackageManager::Yarn => "yarn install",
+            NodePackageManager::Bun => "bun install",
+        }
+    }
+
+    /// Install exactly what the lockfile says, failing when it is stale.
+    pub const fn frozen_install(&self) -> &'static str {
+        match self {
+            NodePackageManager::Npm => "npm ci",
+            NodePackageManager::Pnpm => "pnpm install --frozen-lockfile",
+            NodePackageManager::Yarn => "yarn install --immutable",
+            NodePackageManager::Bun => "bun install --frozen-lockfile",
+        }
+    }
+
+    /// Prefix that runs a binary from `node_modules`, e.g. `pnpm exec `.
+    pub fn exec_prefix(&self) -> &'static str {
+        match self {
+            NodePackageManager::Npm => "npx ",
+            NodePackageManager::Pnpm => "pnpm exec ",
+            NodePackageManager::Yarn => "yarn ",
+            NodePackageManager::Bun => "bunx ",
+        }
+    }
+
+    /// Run a `package.json` script with extra arguments. npm needs `--`
+    /// before the arguments; the others pass them through as they are.
+    /// `bun test` is Bun's own test runner, so Bun always uses `bun run`.
+    pub fn run_script(&self, script: &str, args: &str) -> String {
+        let command = match (self, script) {
+            (NodePackageManager::Npm, "test" | "start") => format!("npm {}", script),
+            (NodePackageManager::Pnpm | NodePackageManager::Yarn, "test" | "start") => {
+                format!("{} {}", self.as_str(), script)
+            }
+            _ => format!("{} run {}", self.as_str(), script),
+        };
+        match (self, args) {
+            (_, "") => command,
+            (NodePackageManager::Npm, args) => format!("{} -- {}", command, args),
+            (_, args) => format!("{} {}", command, args),
+        }
+    }
+
+    pub fn audit(&self) -> &'static str {
+        match self {
+            NodePackageManager::Npm => "npm audit",
+            NodePackageManager::Pnpm => "pnpm audit",
+            NodePackageManager::Yarn => "yarn npm audit",
+            NodePackageManager::Bun => "bun audit",
+        }
+    }
+
+    fn from_package_manager_field(field: &str) -> Option<Self> {
+        let name = field.split('@').next()?;
+        [
+            NodePackageManager::Npm,
+            NodePackageManager::Pnpm,
+            NodePackageManager::Yarn,
+            NodePackageManager::Bun,
+        ]
+        .into_iter()
+        .find(|pm| pm.as_str() == name)
+    }
+}
+
+/// Package globs of a workspace (monorepo) project.
+pub const NODE_WORKSPACE_GLOBS: [&str; 2] = ["apps/*", "packages/*"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeToolchain {
+    pub typescript: bool,
+    pub strictness: TsStrictness,
+    pub eslint_format: EslintFormat,
+    pub package_manager: NodePackageManager,
+    /// A monorepo with packages under `NODE_WORKSPACE_GLOBS`.
+    pub workspaces: bool,
+}
+
+impl NodeToolchain {
+    /// TypeScript projects have a `tsconfig.json`; its `strict` and
+    /// `noUncheckedIndexedAccess` options give the strictness. A config
+    /// that is not plain JSON (e.g. with comments) counts as strict.
+    /// Projects that only have a legacy `.eslintrc.*` keep that format.
+    pub fn detect(dir: &Path) -> Self {
+        let eslint_format = if find_config(dir, &FLAT_ESLINT_CONFIGS).is_none()
+            && find_config(dir, &LEGACY_ESLINT_CONFIGS).is_some()
+        {
+            EslintFormat::Legacy
+        } else {
+            EslintFormat::Flat
+        };
+        let package_json = std::fs::read_to_string(dir.join("package.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok());
+        let package_json = package_json.as_ref();
+        let package_manager = package_json
+            .and_then(|package| package["packageManager"].as_str())
+            .and_then(NodePackageManager::from_package_manager_field)
+            .or_else(|| {
+                [
+                    NodePackageManager::Pnpm,
+                    NodePackageManager::Yarn,
+                    NodePackageManager::Bun,
+                    NodePackageManager::Npm,
+                ]
+                .into_iter()
+                .find(|pm| pm.lockfiles().iter().any(|lock| dir.join(lock).exists()))
+            })
+            .unwrap_or_default();
+        let workspaces = dir.join("pnpm-workspace.yaml").exists()
+            || package_json.is_some_and(|package| package.get("workspaces").is_some());
+        let Ok(content) = std::fs::read_to_string(dir.join("tsconfig.json")) else {
+            return Self {
+                eslint_format,
+                package_manager,
+                workspaces,
+                ..Self::default()
+            };
+        };
+        let strictness = match serde_json::from_str::<serde_json::Value>(&content) {
+            Ok(config) => {
+                let option = |name: &str| config["compilerOptions"][name] == true;
+                if option("strict") && option("noUncheckedIndexedAccess") {
+                    TsStrictness::Strictest
+                } else if option("strict") {
+                    TsStrictness::Strict
+                } else {
+                    TsStrictness::Loose
+                }
+            }
+            Err(_) => TsStrictness::Strict,
+        };
+        Self {
+            typescript: true,
+            strictness,
+            eslint_format,
+            package_manager,
+            workspaces,
+        }
+    }
+
+    /// Override the detected choices. Asking for a strictness implies
+    /// TypeScript.
+    pub fn with_overrides(
+        mut self,
+        typescript: bool,
+        strictness: Option<TsStrictness>,
+        eslint_format: Option<EslintFormat>,
+        package_manager: Option<NodePackageManager>,
+        workspaces: bool,
+    ) -> Self {
+        if let Some(strictness) = strictness {
+            self.strictness = strictness;
+        }
+        if let Some(eslint_format) = eslint_format {
+            self.eslint_format = eslint_format;
+        }
+        if let Some(package_manager) = package_manager {
+            self.package_manager = package_manager;
+        }
+        self.typescript |= typescript || strictness.is_some();
+        self.workspaces |= workspaces;
+        self
+    }
+
+    /// Whether a project on `template` is written in TypeScript: asked
+    /// for, or implied by a TypeScript-first template.
+    pub fn uses_typescript(&self, template: &str) -> bool {
+        self.typescript
+            || matches!(
+                template,
+                "typescript" | "library" | "next" | "vite" | "nest"
+            )
+    }
+}
+
+/// Faster linker for Linux builds, set in `.cargo/config.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RustLinker {
+    /// mold, through clang
+    Mold,
+    /// LLVM's lld, through clang
+    Lld,
+}
+
+impl RustLinker {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RustLinker::Mold => "mold",
+            RustLinker::Lld => "lld",
+        }
+    }
+}
+
+/// Opt-in build performance settings. Each one depends on the machine
+/// (CPU, installed linker or cache), so none is on by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RustToolchain {
+    pub native_cpu: bool,
+    pub linker: Option<RustLinker>,
+    pub sccache: bool,
+}
+
+impl RustToolchain {
+    /// Detect the linker and sccache from `.cargo/config.toml`, so
+    /// regenerating with `--force` keeps them. `target-cpu=native` is never
+    /// inferred: binaries built with it may not run on other machines.
+    pub fn detect(dir: &Path) -> Self {
+        let Some(config) = std::fs::read_to_string(dir.join(".cargo/config.toml"))
+            .ok()
+            .and_then(|content| content.parse::<DocumentMut>().ok())
+        else {
+            return Self::default();
+        };
+        let sccache = config
+            .get("build")
+            .and_then(|build| build.get("rustc-wrapper"))
+            .and_then(|wrapper| wrapper.as_str())
+            .is_some_and(|wrapper| wrapper.contains("sccache"));
+        let rustflags = config
+            .get("target")
+            .and_then(|target| target.as_table_like())
+            .map(|targets| {
+                targets
+                    .iter()
+                    .filter_map(|(_, table)| table.get("rustflags")?.as_array().cloned())
+                    .flatten()
+                    .filter_map(|flag| flag.as_str().map(str::to_string))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let linker = [RustLinker::Mold, RustLinker::Lld]
+            .into_iter()
+            .find(|linker| {
+                rustflags
+                    .iter()
+                    .any(|flag| flag.ends_with(&format!("-fuse-ld={}", linker.as_str())))
+            });
+        Self {
+            native_cpu: false,
+            linker,
+            sccache,
+        }
+    }
+
+    /// Override the detected choices.
+    pub fn with_overrides(
+        mut self,
+        native_cpu: bool,
+        linker: Option<RustLinker>,
+        sccache: bool,
+    ) -> Self {
+        if linker.is_some() {
+            self.linker = linker;
+        }
+        self.native_cpu |= native_cpu;
+        self.sccache |= sccache;
+        self
+    }
+}
+
+/// Linters enabled in `.golangci.yml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum GolangciPreset {
+    /// golangci-lint's standard linters only, formatted with goimports
+    Minimal,
+    /// Standard linters plus security, error wrapping and style checks
+    Standard,
+    /// Standard plus complexity, duplication and magic number checks
+    Strict,
+}
+
+impl GolangciPreset {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GolangciPreset::Minimal => "minimal",
+            GolangciPreset::Standard => "standard",
+            GolangciPreset::Strict => "strict",
+        }
+    }
+
+    /// Preset a template gets unless one is asked for: web services are
+    /// held to the strict set.
+    pub fn for_template(template: &str) -> Self {
+        match template {
+            "web" => GolangciPreset::Strict,
+            _ => GolangciPreset::Standard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoToolchain {
+    /// Module path for a new `go.mod`. Inferred from the git remote when
+    /// unset; an existing `go.mod` always keeps its own.
+    pub module: Option<String>,
+    /// golangci-lint preset; the template's default when unset.
+    pub lint_preset: Option<GolangciPreset>,
+}
+
+impl GoToolchain {
+    /// Override the module path and lint preset.
+    pub fn with_overrides(
+        mut self,
+        module: Option<String>,
+        lint_preset: Option<GolangciPreset>,
+    ) -> Self {
+        if module.is_some() {
+            self.module = module;
+        }
+        if lint_preset.is_some() {
+            self.lint_preset = lint_preset;
+        }
+        self
+    }
+
+    /// The lint preset for `template`.
+    pub fn lint_preset(&self, template: &str) -> GolangciPreset {
+        self.lint_preset
+            .unwrap_or_else(|| GolangciPreset::for_template(template))
+    }
+}
@@ -4,6 +4,6 @@ pub mod error;
 pub mod generators;
 
 pub use commands::CommandHandler;
-pub use config::{EditorConfig, PackageJson, PrettierConfig};
+pub use config::{EditorConfig, Manifest, PackageJson, PrettierConfig};
 pub use error::ZackstrapError;
 pub use generators::{hooks::GitHooksGenerator, ConfigGenerator, ProjectType};
@@ -8,6 +8,13 @@ mod generators;
 
 use commands::CommandHandler;
 use error::ZackstrapError;
+use generators::git::INITIAL_COMMIT_MESSAGE;
+use generators::hooks::{HookKind, HooksManager};
+use generators::toolchain::{
+    EslintFormat, GolangciPreset, NodePackageManager, PythonLinter, PythonPackageManager,
+    RustLinker, TsStrictness,
+};
+use generators::ProjectType;
 
 #[derive(Parser)]
 #[command(
@@ -36,9 +43,22 @@ struct Cli {
     #[arg(long)]
     dry_run: bool,
 
-    /// Generate git hooks for the project
-    #[arg(long)]
-    hooks: bool,
+    /// Generate git hooks for the project. Optionally list the hooks to
+    /// install, e.g. --hooks=pre-commit,post-merge (default: pre-commit,
+    /// pre-push, commit-msg)
+    #[arg(
+        long,
+        value_enum,
+        value_name = "HOOKS",
+        num_args = 0..,
+        require_equals = true,
+        value_delimiter = ','
+    )]
+    hooks: Option<Vec<HookKind>>,
+
+    /// Tool that installs the git hooks (implies --hooks unless 'raw')
+    #[arg(long, value_enum, value_name = "MANAGER")]
+    hooks_manager: Option<HooksManager>,
 }
 
 #[derive(Subcommand)]
@@ -60,24 +80,61 @@ enum Commands {
         /// Template to use (e.g., 'default', 'django', 'flask')
         #[arg(short, long)]
         template: Option<String>,
+        /// Package manager (default: detected, otherwise pip)
+        #[arg(long, value_enum)]
+        package_manager: Option<PythonPackageManager>,
+        /// Linter (default: detected, otherwise flake8)
+        #[arg(long, value_enum)]
+        linter: Option<PythonLinter>,
     },
     /// Generate configuration files for a Node.js project
     Node {
-        /// Template to use (e.g., 'default', 'express', 'react')
+        /// Template to use (e.g., 'default', 'express', 'react', 'typescript', 'library', 'next', 'vite', 'nest')
         #[arg(short, long)]
         template: Option<String>,
+        /// Use TypeScript (implied by the 'typescript', 'library', 'next', 'vite' and 'nest' templates)
+        #[arg(long)]
+        typescript: bool,
+        /// tsconfig.json strictness preset (default: detected, otherwise strict)
+        #[arg(long, value_enum)]
+        ts_strictness: Option<TsStrictness>,
+        /// ESLint config format; `flat` converts an existing .eslintrc
+        /// (default: detected, otherwise flat)
+        #[arg(long, value_enum)]
+        eslint_format: Option<EslintFormat>,
+        /// Package manager (default: detected, otherwise npm)
+        #[arg(long, value_enum)]
+        package_manager: Option<NodePackageManager>,
+        /// Set up a monorepo with packages under apps/ and packages/
+        #[arg(long)]
+        workspaces: bool,
     },
     /// Generate configuration files for a Go project
     Go {
         /// Template to use (e.g., 'default', 'web', 'cli')
         #[arg(short, long)]
         template: Option<String>,
+        /// Module path for a new go.mod (default: from the git remote)
+        #[arg(long)]
+        module: Option<String>,
+        /// golangci-lint preset (default: strict for 'web', otherwise standard)
+        #[arg(long, value_enum)]
+        lint_preset: Option<GolangciPreset>,
     },
     /// Generate configuration files for a Rust project
     Rust {
-        /// Template to use (e.g., 'default', 'web', 'cli')
+        /// Template to use (e.g., 'default', 'web', 'cli', 'lib', 'workspace', 'embedded')
         #[arg(short, long)]
         template: Option<String>,
+        /// Optimize for this machine's CPU (binaries may not run elsewhere)
+        #[arg(long)]
+        native_cpu: bool,
+        /// Link Linux builds with a faster linker (needs clang and the linker)
+        #[arg(long, value_enum)]
+        linker: Option<RustLinker>,
+        /// Cache compiled crates with sccache (needs sccache installed)
+        #[arg(long)]
+        sccache: bool,
     },
     /// Generate configuration files for a Bash project
     Bash {
@@ -89,8 +146,73 @@ enum Commands {
     Auto,
     /// Interactive mode - guided configuration setup
     Interactive,
+    /// Initialize a git repository, then generate configs and git hooks
+    Init {
+        /// Project type (detected from the target directory when omitted)
+        #[arg(value_enum)]
+        language: Option<ProjectType>,
+        /// Template to use (e.g., 'rails', 'django', 'cli')
+        #[arg(short, long)]
+        template: Option<String>,
+        /// Default branch of a new repository
+        #[arg(short, long, default_value = "main")]
+        branch: String,
+        /// Commit the generated files, optionally with a custom message
+        /// (default: "chore: initial commit")
+        #[arg(
+            long,
+            value_name = "MESSAGE",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = INITIAL_COMMIT_MESSAGE
+        )]
+        commit: Option<String>,
+    },
     /// List all available configuration files
     List,
+    /// Install, inspect and run git hooks
+    Hooks {
+        #[command(subcommand)]
+        action: HooksAction,
+    },
+    /// Run a hook's checks natively (called by `--hooks-manager native` shims)
+    HookRun {
+        /// Hook to run
+        #[arg(value_enum)]
+        hook: HookKind,
+        /// Arguments git passed to the hook
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Remove zackstrap's git hooks (same as `hooks uninstall`)
+    #[command(hide = true)]
+    UninstallHooks,
+}
+
+#[derive(Subcommand)]
+enum HooksAction {
+    /// Install git hooks without generating configuration files
+    Install {
+        /// Project type (detected from the target directory when omitted)
+        #[arg(value_enum)]
+        language: Option<ProjectType>,
+        /// Template to use (e.g., 'rails', 'django', 'cli')
+        #[arg(short, long)]
+        template: Option<String>,
+    },
+    /// Remove zackstrap's git hooks and restore any hooks they replaced
+    Uninstall,
+    /// Show which hooks are managed by zackstrap, modified, or foreign
+    Status,
+    /// Run an installed hook against the currently staged files
+    Run {
+        /// Hook to run
+        #[arg(value_enum)]
+        hook: HookKind,
+        /// Arguments passed to the hook, as git would pass them
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
 }
 
 #[tokio::main]
@@ -114,20 +236,92 @@ async fn main() -> Result<(), ZackstrapError> {
         cli.force,
         cli.fail_on_exists,
         cli.dry_run,
-        cli.hooks,
+        cli.hooks.is_some() || cli.hooks_manager.is_some_and(|m| m != HooksManager::Raw),
+    )
+    .with_hooks_manager(cli.hooks_manager.unwrap_or_default())
+    .with_hook_kinds(
+        cli.hooks
+            .filter(|kinds| !kinds.is_empty())
+            .unwrap_or_else(|| HookKind::DEFAULT.to_vec()),
     );
 
     match cli.command {
         Commands::Basic { template } => handler.handle_basic(template).await?,
         Commands::Ruby { template } => handler.handle_ruby(template).await?,
-        Commands::Python { template } => handler.handle_python(template).await?,
-        Commands::Node { template } => handler.handle_node(template).await?,
-        Commands::Go { template } => handler.handle_go(template).await?,
-        Commands::Rust { template } => handler.handle_rust(template).await?,
+        Commands::Python {
+            template,
+            package_manager,
+            linter,
+        } => {
+            handler
+                .with_python_toolchain(package_manager, linter)
+                .handle_python(template)
+                .await?
+        }
+        Commands::Node {
+            template,
+            typescript,
+            ts_strictness,
+            eslint_format,
+            package_manager,
+            workspaces,
+        } => {
+            handler
+                .with_node_toolchain(
+                    typescript,
+                    ts_strictness,
+                    eslint_format,
+                    package_manager,
+                    workspaces,
+                )
+                .handle_node(template)
+                .await?
+        }
+        Commands::Go {
+            template,
+            module,
+            lint_preset,
+        } => {
+            handler
+                .with_go_toolchain(module, lint_preset)
+                .handle_go(template)
+                .await?
+        }
+        Commands::Rust {
+            template,
+            native_cpu,
+            linker,
+            sccache,
+        } => {
+            handler
+                .with_rust_toolchain(native_cpu, linker, sccache)
+                .handle_rust(template)
+                .await?
+        }
         Commands::Bash { template } => handler.handle_bash(template).await?,
         Commands::Auto => handler.handle_auto().await?,
         Commands::Interactive => handler.handle_interactive().await?,
+        Commands::Init {
+            language,
+            template,
+            branch,
+            commit,
+        } => {
+            handler
+                .handle_init(language, template, &branch, commit)
+                .await?
+        }
         Commands::List => handler.handle_list(),
+        Commands::Hooks { action } => match action {
+            HooksAction::Install { language, template } => {
+                handler.handle_hooks_install(language, template).await?
+            }
+            HooksAction::Uninstall => handler.handle_uninstall_hooks().await?,
+            HooksAction::Status => handler.handle_hooks_status().await?,
+            HooksAction::Run { hook, args } => handler.handle_hooks_run(hook, &args)?,
+        },
+        Commands::HookRun { hook, args } => handler.handle_hook_run(hook, &args).await?,
+        Commands::UninstallHooks => handler.handle_uninstall_hooks().await?,
     }
 
     Ok(())
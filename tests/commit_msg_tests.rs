@@ -0,0 +1,217 @@
+use assert_cmd::Command;
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use zackstrap::generators::hooks::CommitMsgPolicy;
+use zackstrap::{Manifest, ZackstrapError};
+
+const TEAM_MANIFEST: &str = r#"[commit-msg]
+types = ["feat", "fix", "chore"]
+scopes = ["api", "cli"]
+require-scope = true
+ticket-pattern = "[A-Z]+-[0-9]+"
+max-subject-length = 50
+body-line-length = 72
+"#;
+
+fn team_policy() -> CommitMsgPolicy {
+    let temp_dir = TempDir::new().unwrap();
+    temp_dir
+        .child(".zackstrap.toml")
+        .write_str(TEAM_MANIFEST)
+        .unwrap();
+    Manifest::load(temp_dir.path()).unwrap().commit_msg
+}
+
+/// Run the policy's shell snippet against `message` and report success.
+fn shell_accepts(policy: &CommitMsgPolicy, message: &str) -> bool {
+    let temp_dir = TempDir::new().unwrap();
+    let msg_file = temp_dir.child("COMMIT_EDITMSG");
+    msg_file.write_str(message).unwrap();
+    std::process::Command::new("sh")
+        .arg("-c")
+        .arg(policy.shell_check("$1"))
+        .arg("commit-msg")
+        .arg(msg_file.path())
+        .output()
+        .unwrap()
+        .status
+        .success()
+}
+
+#[test]
+fn test_default_policy_matches_conventional_commits() {
+    let policy = CommitMsgPolicy::default();
+
+    assert!(policy.check("feat: add new feature\n").is_empty());
+    assert!(policy
+        .check("fix(api): resolve authentication bug")
+        .is_empty());
+    assert!(policy.check("refactor!: drop the v1 API").is_empty());
+    assert_eq!(
+        policy.check("# only a comment\n\n"),
+        vec!["Commit message cannot be empty"]
+    );
+
+    let violations = policy.check("updated stuff");
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].contains("conventional commits"));
+    assert!(policy
+        .check("fix: typo")
+        .iter()
+        .any(|v| v.contains("at least 10")));
+}
+
+#[test]
+fn test_automated_commits_are_skipped_unless_disabled() {
+    let mut policy = CommitMsgPolicy::default();
+    for message in [
+        "Merge branch 'main' into feature",
+        "Revert \"feat: add new feature\"",
+        "fixup! feat: add new feature",
+        "squash! fix(api): resolve bug",
+    ] {
+        assert!(policy.check(message).is_empty(), "{}", message);
+    }
+
+    policy.skip_automated = false;
+    assert!(!policy.check("Merge branch 'main' into feature").is_empty());
+}
+
+#[test]
+fn test_manifest_policy_enforces_scopes_tickets_and_lengths() {
+    let policy = team_policy();
+
+    assert!(policy
+        .check("feat(api): add search ABC-123\n\nExplain the change here.\n")
+        .is_empty());
+
+    // Unknown types and scopes, and missing scopes, break the format rule
+    assert_eq!(policy.check("docs(api): update guide ABC-1").len(), 1);
+    assert_eq!(policy.check("feat(web): add search ABC-123").len(), 1);
+    assert_eq!(policy.check("feat: add search ABC-123").len(), 1);
+
+    let violations = policy.check("feat(cli): add a search command without a ticket");
+    assert_eq!(
+        violations,
+        vec!["Commit message must reference a ticket matching [A-Z]+-[0-9]+"]
+    );
+
+    // The ticket may live in the body, but body lines are wrapped at 72
+    let long_line = "x".repeat(73);
+    let violations = policy.check(&format!(
+        "feat(cli): add search command\n\nRefs ABC-9\n{}\n",
+        long_line
+    ));
+    assert_eq!(
+        violations,
+        vec!["Commit body lines must be at most 72 characters long"]
+    );
+
+    let violations = policy.check(&format!("fix(api): {} ABC-1", "y".repeat(50)));
+    assert_eq!(
+        violations,
+        vec!["Commit subject must be at most 50 characters long"]
+    );
+}
+
+#[test]
+fn test_comments_and_scissors_are_ignored() {
+    let policy = team_policy();
+    let message = "# Please enter the commit message\n\
+                   fix(cli): handle empty input ABC-7\n\
+                   # ------------------------ >8 ------------------------\n\
+                   diff --git a/src/main.rs b/src/main.rs with a very long line that is not part of the message body\n";
+    assert!(policy.check(message).is_empty());
+    assert!(shell_accepts(&policy, message));
+}
+
+#[test]
+fn test_shell_check_agrees_with_rust_check() {
+    let policies = [CommitMsgPolicy::default(), team_policy()];
+    let messages = [
+        "feat: add new feature",
+        "feat(api): add search ABC-123",
+        "feat(api): add search ABC-123\n\nA body line that is definitely longer than seventy-two characters in total.",
+        "docs(web): it's a quote ABC-2",
+        "fix: typo",
+        "bad message",
+        "",
+        "Merge branch 'main'",
+        "fixup! feat(api): add search ABC-123",
+    ];
+
+    for policy in &policies {
+        for message in messages {
+            assert_eq!(
+                shell_accepts(policy, message),
+                policy.check(message).is_empty(),
+                "policy {:?} disagrees on {:?}",
+                policy.types,
+                message
+            );
+        }
+    }
+}
+
+#[test]
+fn test_manifest_load_defaults_and_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    assert_eq!(
+        Manifest::load(temp_dir.path()).unwrap().commit_msg,
+        CommitMsgPolicy::default()
+    );
+
+    // Unset keys keep their defaults
+    temp_dir
+        .child(".zackstrap.toml")
+        .write_str("[commit-msg]\nmax-subject-length = 72\n")
+        .unwrap();
+    let policy = Manifest::load(temp_dir.path()).unwrap().commit_msg;
+    assert_eq!(policy.max_subject_length, Some(72));
+    assert_eq!(policy.types, CommitMsgPolicy::default().types);
+
+    temp_dir
+        .child(".zackstrap.toml")
+        .write_str("[commit-msg]\nticket-pattern = \"[A-Z+\"\n")
+        .unwrap();
+    assert!(matches!(
+        Manifest::load(temp_dir.path()),
+        Err(ZackstrapError::InvalidManifest(_, _))
+    ));
+
+    temp_dir
+        .child(".zackstrap.toml")
+        .write_str("[commit-msg]\nmax-length = 72\n")
+        .unwrap();
+    assert!(matches!(
+        Manifest::load(temp_dir.path()),
+        Err(ZackstrapError::InvalidManifest(_, _))
+    ));
+}
+
+#[test]
+fn test_hooks_manager_uses_manifest_policy() {
+    let temp_dir = TempDir::new().unwrap();
+    temp_dir
+        .child(".zackstrap.toml")
+        .write_str(TEAM_MANIFEST)
+        .unwrap();
+
+    Command::from_std(std::process::Command::new(assert_cmd::cargo::cargo_bin!(
+        "zackstrap"
+    )))
+    .arg("--target")
+    .arg(temp_dir.path())
+    .arg("--hooks-manager")
+    .arg("lefthook")
+    .arg("go")
+    .assert()
+    .success();
+
+    let content = std::fs::read_to_string(temp_dir.child("lefthook.yml").path()).unwrap();
+    let config: serde_yaml::Value = serde_yaml::from_str(&content).unwrap();
+    let run = config["commit-msg"]["jobs"][0]["run"].as_str().unwrap();
+    assert!(run.contains("'[A-Z]+-[0-9]+'"));
+    assert!(run.contains("^(feat|fix|chore)(\\((api|cli)\\))!?: .+"));
+    assert!(run.contains("\"{1}\""));
+}
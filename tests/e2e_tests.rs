@@ -182,6 +182,75 @@ fn e2e_python_flask() {
     );
 }
 
+#[test]
+fn e2e_python_package_managers_and_linters() {
+    for (package_manager, lockfile) in [
+        ("uv", Some("uv.lock")),
+        ("poetry", Some("poetry.lock")),
+        ("pdm", Some("pdm.lock")),
+        ("pip", None),
+    ] {
+        for linter in ["ruff", "flake8"] {
+            let temp = TempDir::new().unwrap();
+            let context = format!("python {} {}", package_manager, linter);
+            run_ok(
+                &temp,
+                &[
+                    "python",
+                    "--package-manager",
+                    package_manager,
+                    "--linter",
+                    linter,
+                ],
+            );
+
+            let p = temp.path();
+            assert_valid_toml(p.join("pyproject.toml").as_path(), &context);
+            let pyproject = std::fs::read_to_string(p.join("pyproject.toml")).unwrap();
+            let justfile = std::fs::read_to_string(p.join("justfile")).unwrap();
+            assert!(!pyproject.contains("[tool.flake8]"), "{}", context);
+            assert_eq!(
+                p.join(".flake8").exists(),
+                linter == "flake8",
+                "{}",
+                context
+            );
+            assert_eq!(
+                pyproject.contains("[tool.ruff]"),
+                linter == "ruff",
+                "{}",
+                context
+            );
+            assert_eq!(
+                p.join("requirements-dev.txt").exists(),
+                lockfile.is_none(),
+                "{}",
+                context
+            );
+            match lockfile {
+                Some(lockfile) => {
+                    assert!(
+                        justfile.contains(&format!("@{} run pytest", package_manager)),
+                        "{}",
+                        context
+                    );
+                    assert!(justfile.contains(lockfile), "{}", context);
+                }
+                None => assert!(justfile.contains("@pip install -r requirements-dev.txt")),
+            }
+        }
+    }
+
+    // Regenerating without options keeps the detected toolchain
+    let temp = TempDir::new().unwrap();
+    run_ok(
+        &temp,
+        &["python", "--package-manager", "poetry", "--linter", "ruff"],
+    );
+    let (stdout, _) = run_ok(&temp, &["--force", "python"]);
+    assert!(stdout.contains("poetry with ruff"), "{}", stdout);
+}
+
 // --- Node ---
 
 #[test]
@@ -191,15 +260,12 @@ fn e2e_node_default() {
 
     let p = temp.path();
     assert!(p.join("package.json").exists());
-    assert!(p.join(".eslintrc.json").exists());
+    assert!(p.join("eslint.config.mjs").exists());
+    assert!(!p.join(".eslintrc.json").exists());
     assert_valid_json(
         p.join("package.json").as_path(),
         "node default package.json",
     );
-    assert_valid_json(
-        p.join(".eslintrc.json").as_path(),
-        "node default .eslintrc.json",
-    );
     assert_valid_json(p.join(".prettierrc").as_path(), "node default .prettierrc");
 }
 
@@ -211,10 +277,7 @@ fn e2e_node_express() {
         temp.path().join("package.json").as_path(),
         "node express package.json",
     );
-    assert_valid_json(
-        temp.path().join(".eslintrc.json").as_path(),
-        "node express .eslintrc.json",
-    );
+    assert!(temp.path().join("eslint.config.mjs").exists());
 }
 
 #[test]
@@ -225,10 +288,456 @@ fn e2e_node_react() {
         temp.path().join("package.json").as_path(),
         "node react package.json",
     );
-    assert_valid_json(
-        temp.path().join(".eslintrc.json").as_path(),
-        "node react .eslintrc.json",
+    assert!(temp.path().join("eslint.config.mjs").exists());
+}
+
+#[test]
+fn e2e_node_typescript() {
+    for (args, template, types) in [
+        (
+            &["node", "--eslint-format", "legacy", "--typescript"][..],
+            "default",
+            &["@types/node"][..],
+        ),
+        (
+            &[
+                "node",
+                "--eslint-format",
+                "legacy",
+                "-t",
+                "express",
+                "--typescript",
+            ][..],
+            "express",
+            &["@types/node", "@types/express"][..],
+        ),
+        (
+            &[
+                "node",
+                "--eslint-format",
+                "legacy",
+                "-t",
+                "react",
+                "--typescript",
+            ][..],
+            "react",
+            &["@types/react", "@types/react-dom"][..],
+        ),
+        (
+            &["node", "--eslint-format", "legacy", "-t", "typescript"][..],
+            "typescript",
+            &["@types/node"][..],
+        ),
+        (
+            &["node", "--eslint-format", "legacy", "-t", "library"][..],
+            "library",
+            &["@types/node"][..],
+        ),
+    ] {
+        let temp = TempDir::new().unwrap();
+        let context = format!("node {} typescript", template);
+        run_ok(&temp, args);
+
+        let p = temp.path();
+        for file in ["tsconfig.json", ".eslintrc.json", "package.json"] {
+            assert_valid_json(p.join(file).as_path(), &format!("{} {}", context, file));
+        }
+        let read_json = |file: &str| -> serde_json::Value {
+            serde_json::from_str(&std::fs::read_to_string(p.join(file)).unwrap()).unwrap()
+        };
+        let tsconfig = read_json("tsconfig.json");
+        assert_eq!(tsconfig["compilerOptions"]["strict"], true, "{}", context);
+        assert_eq!(
+            tsconfig["compilerOptions"]["declaration"] == true,
+            template == "library",
+            "{}",
+            context
+        );
+        assert_eq!(
+            tsconfig["compilerOptions"]["jsx"] == "react-jsx",
+            template == "react",
+            "{}",
+            context
+        );
+
+        let eslint = read_json(".eslintrc.json");
+        assert_eq!(eslint["parser"], "@typescript-eslint/parser", "{}", context);
+        assert_eq!(eslint["parserOptions"]["project"], true, "{}", context);
+
+        let dev_dependencies = &read_json("package.json")["devDependencies"];
+        for name in ["typescript", "@typescript-eslint/parser"]
+            .iter()
+            .chain(types)
+        {
+            assert!(
+                dev_dependencies.get(*name).is_some(),
+                "{} {}",
+                context,
+                name
+            );
+        }
+        let justfile = std::fs::read_to_string(p.join("justfile")).unwrap();
+        assert!(justfile.contains("@npx tsc --noEmit"), "{}", context);
+    }
+
+    // Plain JavaScript projects get none of it
+    let temp = TempDir::new().unwrap();
+    run_ok(&temp, &["node"]);
+    assert!(!temp.path().join("tsconfig.json").exists());
+    let justfile = std::fs::read_to_string(temp.path().join("justfile")).unwrap();
+    assert!(!justfile.contains("typecheck"));
+
+    // Strictness presets, kept when regenerating without options
+    let temp = TempDir::new().unwrap();
+    run_ok(
+        &temp,
+        &[
+            "node",
+            "--eslint-format",
+            "legacy",
+            "--ts-strictness",
+            "strictest",
+        ],
     );
+    let tsconfig = std::fs::read_to_string(temp.path().join("tsconfig.json")).unwrap();
+    assert!(tsconfig.contains("\"noUncheckedIndexedAccess\": true"));
+    let eslint = std::fs::read_to_string(temp.path().join(".eslintrc.json")).unwrap();
+    assert!(eslint.contains("plugin:@typescript-eslint/strict-type-checked"));
+    let (stdout, _) = run_ok(&temp, &["--force", "node"]);
+    assert!(stdout.contains("TypeScript (strictest)"), "{}", stdout);
+
+    let temp = TempDir::new().unwrap();
+    run_ok(&temp, &["node", "--ts-strictness", "loose"]);
+    let tsconfig = std::fs::read_to_string(temp.path().join("tsconfig.json")).unwrap();
+    assert!(tsconfig.contains("\"strict\": false"));
+}
+
+#[test]
+fn e2e_node_eslint_flat_config() {
+    for (template, typescript, plugins) in [
+        ("default", false, &[][..]),
+        ("express", false, &[][..]),
+        (
+            "react",
+            false,
+            &["eslint-plugin-react", "eslint-plugin-react-hooks"][..],
+        ),
+        ("typescript", true, &["typescript-eslint"][..]),
+        (
+            "react",
+            true,
+            &[
+                "typescript-eslint",
+                "eslint-plugin-react",
+                "eslint-plugin-react-hooks",
+            ][..],
+        ),
+    ] {
+        let temp = TempDir::new().unwrap();
+        let context = format!("node {} flat (typescript: {})", template, typescript);
+        let mut args = vec!["node", "--template", template];
+        if typescript {
+            args.push("--typescript");
+        }
+        run_ok(&temp, &args);
+
+        let p = temp.path();
+        assert!(!p.join(".eslintrc.json").exists(), "{}", context);
+        let config = std::fs::read_to_string(p.join("eslint.config.mjs")).unwrap();
+        let package: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(p.join("package.json")).unwrap())
+                .unwrap();
+        let dev_dependencies = &package["devDependencies"];
+        assert_eq!(dev_dependencies["eslint"], "^9.0.0", "{}", context);
+        assert!(dev_dependencies.get("@eslint/js").is_some(), "{}", context);
+        assert!(
+            dev_dependencies.get("@typescript-eslint/parser").is_none(),
+            "{}",
+            context
+        );
+        for plugin in plugins {
+            assert!(
+                config.contains(&format!("from \"{}\"", plugin)),
+                "{}",
+                context
+            );
+            assert!(dev_dependencies.get(*plugin).is_some(), "{}", context);
+        }
+        assert_eq!(
+            config.contains("projectService: true"),
+            typescript,
+            "{}",
+            context
+        );
+        assert_eq!(
+            config.contains(r#""no-console": "off""#),
+            template == "express",
+            "{}",
+            context
+        );
+    }
+
+    let temp = TempDir::new().unwrap();
+    run_ok(&temp, &["node", "--ts-strictness", "strictest"]);
+    let config = std::fs::read_to_string(temp.path().join("eslint.config.mjs")).unwrap();
+    assert!(config.contains("...tseslint.configs.strictTypeChecked,"));
+}
+
+#[test]
+fn e2e_node_eslint_legacy_config_migration() {
+    let temp = TempDir::new().unwrap();
+    let p = temp.path();
+    std::fs::write(
+        p.join(".eslintrc.yml"),
+        "env:\n  node: true\nextends: eslint:recommended\nrules:\n  semi: error\n",
+    )
+    .unwrap();
+
+    // A legacy project stays legacy unless asked to convert
+    run_ok(&temp, &["node"]);
+    assert!(!p.join("eslint.config.mjs").exists());
+
+    let (stdout, _) = run_ok(&temp, &["node", "--eslint-format", "flat"]);
+    assert!(
+        stdout.contains("Converting .eslintrc.yml to eslint.config.mjs"),
+        "{}",
+        stdout
+    );
+    let config = std::fs::read_to_string(p.join("eslint.config.mjs")).unwrap();
+    assert!(config.contains("import { FlatCompat } from \"@eslint/eslintrc\";"));
+    assert!(config.contains("...compat.config({"));
+    assert!(config.contains("\"semi\": \"error\""));
+    assert!(config.contains("delete .eslintrc.yml"));
+
+    // JavaScript configs are loaded rather than inlined
+    let temp = TempDir::new().unwrap();
+    std::fs::write(
+        temp.path().join(".eslintrc.js"),
+        "module.exports = { rules: {} };\n",
+    )
+    .unwrap();
+    run_ok(&temp, &["node", "--eslint-format", "flat"]);
+    let config = std::fs::read_to_string(temp.path().join("eslint.config.mjs")).unwrap();
+    assert!(config.contains("createRequire(import.meta.url)(\"./.eslintrc.js\")"));
+
+    // Once converted, regenerating keeps the flat config
+    let (stdout, _) = run_ok(&temp, &["node"]);
+    assert!(!stdout.contains("Converting"), "{}", stdout);
+}
+
+#[test]
+fn e2e_node_package_managers() {
+    for (package_manager, config, install, test) in [
+        ("npm", Some(".npmrc"), "@npm install", "@npm test"),
+        ("pnpm", Some(".npmrc"), "@pnpm install", "@pnpm test"),
+        ("yarn", Some(".yarnrc.yml"), "@yarn install", "@yarn test"),
+        ("bun", None, "@bun install", "@bun run test"),
+    ] {
+        let temp = TempDir::new().unwrap();
+        let context = format!("node {}", package_manager);
+        run_ok(&temp, &["node", "--package-manager", package_manager]);
+
+        let p = temp.path();
+        let package: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(p.join("package.json")).unwrap())
+                .unwrap();
+        assert!(
+            package["packageManager"]
+                .as_str()
+                .unwrap()
+                .starts_with(&format!("{}@", package_manager)),
+            "{}",
+            context
+        );
+        if package_manager == "bun" {
+            // `bun audit` and the text bun.lock need Bun 1.2.15+
+            let version: Vec<u32> = package["packageManager"].as_str().unwrap()[4..]
+                .split('.')
+                .map(|part| part.parse().unwrap())
+                .collect();
+            assert!(version >= vec![1, 2, 15], "{:?}", version);
+        }
+        assert!(package.get("workspaces").is_none(), "{}", context);
+        if let Some(config) = config {
+            assert!(p.join(config).exists(), "{} {}", context, config);
+        }
+        let justfile = std::fs::read_to_string(p.join("justfile")).unwrap();
+        assert!(justfile.contains(install), "{}", context);
+        assert!(justfile.contains(test), "{}", context);
+        if package_manager != "npm" {
+            assert!(!justfile.contains("@npm "), "{}", context);
+        }
+    }
+    // Workspaces: pnpm has its own file, the others use package.json
+    let temp = TempDir::new().unwrap();
+    run_ok(
+        &temp,
+        &["node", "--package-manager", "pnpm", "--workspaces"],
+    );
+    let workspace = std::fs::read_to_string(temp.path().join("pnpm-workspace.yaml")).unwrap();
+    let workspace: serde_yaml::Value = serde_yaml::from_str(&workspace).unwrap();
+    assert_eq!(workspace["packages"][0], "apps/*");
+    let package = std::fs::read_to_string(temp.path().join("package.json")).unwrap();
+    assert!(package.contains("\"private\":true"));
+    assert!(!package.contains("\"workspaces\""));
+
+    let temp = TempDir::new().unwrap();
+    run_ok(
+        &temp,
+        &["node", "--package-manager", "yarn", "--workspaces"],
+    );
+    assert!(!temp.path().join("pnpm-workspace.yaml").exists());
+    let package: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(temp.path().join("package.json")).unwrap())
+            .unwrap();
+    assert_eq!(package["private"], true);
+    assert_eq!(
+        package["workspaces"],
+        serde_json::json!(["apps/*", "packages/*"])
+    );
+
+    // An existing lockfile picks the package manager
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("yarn.lock"), "").unwrap();
+    let (stdout, _) = run_ok(&temp, &["node"]);
+    assert!(stdout.contains("(template: default, yarn)"), "{}", stdout);
+    let justfile = std::fs::read_to_string(temp.path().join("justfile")).unwrap();
+    assert!(justfile.contains("@yarn install"));
+
+    // An existing package.json keeps its keys and gains packageManager
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("pnpm-lock.yaml"), "").unwrap();
+    std::fs::write(
+        temp.path().join("package.json"),
+        r#"{"name": "existing-app", "scripts": {"test": "mocha"}, "dependencies": {"lodash": "^4.17.21"}}"#,
+    )
+    .unwrap();
+    run_ok(&temp, &["node"]);
+    let package: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(temp.path().join("package.json")).unwrap())
+            .unwrap();
+    assert_eq!(package["name"], "existing-app");
+    assert_eq!(package["scripts"]["test"], "mocha");
+    assert_eq!(package["dependencies"]["lodash"], "^4.17.21");
+    assert!(package["packageManager"]
+        .as_str()
+        .unwrap()
+        .starts_with("pnpm@"));
+}
+
+#[test]
+fn e2e_node_framework_templates() {
+    for (template, script, file) in [
+        ("next", "next dev", None),
+        ("vite", "vite", Some("vite.config.ts")),
+        ("nest", "nest start --watch", Some("nest-cli.json")),
+        ("library", "tsup --watch", Some("tsup.config.ts")),
+    ] {
+        let temp = TempDir::new().unwrap();
+        run_ok(&temp, &["node", "--template", template]);
+        let p = temp.path();
+        let package: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(p.join("package.json")).unwrap())
+                .unwrap();
+        assert_eq!(package["scripts"]["dev"], script, "{}", template);
+        assert!(
+            package["devDependencies"]["typescript"].is_string(),
+            "{}",
+            template
+        );
+        // The pre-push hook runs the tests with --coverage, which vitest
+        // needs a provider for
+        if package["scripts"]["test"] == "vitest run" {
+            assert_eq!(
+                package["devDependencies"]["@vitest/coverage-v8"],
+                package["devDependencies"]["vitest"],
+                "{}",
+                template
+            );
+        }
+        let tsconfig: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(p.join("tsconfig.json")).unwrap())
+                .unwrap();
+        assert_eq!(tsconfig["compilerOptions"]["strict"], true, "{}", template);
+        if let Some(file) = file {
+            assert!(p.join(file).exists(), "{} {}", template, file);
+        }
+        // Tool configs are outside tsconfig.json, so type-aware linting is
+        // turned off for them
+        if let Some(file) = file.filter(|file| file.ends_with(".config.ts")) {
+            let eslint = std::fs::read_to_string(p.join("eslint.config.mjs")).unwrap();
+            assert!(
+                eslint.contains(
+                    "files: [\"**/*.{js,mjs,cjs}\", \"*.config.{ts,mts}\"],\n    ...tseslint.configs.disableTypeChecked,"
+                ),
+                "{} {}",
+                template,
+                file
+            );
+
+            let legacy = TempDir::new().unwrap();
+            run_ok(
+                &legacy,
+                &["node", "--eslint-format", "legacy", "--template", template],
+            );
+            let eslint: serde_json::Value = serde_json::from_str(
+                &std::fs::read_to_string(legacy.path().join(".eslintrc.json")).unwrap(),
+            )
+            .unwrap();
+            let overrides = &eslint["overrides"][0];
+            assert_eq!(overrides["files"][0], "*.config.ts", "{}", template);
+            assert_eq!(
+                overrides["extends"][0], "plugin:@typescript-eslint/disable-type-checked",
+                "{}",
+                template
+            );
+        }
+        let justfile = std::fs::read_to_string(p.join("justfile")).unwrap();
+        assert!(justfile.contains("@npm run dev"), "{}", template);
+        assert!(justfile.contains("@npx tsc --noEmit"), "{}", template);
+    }
+
+    // Next.js loads its shared config through FlatCompat
+    let temp = TempDir::new().unwrap();
+    run_ok(&temp, &["node", "--template", "next"]);
+    let eslint = std::fs::read_to_string(temp.path().join("eslint.config.mjs")).unwrap();
+    assert!(eslint.contains(r#"compat.extends("next/core-web-vitals", "next/typescript")"#));
+    let tsconfig = std::fs::read_to_string(temp.path().join("tsconfig.json")).unwrap();
+    assert!(tsconfig.contains("next-env.d.ts"));
+    assert!(!temp.path().join("vite.config.ts").exists());
+    let gitignore = std::fs::read_to_string(temp.path().join(".gitignore")).unwrap();
+    assert!(gitignore.contains(".next/"));
+    let justfile = std::fs::read_to_string(temp.path().join("justfile")).unwrap();
+    assert!(justfile.contains("@npm start"));
+
+    // NestJS tests run through ts-jest
+    let temp = TempDir::new().unwrap();
+    run_ok(&temp, &["node", "--template", "nest"]);
+    let jest: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(temp.path().join("jest.config.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(jest["transform"]["^.+\\.(t|j)s$"], "ts-jest");
+
+    // Libraries publish ESM and CommonJS builds with declarations
+    let temp = TempDir::new().unwrap();
+    run_ok(&temp, &["node", "--template", "library"]);
+    let package: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(temp.path().join("package.json")).unwrap())
+            .unwrap();
+    assert_eq!(package["type"], "module");
+    assert_eq!(
+        package["exports"]["."]["import"]["default"],
+        "./dist/index.js"
+    );
+    assert_eq!(
+        package["exports"]["."]["require"]["default"],
+        "./dist/index.cjs"
+    );
+    assert_eq!(package["types"], "./dist/index.d.ts");
+    let tsup = std::fs::read_to_string(temp.path().join("tsup.config.ts")).unwrap();
+    assert!(tsup.contains(r#"format: ["esm", "cjs"]"#));
+    assert!(tsup.contains("dts: true"));
 }
 
 // --- Go --- (go.mod is not TOML, just assert exists + content)
@@ -243,7 +752,7 @@ fn e2e_go_default() {
     assert!(go_mod.exists(), "missing go.mod");
     let content = std::fs::read_to_string(&go_mod).unwrap();
     assert!(
-        content.contains("module myproject") && content.contains("go 1.21"),
+        !content.contains("module myproject") && content.contains("go 1.22"),
         "go.mod missing expected content: {}",
         content
     );
@@ -255,6 +764,88 @@ fn e2e_go_web() {
     let temp = TempDir::new().unwrap();
     run_ok(&temp, &["go", "--template", "web"]);
     assert!(temp.path().join("go.mod").exists());
+    assert!(temp.path().join("cmd/server/main.go").exists());
+    assert!(temp.path().join("internal/server/server_test.go").exists());
+}
+
+#[test]
+fn e2e_go_module_path() {
+    // From the git remote, including the project's subdirectory
+    let temp = TempDir::new().unwrap();
+    let git = |args: &[&str]| {
+        assert!(std::process::Command::new("git")
+            .args(args)
+            .current_dir(temp.path())
+            .output()
+            .unwrap()
+            .status
+            .success());
+    };
+    git(&["init", "--quiet"]);
+    git(&["remote", "add", "origin", "git@github.com:acme/widgets.git"]);
+    std::fs::create_dir(temp.path().join("api")).unwrap();
+    let mut cmd = zackstrap_cmd();
+    cmd.arg("--target")
+        .arg(temp.path().join("api"))
+        .args(["go", "--template", "cli"]);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let go_mod = std::fs::read_to_string(temp.path().join("api/go.mod")).unwrap();
+    assert!(
+        go_mod.starts_with("module github.com/acme/widgets/api\n"),
+        "{}",
+        go_mod
+    );
+    let main = std::fs::read_to_string(temp.path().join("api/cmd/cli/main.go")).unwrap();
+    assert!(main.contains("\"github.com/acme/widgets/api/internal/cli\""));
+
+    // --module wins over the remote
+    let temp = TempDir::new().unwrap();
+    let (stdout, _) = run_ok(&temp, &["go", "--module", "example.com/tool"]);
+    assert!(stdout.contains("module example.com/tool"), "{}", stdout);
+    let go_mod = std::fs::read_to_string(temp.path().join("go.mod")).unwrap();
+    assert_eq!(go_mod, "module example.com/tool\n\ngo 1.22\n");
+}
+
+#[test]
+fn e2e_bash_scripts_run() {
+    let temp = TempDir::new().unwrap();
+    let project = temp.path().join("greeter");
+    std::fs::create_dir(&project).unwrap();
+    let bash = |dir: &Path, args: &[&str]| {
+        std::process::Command::new("bash")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+    };
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(&project)
+        .args(["bash", "--template", "cli"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("script bin/greeter"));
+    let output = bash(&project, &["bin/greeter", "--greeting", "Hi", "Ada"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Hi, Ada!\n");
+    let output = bash(&project, &["bin/greeter", "--version"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "greeter 0.1.0\n");
+    let output = bash(&project, &["bin/greeter", "--nope"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    // The default template's entry point sources its library
+    let project = temp.path().join("hello");
+    std::fs::create_dir(&project).unwrap();
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(&project)
+        .arg("bash")
+        .assert()
+        .success();
+    let output = bash(&project, &["bin/hello"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Hello, world!\n");
 }
 
 #[test]
@@ -305,6 +896,110 @@ fn e2e_rust_cli() {
     );
 }
 
+#[test]
+fn e2e_rust_crate_templates() {
+    for template in ["lib", "workspace", "embedded"] {
+        let temp = TempDir::new().unwrap();
+        run_ok(&temp, &["rust", "--template", template]);
+        let p = temp.path();
+        for file in [
+            "rust-toolchain.toml",
+            "deny.toml",
+            "release.toml",
+            ".cargo/config.toml",
+        ] {
+            assert_valid_toml(
+                p.join(file).as_path(),
+                &format!("rust {} {}", template, file),
+            );
+        }
+        let justfile = std::fs::read_to_string(p.join("justfile")).unwrap();
+        assert!(justfile.contains("cargo deny check"), "{}", template);
+        assert!(justfile.contains("cargo doc"), "{}", template);
+        if template != "embedded" {
+            assert!(justfile.contains("cargo nextest run"), "{}", template);
+        }
+    }
+
+    // The workspace template writes the workspace root with shared lints
+    let temp = TempDir::new().unwrap();
+    run_ok(&temp, &["rust", "--template", "workspace"]);
+    let manifest: toml::Value =
+        toml::from_str(&std::fs::read_to_string(temp.path().join("Cargo.toml")).unwrap()).unwrap();
+    assert_eq!(
+        manifest["workspace"]["members"][0].as_str(),
+        Some("crates/*")
+    );
+    assert_eq!(
+        manifest["workspace"]["lints"]["rust"]["unsafe_code"].as_str(),
+        Some("forbid")
+    );
+
+    // Embedded projects cross-compile and keep unsafe code allowed
+    let temp = TempDir::new().unwrap();
+    run_ok(&temp, &["rust", "--template", "embedded"]);
+    let toolchain = std::fs::read_to_string(temp.path().join("rust-toolchain.toml")).unwrap();
+    assert!(toolchain.contains("thumbv7em-none-eabihf"));
+    let config = std::fs::read_to_string(temp.path().join(".cargo/config.toml")).unwrap();
+    assert!(config.contains("probe-rs run"));
+    assert!(!temp.path().join("Cargo.toml").exists());
+}
+
+#[test]
+fn e2e_rust_lints_merge_into_existing_manifest() {
+    let temp = TempDir::new().unwrap();
+    let manifest = "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[lints.rust]\nunsafe_code = \"allow\"\n";
+    std::fs::write(temp.path().join("Cargo.toml"), manifest).unwrap();
+    let (stdout, _) = run_ok(&temp, &["--force", "rust", "--template", "lib"]);
+
+    let merged = std::fs::read_to_string(temp.path().join("Cargo.toml")).unwrap();
+    assert!(merged.starts_with(manifest), "{}", merged);
+    assert!(merged.contains("missing_docs = \"warn\""));
+    assert!(merged.contains("[lints.clippy]"));
+    assert!(stdout.contains("lints.rust.unsafe_code"), "{}", stdout);
+}
+
+#[test]
+fn e2e_rust_profiles_move_to_cargo_toml() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir(temp.path().join(".cargo")).unwrap();
+    std::fs::write(
+        temp.path().join(".cargo/config.toml"),
+        "[build]\ntarget = \"target\"\n",
+    )
+    .unwrap();
+    let (stdout, _) = run_ok(&temp, &["rust"]);
+    assert!(stdout.contains("[INVALID]"), "{}", stdout);
+    assert!(stdout.contains("build.target = \"target\""), "{}", stdout);
+
+    let manifest: toml::Value =
+        toml::from_str(&std::fs::read_to_string(temp.path().join("Cargo.toml")).unwrap()).unwrap();
+    assert_eq!(manifest["package"]["name"].as_str(), Some("demo"));
+    assert_eq!(
+        manifest["profile"]["release"]["codegen-units"].as_integer(),
+        Some(1)
+    );
+    // Plain packages do not get lint tables, only crate templates do
+    assert!(manifest.get("lints").is_none());
+
+    // --force regenerates a clean config
+    let (stdout, _) = run_ok(&temp, &["--force", "rust", "--sccache"]);
+    assert!(!stdout.contains("[INVALID]"), "{}", stdout);
+    assert!(
+        stdout.contains("(template: default, sccache)"),
+        "{}",
+        stdout
+    );
+    let config = std::fs::read_to_string(temp.path().join(".cargo/config.toml")).unwrap();
+    assert!(!config.contains("target = \"target\""));
+    assert!(config.contains("rustc-wrapper = \"sccache\""));
+}
+
 // --- Auto ---
 
 #[test]
@@ -348,10 +1043,7 @@ fn e2e_auto_node() {
         temp.path().join("package.json").as_path(),
         "auto node package.json",
     );
-    assert_valid_json(
-        temp.path().join(".eslintrc.json").as_path(),
-        "auto node .eslintrc.json",
-    );
+    assert!(temp.path().join("eslint.config.mjs").exists());
 }
 
 #[test]
@@ -242,3 +242,34 @@ async fn test_fail_on_exists_mixed_scenarios() {
     let result = force_generator.generate_basic(true).await;
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_fail_on_exists_merged_file() {
+    use zackstrap::generators::merge::MergeStrategy;
+
+    let temp_dir = TempDir::new().unwrap();
+    let generator = ConfigGenerator::new(temp_dir.path().to_path_buf());
+    std::fs::write(temp_dir.path().join(".gitignore"), "target/\n").unwrap();
+
+    // An existing file is not merged into when fail_on_exists=true
+    let result = generator
+        .emit_merged_file(".gitignore", "*.log\n", MergeStrategy::ManagedBlock, true)
+        .await;
+    assert!(matches!(
+        result.unwrap_err(),
+        zackstrap::error::ZackstrapError::FileExists(_)
+    ));
+    assert_eq!(
+        std::fs::read_to_string(temp_dir.path().join(".gitignore")).unwrap(),
+        "target/\n"
+    );
+
+    // With fail_on_exists=false the generated block is merged in
+    let result = generator
+        .emit_merged_file(".gitignore", "*.log\n", MergeStrategy::ManagedBlock, false)
+        .await;
+    assert!(result.is_ok());
+    let content = std::fs::read_to_string(temp_dir.path().join(".gitignore")).unwrap();
+    assert!(content.starts_with("target/\n"));
+    assert!(content.contains("*.log\n"));
+}
@@ -0,0 +1,1633 @@
+use assert_cmd::Command;
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use zackstrap::generators::git::GitRepo;
+use zackstrap::generators::hooks::chain::DISPATCHER_MARKER;
+use zackstrap::generators::hooks::checks::{hook_checks, HookKind};
+use zackstrap::generators::hooks::managers::{
+    render_husky_hook, render_lefthook_config, render_pre_commit_config, HookPlan, HooksManager,
+};
+use zackstrap::generators::hooks::runner::RunnerCheck;
+use zackstrap::generators::hooks::{lifecycle, CommitMsgPolicy, UninstallOutcome};
+use zackstrap::generators::toolchain::{
+    NodePackageManager, NodeToolchain, PythonLinter, PythonPackageManager, PythonToolchain,
+    Toolchain,
+};
+use zackstrap::{ConfigGenerator, GitHooksGenerator, ProjectType};
+
+fn default_plan(project_type: &ProjectType, template: &str) -> HookPlan {
+    HookPlan::new(
+        project_type,
+        template,
+        &Toolchain::default(),
+        &HookKind::DEFAULT,
+        CommitMsgPolicy::default(),
+    )
+}
+
+fn zackstrap_cmd() -> Command {
+    Command::from_std(std::process::Command::new(assert_cmd::cargo::cargo_bin!(
+        "zackstrap"
+    )))
+}
+
+#[test]
+fn test_hook_checks_per_template() {
+    let checks = hook_checks(&ProjectType::Rust, "cli", &Toolchain::default());
+    let commands = |kind: HookKind| -> Vec<&str> {
+        checks
+            .iter()
+            .filter(|c| c.hook == kind)
+            .map(|c| c.command.as_ref())
+            .collect()
+    };
+
+    assert_eq!(
+        commands(HookKind::PreCommit),
+        vec![
+            "rustfmt --check {files}",
+            "cargo check",
+            "cargo clippy --all-targets --all-features -- -D warnings",
+            "cargo build",
+        ]
+    );
+    assert!(commands(HookKind::PrePush).contains(&"cargo test --all-features"));
+    assert!(commands(HookKind::PrePush).contains(&"cargo build --release"));
+
+    let django = hook_checks(&ProjectType::Python, "django", &Toolchain::default());
+    assert!(django
+        .iter()
+        .any(|c| c.command == "python manage.py check --deploy" && c.hook == HookKind::PrePush));
+}
+
+#[tokio::test]
+async fn test_raw_hooks_run_every_hook_check() {
+    let templates: &[(ProjectType, &[&str])] = &[
+        (ProjectType::Ruby, &["default", "rails", "sinatra", "gem"]),
+        (ProjectType::Python, &["default", "django", "flask"]),
+        (
+            ProjectType::Node,
+            &[
+                "default", "express", "react", "next", "vite", "nest", "library",
+            ],
+        ),
+        (ProjectType::Go, &["default", "web", "cli"]),
+        (
+            ProjectType::Rust,
+            &["default", "web", "cli", "lib", "workspace", "embedded"],
+        ),
+        (ProjectType::Bash, &["default", "cli", "devops"]),
+        (ProjectType::Basic, &["default"]),
+    ];
+    for (project_type, templates) in templates {
+        for template in *templates {
+            let temp_dir = TempDir::new().unwrap();
+            git_init(&temp_dir);
+            GitHooksGenerator::new(temp_dir.path().to_path_buf())
+                .generate_hooks(project_type, template, false)
+                .await
+                .unwrap();
+            let script = |kind: HookKind| {
+                std::fs::read_to_string(
+                    temp_dir
+                        .path()
+                        .join(format!(".git/hooks/{}.d/50-zackstrap", kind.as_str())),
+                )
+                .unwrap()
+            };
+            let (pre_commit, pre_push) = (script(HookKind::PreCommit), script(HookKind::PrePush));
+
+            // The scripts are generated from the checks, so they cannot drift
+            for check in hook_checks(project_type, template, &Toolchain::default()) {
+                let script = match check.hook {
+                    HookKind::PreCommit => &pre_commit,
+                    _ => &pre_push,
+                };
+                let context = format!("{:?} {} {}", project_type, template, check.id);
+                assert!(
+                    script.contains(&format!("\n    {}\n", check.command_with_files("\"$@\""))),
+                    "{}",
+                    context
+                );
+                if let (HookKind::PreCommit, Some(regex)) = (check.hook, check.files_regex()) {
+                    assert!(
+                        script.contains(&format!("\"{}\" '{}'\n", check.name, regex)),
+                        "{}",
+                        context
+                    );
+                }
+            }
+        }
+    }
+
+    // The CLI build also runs when only the manifest or lockfile changes
+    let checks = hook_checks(&ProjectType::Rust, "cli", &Toolchain::default());
+    let build = checks.iter().find(|c| c.id == "cargo-build").unwrap();
+    assert_eq!(
+        build.files_regex().unwrap(),
+        "\\.rs$|(^|/)Cargo\\.toml$|(^|/)Cargo\\.lock$"
+    );
+}
+
+#[test]
+fn test_pre_commit_config_runs_every_check() {
+    let plan = default_plan(&ProjectType::Go, "web");
+    let checks = &plan.checks;
+    let content = render_pre_commit_config(&plan);
+    let config: serde_yaml::Value = serde_yaml::from_str(&content).unwrap();
+
+    let install_types: Vec<&str> = config["default_install_hook_types"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    assert_eq!(install_types, vec!["pre-commit", "pre-push", "commit-msg"]);
+
+    let repo = &config["repos"][0];
+    assert_eq!(repo["repo"].as_str(), Some("local"));
+    let hooks = repo["hooks"].as_sequence().unwrap();
+    assert_eq!(hooks.len(), checks.len() + 1);
+    for (hook, check) in hooks.iter().zip(checks) {
+        assert_eq!(hook["id"].as_str(), Some(check.id));
+        assert_eq!(
+            hook["args"][0].as_str(),
+            Some(check.command_with_files("\"$@\"").as_str())
+        );
+        assert_eq!(hook["pass_filenames"].as_bool(), Some(check.takes_files()));
+        assert_eq!(
+            hook["files"].as_str().map(str::to_string),
+            check.files_regex()
+        );
+        assert_eq!(hook["stages"][0].as_str(), Some(check.hook.as_str()));
+        assert_eq!(hook["language"].as_str(), Some("system"));
+    }
+    assert_eq!(
+        hooks.last().unwrap()["stages"][0].as_str(),
+        Some("commit-msg")
+    );
+}
+
+#[test]
+fn test_lefthook_config_keeps_check_order() {
+    let plan = default_plan(&ProjectType::Bash, "devops");
+    let checks = &plan.checks;
+    let content = render_lefthook_config(&plan);
+    let config: serde_yaml::Value = serde_yaml::from_str(&content).unwrap();
+
+    let jobs: Vec<&str> = config["pre-commit"]["jobs"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .filter_map(|job| job["name"].as_str())
+        .collect();
+    let expected: Vec<&str> = checks
+        .iter()
+        .filter(|c| c.hook == HookKind::PreCommit)
+        .map(|c| c.id)
+        .collect();
+    assert_eq!(jobs, expected);
+    assert_eq!(config["pre-commit"]["piped"].as_bool(), Some(true));
+    assert!(config["commit-msg"]["jobs"][0]["run"]
+        .as_str()
+        .unwrap()
+        .contains("{1}"));
+}
+
+#[test]
+fn test_pre_commit_checks_only_staged_files() {
+    for project_type in [
+        ProjectType::Ruby,
+        ProjectType::Python,
+        ProjectType::Node,
+        ProjectType::Go,
+        ProjectType::Rust,
+        ProjectType::Bash,
+        ProjectType::Basic,
+    ] {
+        for check in hook_checks(&project_type, "default", &Toolchain::default()) {
+            if check.hook == HookKind::PreCommit {
+                assert!(
+                    !check.extensions.is_empty(),
+                    "{} runs on every commit",
+                    check.id
+                );
+            }
+        }
+    }
+
+    let content = render_lefthook_config(&default_plan(&ProjectType::Python, "default"));
+    let config: serde_yaml::Value = serde_yaml::from_str(&content).unwrap();
+    let black = &config["pre-commit"]["jobs"][0];
+    assert_eq!(black["run"].as_str(), Some("black --check {staged_files}"));
+    assert_eq!(black["glob"].as_str(), Some("*.{py,pyi}"));
+    assert!(config["pre-push"]["jobs"][0]["glob"].is_null());
+}
+
+#[test]
+fn test_husky_hooks_list_commands() {
+    let plan = default_plan(&ProjectType::Node, "express");
+    let pre_commit = render_husky_hook(HookKind::PreCommit, &plan);
+    assert!(pre_commit.contains("git diff --cached --name-only --diff-filter=ACM"));
+    assert!(pre_commit.contains("    npx eslint \"$@\"\n"));
+    assert!(!pre_commit.contains("npm test"));
+
+    let pre_push = render_husky_hook(HookKind::PrePush, &plan);
+    assert!(pre_push.contains("npm audit\n"));
+    assert!(pre_push.contains("npm run build\n"));
+    assert!(pre_push.contains("npm test -- --coverage\n"));
+    assert!(!pre_push.contains("npx eslint"));
+
+    let commit_msg = render_husky_hook(HookKind::CommitMsg, &plan);
+    assert!(commit_msg.contains("/^#/d' \"$1\""));
+}
+
+#[tokio::test]
+async fn test_husky_merges_prepare_script_into_package_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let generator = ConfigGenerator::new(temp_dir.path().to_path_buf());
+    generator
+        .generate_node_with_template("express")
+        .await
+        .unwrap();
+    generator
+        .generate_hooks_manager_files(
+            HooksManager::Husky,
+            &default_plan(&ProjectType::Node, "express"),
+        )
+        .await
+        .unwrap();
+
+    temp_dir
+        .child(".husky/pre-commit")
+        .assert(predicates::str::contains("npx eslint \"$@\""));
+    temp_dir
+        .child(".husky/commit-msg")
+        .assert(predicates::path::exists());
+
+    let content = std::fs::read_to_string(temp_dir.child("package.json").path()).unwrap();
+    let package: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(package["name"].as_str(), Some("express-project"));
+    assert_eq!(package["scripts"]["prepare"].as_str(), Some("husky"));
+    assert!(package["devDependencies"]["husky"].is_string());
+    assert!(package["devDependencies"]["eslint"].is_string());
+}
+
+#[test]
+fn test_hooks_manager_does_not_need_git() {
+    let temp_dir = TempDir::new().unwrap();
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--hooks-manager")
+        .arg("lefthook")
+        .arg("rust")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("lefthook install"));
+
+    temp_dir
+        .child("lefthook.yml")
+        .assert(predicates::path::exists());
+    temp_dir.child(".git").assert(predicates::path::missing());
+}
+
+#[test]
+fn test_husky_install_hint_uses_package_manager() {
+    let temp_dir = TempDir::new().unwrap();
+    let output = zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args([
+            "--hooks-manager",
+            "husky",
+            "node",
+            "--package-manager",
+            "pnpm",
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8_lossy(&output.get_output().stdout).to_string();
+    assert!(
+        stdout.contains("Run `pnpm install` to activate"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("`npm install`"), "{}", stdout);
+}
+
+#[test]
+fn test_hooks_manager_dry_run_previews_config() {
+    let temp_dir = TempDir::new().unwrap();
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--dry-run")
+        .arg("--hooks-manager")
+        .arg("pre-commit")
+        .arg("python")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(".pre-commit-config.yaml"))
+        .stdout(predicates::str::contains("black --check \"$@\""));
+
+    temp_dir
+        .child(".pre-commit-config.yaml")
+        .assert(predicates::path::missing());
+}
+
+fn git_init(temp_dir: &TempDir) {
+    std::process::Command::new("git")
+        .arg("init")
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("git init");
+}
+
+fn git_config(temp_dir: &TempDir, key: &str) -> String {
+    let output = std::process::Command::new("git")
+        .args(["config", "--get", key])
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("git config");
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn test_githooks_manager_sets_core_hooks_path() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--hooks-manager")
+        .arg("githooks")
+        .arg("rust")
+        .assert()
+        .success();
+
+    for hook in ["pre-commit", "pre-push", "commit-msg"] {
+        temp_dir
+            .child(format!(".githooks/{}", hook))
+            .assert(predicates::path::exists());
+    }
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::missing());
+    assert_eq!(git_config(&temp_dir, "core.hooksPath"), ".githooks");
+    temp_dir.child("justfile").assert(predicates::str::contains(
+        "install-hooks:\n    git config core.hooksPath \"$(git rev-parse --show-prefix).githooks\"",
+    ));
+}
+
+#[tokio::test]
+async fn test_raw_hooks_follow_existing_core_hooks_path() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    std::process::Command::new("git")
+        .args(["config", "core.hooksPath", "tools/hooks"])
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+
+    let generator = GitHooksGenerator::new(temp_dir.path().to_path_buf());
+    assert_eq!(
+        generator.hooks_dir().await.unwrap(),
+        temp_dir.path().join("tools/hooks")
+    );
+    generator.generate_basic_hooks(false).await.unwrap();
+
+    temp_dir
+        .child("tools/hooks/pre-commit")
+        .assert(predicates::path::exists());
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::missing());
+}
+
+fn git(dir: &std::path::Path, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .args([
+            "-c",
+            "user.name=zackstrap",
+            "-c",
+            "user.email=zackstrap@example.com",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {:?}",
+        args,
+        output
+    );
+}
+
+#[tokio::test]
+async fn test_hooks_from_subdirectory_land_in_repo_root() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    temp_dir.child("services/api").create_dir_all().unwrap();
+
+    let generator = GitHooksGenerator::new(temp_dir.child("services/api").path().to_path_buf());
+    generator.generate_basic_hooks(false).await.unwrap();
+
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::exists());
+}
+
+#[tokio::test]
+async fn test_worktree_hooks_go_to_common_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let main = temp_dir.child("main");
+    main.create_dir_all().unwrap();
+    git(main.path(), &["init"]);
+    git(main.path(), &["commit", "--allow-empty", "-m", "init"]);
+    git(main.path(), &["worktree", "add", "../feature"]);
+
+    let repo = GitRepo::discover(temp_dir.child("feature").path()).unwrap();
+    let main_git = std::fs::canonicalize(main.child(".git").path()).unwrap();
+    assert_eq!(repo.common_dir, main_git);
+    assert_eq!(repo.git_dir, main_git.join("worktrees/feature"));
+
+    let generator = GitHooksGenerator::new(temp_dir.child("feature").path().to_path_buf());
+    generator.generate_basic_hooks(false).await.unwrap();
+    main.child(".git/hooks/commit-msg")
+        .assert(predicates::path::exists());
+}
+
+#[tokio::test]
+async fn test_submodule_gitdir_file_is_followed() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    let module_git = temp_dir.child(".git/modules/vendor/lib");
+    module_git.create_dir_all().unwrap();
+    git(module_git.path(), &["init", "--bare"]);
+    temp_dir
+        .child("vendor/lib/.git")
+        .write_str("gitdir: ../../.git/modules/vendor/lib\n")
+        .unwrap();
+
+    let generator = GitHooksGenerator::new(temp_dir.child("vendor/lib").path().to_path_buf());
+    generator.generate_basic_hooks(false).await.unwrap();
+
+    module_git
+        .child("hooks/pre-push")
+        .assert(predicates::path::exists());
+    temp_dir
+        .child(".git/hooks/pre-push")
+        .assert(predicates::path::missing());
+}
+
+#[test]
+fn test_git_dir_environment_is_honored() {
+    let repo_dir = TempDir::new().unwrap();
+    git_init(&repo_dir);
+    let target = TempDir::new().unwrap();
+
+    zackstrap_cmd()
+        .env("GIT_DIR", repo_dir.child(".git").path())
+        .arg("--target")
+        .arg(target.path())
+        .arg("--hooks")
+        .arg("basic")
+        .assert()
+        .success();
+
+    repo_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::exists());
+}
+
+#[tokio::test]
+async fn test_existing_hook_is_chained_not_clobbered() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    let legacy = "#!/bin/sh\necho legacy\n";
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .write_str(legacy)
+        .unwrap();
+
+    let generator = GitHooksGenerator::new(temp_dir.path().to_path_buf());
+    generator.generate_basic_hooks(false).await.unwrap();
+
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::str::contains(DISPATCHER_MARKER));
+    temp_dir
+        .child(".git/hooks/pre-commit.d/00-legacy")
+        .assert(legacy);
+    temp_dir
+        .child(".git/hooks/pre-commit.d/50-zackstrap")
+        .assert(predicates::str::contains("Basic Pre-commit Hook"));
+
+    // Re-installing only touches zackstrap's own script, and only with force
+    assert!(generator.generate_basic_hooks(false).await.is_err());
+    generator.generate_basic_hooks(true).await.unwrap();
+    temp_dir
+        .child(".git/hooks/pre-commit.d/00-legacy")
+        .assert(legacy);
+    temp_dir
+        .child(".git/hooks/pre-commit.d/01-legacy")
+        .assert(predicates::path::missing());
+}
+
+#[tokio::test]
+async fn test_dispatcher_runs_every_script_in_order() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    temp_dir
+        .child(".git/hooks/commit-msg")
+        .write_str("#!/bin/sh\necho legacy >> \"$1.log\"\n")
+        .unwrap();
+    std::process::Command::new("chmod")
+        .arg("+x")
+        .arg(temp_dir.child(".git/hooks/commit-msg").path())
+        .output()
+        .unwrap();
+
+    let generator = GitHooksGenerator::new(temp_dir.path().to_path_buf());
+    generator.generate_basic_hooks(false).await.unwrap();
+
+    temp_dir
+        .child("MSG")
+        .write_str("feat: add a thing\n")
+        .unwrap();
+    let ok = std::process::Command::new(temp_dir.child(".git/hooks/commit-msg").path())
+        .arg(temp_dir.child("MSG").path())
+        .output()
+        .unwrap();
+    assert!(ok.status.success());
+    temp_dir.child("MSG.log").assert("legacy\n");
+
+    temp_dir.child("MSG").write_str("bad message\n").unwrap();
+    let bad = std::process::Command::new(temp_dir.child(".git/hooks/commit-msg").path())
+        .arg(temp_dir.child("MSG").path())
+        .output()
+        .unwrap();
+    assert!(!bad.status.success());
+}
+
+#[tokio::test]
+async fn test_uninstall_does_not_create_hooks_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+
+    let generator =
+        GitHooksGenerator::new(temp_dir.path().to_path_buf()).with_hooks_path(".githooks");
+    let outcomes = generator.uninstall_hooks().await.unwrap();
+
+    assert!(outcomes
+        .iter()
+        .all(|(_, outcome)| *outcome == UninstallOutcome::NotInstalled));
+    temp_dir
+        .child(".githooks")
+        .assert(predicates::path::missing());
+}
+
+#[tokio::test]
+async fn test_uninstall_restores_previous_hooks() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    let legacy = "#!/bin/sh\necho legacy\n";
+    temp_dir
+        .child(".git/hooks/pre-push")
+        .write_str(legacy)
+        .unwrap();
+
+    let generator = GitHooksGenerator::new(temp_dir.path().to_path_buf());
+    generator.generate_basic_hooks(false).await.unwrap();
+    temp_dir
+        .child(".git/hooks/commit-msg.d/10-team")
+        .write_str("#!/bin/sh\n")
+        .unwrap();
+
+    let outcomes = generator.uninstall_hooks().await.unwrap();
+    assert_eq!(
+        outcomes,
+        vec![
+            ("pre-commit", UninstallOutcome::Removed),
+            ("pre-push", UninstallOutcome::Restored),
+            ("commit-msg", UninstallOutcome::Kept),
+            ("prepare-commit-msg", UninstallOutcome::NotInstalled),
+            ("post-merge", UninstallOutcome::NotInstalled),
+            ("post-checkout", UninstallOutcome::NotInstalled),
+        ]
+    );
+
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::missing());
+    temp_dir
+        .child(".git/hooks/pre-commit.d")
+        .assert(predicates::path::missing());
+    temp_dir.child(".git/hooks/pre-push").assert(legacy);
+    temp_dir
+        .child(".git/hooks/pre-push.d")
+        .assert(predicates::path::missing());
+    temp_dir
+        .child(".git/hooks/commit-msg.d/50-zackstrap")
+        .assert(predicates::path::missing());
+
+    // A second uninstall leaves foreign hooks alone
+    let again = generator.uninstall_hooks().await.unwrap();
+    assert_eq!(again[1], ("pre-push", UninstallOutcome::NotInstalled));
+}
+
+#[tokio::test]
+async fn test_raw_pre_commit_stashes_unstaged_changes() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    temp_dir.child("notes.txt").write_str("one\n").unwrap();
+    git(temp_dir.path(), &["add", "notes.txt"]);
+    temp_dir.child("notes.txt").write_str("one\ntwo\n").unwrap();
+
+    // A patch kept by an earlier failed restore
+    let leftover = temp_dir.child(".git/zackstrap-unstaged.keep01");
+    leftover.write_str("earlier changes\n").unwrap();
+
+    let generator = GitHooksGenerator::new(temp_dir.path().to_path_buf());
+    generator.generate_basic_hooks(false).await.unwrap();
+    temp_dir
+        .child(".git/hooks/pre-commit.d/50-zackstrap")
+        .assert(predicates::str::contains(
+            "git diff --cached --name-only --diff-filter=ACM",
+        ));
+
+    git(temp_dir.path(), &["commit", "-m", "feat: add notes file"]);
+    leftover.assert("earlier changes\n");
+    let patches = std::fs::read_dir(temp_dir.child(".git").path())
+        .unwrap()
+        .filter(|entry| {
+            entry
+                .as_ref()
+                .unwrap()
+                .file_name()
+                .to_string_lossy()
+                .starts_with("zackstrap-unstaged")
+        })
+        .count();
+    assert_eq!(patches, 1);
+
+    // The commit holds only the staged content; the unstaged edit survives
+    let output = std::process::Command::new("git")
+        .args(["show", "HEAD:notes.txt"])
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "one\n");
+    temp_dir.child("notes.txt").assert("one\ntwo\n");
+}
+
+#[tokio::test]
+async fn test_go_web_pre_push_builds_the_server_package() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    ConfigGenerator::new(temp_dir.path().to_path_buf())
+        .generate_go_with_template("web")
+        .await
+        .unwrap();
+    GitHooksGenerator::new(temp_dir.path().to_path_buf())
+        .generate_go_hooks("web", false)
+        .await
+        .unwrap();
+
+    let script =
+        std::fs::read_to_string(temp_dir.child(".git/hooks/pre-push.d/50-zackstrap").path())
+            .unwrap();
+    let checks = hook_checks(&ProjectType::Go, "web", &Toolchain::default());
+    let builds: Vec<&str> = script
+        .lines()
+        .chain(checks.iter().map(|c| c.command.as_ref()))
+        .flat_map(|line| line.split(" && ").flat_map(|part| part.split("; ")))
+        .filter(|part| part.contains("go build"))
+        .collect();
+    assert!(!builds.is_empty());
+    for build in builds {
+        // The last argument is the package, which must hold the skeleton's main
+        let package = build.split_whitespace().last().unwrap();
+        assert!(
+            temp_dir.child(package).child("main.go").path().is_file(),
+            "{}",
+            build
+        );
+    }
+}
+
+/// Put executable stand-ins for `tools` first on `PATH`, returning its value.
+fn stub_tools(temp_dir: &TempDir, tools: &[(&str, &str)]) -> String {
+    use std::os::unix::fs::PermissionsExt;
+
+    let bin = temp_dir.path().join(".stubs");
+    std::fs::create_dir_all(&bin).unwrap();
+    for (name, body) in tools {
+        let path = bin.join(name);
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    format!("{}:{}", bin.display(), std::env::var("PATH").unwrap())
+}
+
+#[tokio::test]
+async fn test_go_pre_commit_handles_staged_package_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    temp_dir.child(".gitignore").write_str(".stubs/\n").unwrap();
+    let log = temp_dir.path().join("go.log");
+    let path = stub_tools(
+        &temp_dir,
+        &[
+            (
+                "go",
+                &format!("printf '%s\\n' \"$@\" >> '{}'", log.display()),
+            ),
+            ("gofmt", "exit 0"),
+        ],
+    );
+    GitHooksGenerator::new(temp_dir.path().to_path_buf())
+        .generate_go_hooks("default", false)
+        .await
+        .unwrap();
+    let run_hook = || {
+        std::process::Command::new(temp_dir.child(".git/hooks/pre-commit").path())
+            .env("PATH", &path)
+            .current_dir(temp_dir.path())
+            .output()
+            .unwrap()
+    };
+
+    // No staged Go files: nothing to vet, and no empty dirname call
+    temp_dir.child("README.md").write_str("# demo\n").unwrap();
+    git(temp_dir.path(), &["add", "README.md"]);
+    let output = run_hook();
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("dirname"));
+    assert!(!log.exists());
+
+    // A package directory with a space is passed as one argument
+    temp_dir
+        .child("cmd/my tool/main.go")
+        .write_str("package main\n")
+        .unwrap();
+    git(temp_dir.path(), &["add", "cmd"]);
+    assert!(run_hook().status.success());
+    assert_eq!(
+        std::fs::read_to_string(&log).unwrap(),
+        "vet\n./cmd/my tool\n"
+    );
+}
+
+#[tokio::test]
+async fn test_hooks_run_every_check_and_summarize() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    temp_dir.child(".gitignore").write_str(".stubs/\n").unwrap();
+    let log = temp_dir.path().join("go.log");
+    let path = stub_tools(
+        &temp_dir,
+        &[
+            (
+                "go",
+                &format!(
+                    "if [ \"$1\" = list ]; then pwd; exit; fi; printf '%s\\n' \"$1\" >> '{}'; exit 1",
+                    log.display()
+                ),
+            ),
+            ("gofmt", "echo main.go"),
+            ("golangci-lint", "exit 0"),
+        ],
+    );
+    GitHooksGenerator::new(temp_dir.path().to_path_buf())
+        .generate_go_hooks("default", false)
+        .await
+        .unwrap();
+    temp_dir
+        .child("main.go")
+        .write_str("package main\n")
+        .unwrap();
+    git(temp_dir.path(), &["add", "main.go"]);
+
+    // gofmt fails first, but go vet still runs and its failure is reported
+    let output = std::process::Command::new(temp_dir.child(".git/hooks/pre-commit").path())
+        .env("PATH", &path)
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success());
+    assert_eq!(std::fs::read_to_string(&log).unwrap(), "vet\n");
+    assert!(stdout.contains("  gofmt          FAIL"), "{}", stdout);
+    assert!(stdout.contains("  go vet         FAIL"), "{}", stdout);
+    assert!(stdout.contains("  golangci-lint  PASS"), "{}", stdout);
+    assert!(stdout.contains("❌ 2 check(s) failed"));
+    assert!(!stdout.contains("checks passed"));
+
+    // Husky runs its scripts with `sh -e`, which must not stop at the first failure
+    std::fs::remove_file(&log).unwrap();
+    let plan = default_plan(&ProjectType::Go, "default");
+    temp_dir
+        .child("pre-push")
+        .write_str(&render_husky_hook(HookKind::PrePush, &plan))
+        .unwrap();
+    let output = std::process::Command::new("sh")
+        .args(["-e", "pre-push"])
+        .env("PATH", &path)
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success());
+    assert_eq!(std::fs::read_to_string(&log).unwrap(), "mod\ntest\n");
+    assert!(
+        stdout.contains("go mod tidy                   FAIL"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Go tests with coverage        FAIL"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("golangci-lint (all packages)  PASS"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("❌ 2 check(s) failed"));
+}
+
+#[tokio::test]
+async fn test_go_pre_push_runs_in_every_workspace_module() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    temp_dir.child(".gitignore").write_str(".stubs/\n").unwrap();
+    temp_dir
+        .child("go.work")
+        .write_str("go 1.22\n\nuse (\n\t./api\n\t./worker\n)\n")
+        .unwrap();
+    temp_dir
+        .child("api/go.mod")
+        .write_str("module api\n")
+        .unwrap();
+    temp_dir
+        .child("worker/go.mod")
+        .write_str("module worker\n")
+        .unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    let log = root.join("go.log");
+    let path = stub_tools(
+        &temp_dir,
+        &[(
+            "go",
+            &format!(
+                "if [ \"$1\" = list ]; then printf '%s\\n' '{root}/api' '{root}/worker'; exit; fi; echo \"$(basename \"$PWD\") $1\" >> '{log}'",
+                root = root.display(),
+                log = log.display()
+            ),
+        )],
+    );
+    GitHooksGenerator::new(temp_dir.path().to_path_buf())
+        .generate_go_hooks("web", false)
+        .await
+        .unwrap();
+
+    // No root go.mod: checks run per module and the root-only server build is skipped
+    let output = std::process::Command::new(temp_dir.child(".git/hooks/pre-push").path())
+        .env("PATH", &path)
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    assert_eq!(
+        std::fs::read_to_string(&log).unwrap(),
+        "api mod\nworker mod\napi test\napi tool\nworker test\nworker tool\n"
+    );
+}
+
+#[tokio::test]
+async fn test_prepare_commit_msg_adds_ticket_from_branch() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    git(
+        temp_dir.path(),
+        &["checkout", "-b", "feature/ABC-123-search"],
+    );
+
+    let generator = GitHooksGenerator::new(temp_dir.path().to_path_buf())
+        .with_hook_kinds(&[HookKind::PrepareCommitMsg]);
+    generator.generate_basic_hooks(false).await.unwrap();
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::missing());
+
+    temp_dir.child("notes.txt").write_str("one\n").unwrap();
+    git(temp_dir.path(), &["add", "notes.txt"]);
+    git(temp_dir.path(), &["commit", "-m", "feat: add search"]);
+
+    let output = std::process::Command::new("git")
+        .args(["log", "-1", "--format=%B"])
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+    let message = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(message.trim_end(), "feat: add search\n\nRefs: ABC-123");
+}
+
+#[test]
+fn test_lifecycle_hooks_in_managers() {
+    let plan = HookPlan::new(
+        &ProjectType::Node,
+        "default",
+        &Toolchain::default(),
+        &HookKind::ALL,
+        CommitMsgPolicy::default(),
+    );
+
+    let pre_commit: serde_yaml::Value =
+        serde_yaml::from_str(&render_pre_commit_config(&plan)).unwrap();
+    let install_types = pre_commit["default_install_hook_types"]
+        .as_sequence()
+        .unwrap();
+    assert_eq!(install_types.len(), HookKind::ALL.len());
+    let hooks = pre_commit["repos"][0]["hooks"].as_sequence().unwrap();
+    let post_checkout = hooks
+        .iter()
+        .find(|hook| hook["stages"][0] == "post-checkout")
+        .unwrap();
+    assert_eq!(post_checkout["id"], "refresh-dependencies");
+    assert_eq!(post_checkout["always_run"], true);
+    assert!(post_checkout["args"][0]
+        .as_str()
+        .unwrap()
+        .contains("\"$PRE_COMMIT_FROM_REF\""));
+
+    let lefthook: serde_yaml::Value = serde_yaml::from_str(&render_lefthook_config(&plan)).unwrap();
+    let run = lefthook["post-merge"]["jobs"][0]["run"].as_str().unwrap();
+    assert!(run.contains("package-lock\\.json"));
+    assert!(run.contains("npm ci"));
+    let run = lefthook["prepare-commit-msg"]["jobs"][0]["run"]
+        .as_str()
+        .unwrap();
+    assert!(run.contains("\"{2}\""));
+
+    let husky = render_husky_hook(HookKind::PostCheckout, &plan);
+    assert!(husky.contains("git diff --name-only \"$1\" \"$2\""));
+}
+
+#[tokio::test]
+async fn test_post_checkout_reinstalls_changed_lockfiles() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    temp_dir.child("README.md").write_str("# demo\n").unwrap();
+    git(temp_dir.path(), &["add", "README.md"]);
+    git(temp_dir.path(), &["commit", "-m", "chore: initial commit"]);
+    git(temp_dir.path(), &["branch", "base"]);
+    temp_dir
+        .child("api/Cargo.lock")
+        .write_str("# lock\n")
+        .unwrap();
+    git(temp_dir.path(), &["add", "api/Cargo.lock"]);
+    git(temp_dir.path(), &["commit", "-m", "chore: add lockfile"]);
+
+    let script = lifecycle::post_checkout_script(&ProjectType::Rust, "$1", "$2", "$3")
+        .replace("cargo fetch", "touch fetched");
+    let hook = temp_dir.child(".git/hooks/post-checkout");
+    hook.write_str(&format!("#!/bin/sh\n{}", script)).unwrap();
+    std::process::Command::new("chmod")
+        .arg("+x")
+        .arg(hook.path())
+        .output()
+        .unwrap();
+
+    // Checking out a file does not trigger a refresh
+    git(temp_dir.path(), &["checkout", "base", "--", "README.md"]);
+    temp_dir
+        .child("api/fetched")
+        .assert(predicates::path::missing());
+
+    // Leaving the branch deletes the lockfile, coming back restores it
+    git(temp_dir.path(), &["checkout", "base"]);
+    temp_dir
+        .child("api/fetched")
+        .assert(predicates::path::missing());
+    git(temp_dir.path(), &["checkout", "-"]);
+    temp_dir
+        .child("api/fetched")
+        .assert(predicates::path::exists());
+}
+
+#[test]
+fn test_hooks_flag_selects_hook_types() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--hooks=post-merge,prepare-commit-msg")
+        .arg("python")
+        .assert()
+        .success();
+
+    temp_dir
+        .child(".git/hooks/post-merge.d/50-zackstrap")
+        .assert(predicates::str::contains("requirements\\.txt"));
+    temp_dir
+        .child(".git/hooks/prepare-commit-msg")
+        .assert(predicates::path::exists());
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::missing());
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--hooks=post-rewrite")
+        .arg("python")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_hooks_subcommand_install_status_and_uninstall() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    temp_dir
+        .child("Cargo.toml")
+        .write_str("[package]\n")
+        .unwrap();
+    temp_dir
+        .child(".git/hooks/post-merge")
+        .write_str("#!/bin/sh\necho team hook\n")
+        .unwrap();
+
+    // The project type is detected and no config files are generated
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["hooks", "install"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Rust project"));
+    temp_dir
+        .child(".git/hooks/pre-commit.d/50-zackstrap")
+        .assert(predicates::str::contains("cargo clippy"));
+    temp_dir
+        .child("rustfmt.toml")
+        .assert(predicates::path::missing());
+
+    temp_dir
+        .child(".git/hooks/pre-push.d/50-zackstrap")
+        .write_str("#!/bin/bash\nexit 0\n")
+        .unwrap();
+    temp_dir
+        .child(".git/hooks/commit-msg.d/10-team")
+        .write_str("#!/bin/sh\n")
+        .unwrap();
+
+    let output = zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["hooks", "status"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let status = |hook: &str| {
+        stdout
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>())
+            .find(|words| words.get(1) == Some(&hook))
+            .map(|words| words[0].to_string())
+            .unwrap_or_else(|| panic!("no status for {} in {}", hook, stdout))
+    };
+    assert_eq!(status("pre-commit"), "[MANAGED]");
+    assert_eq!(status("pre-push"), "[MODIFIED]");
+    assert_eq!(status("commit-msg"), "[MANAGED]");
+    assert_eq!(status("post-merge"), "[FOREIGN]");
+    assert_eq!(status("post-checkout"), "[MISSING]");
+    assert!(stdout.contains("also runs commit-msg.d/10-team"));
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["hooks", "uninstall"])
+        .assert()
+        .success();
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::missing());
+    temp_dir
+        .child(".git/hooks/post-merge")
+        .assert("#!/bin/sh\necho team hook\n");
+}
+
+#[test]
+fn test_hooks_run_uses_staged_files() {
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["hooks", "run", "pre-commit"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("HookNotInstalled"));
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["hooks", "install", "basic"])
+        .assert()
+        .success();
+
+    // A team check chained next to zackstrap's looks at the staged content
+    let team_check = temp_dir.child(".git/hooks/pre-commit.d/10-no-todo");
+    team_check
+        .write_str("#!/bin/sh\n! git diff --cached | grep -q TODO\n")
+        .unwrap();
+    std::process::Command::new("chmod")
+        .arg("+x")
+        .arg(team_check.path())
+        .output()
+        .unwrap();
+    temp_dir.child("notes.txt").write_str("TODO\n").unwrap();
+    git(temp_dir.path(), &["add", "notes.txt"]);
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["hooks", "run", "pre-commit"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("HookFailed"));
+
+    // Once the fix is staged the hook passes, and unstaged edits survive
+    temp_dir.child("notes.txt").write_str("done\n").unwrap();
+    git(temp_dir.path(), &["add", "notes.txt"]);
+    temp_dir
+        .child("notes.txt")
+        .write_str("done\nTODO\n")
+        .unwrap();
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["hooks", "run", "pre-commit"])
+        .assert()
+        .success();
+    temp_dir.child("notes.txt").assert("done\nTODO\n");
+
+    let message = temp_dir.child("MSG");
+    message.write_str("updated stuff\n").unwrap();
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["hooks", "run", "commit-msg"])
+        .arg(message.path())
+        .assert()
+        .failure()
+        .stdout(predicates::str::contains("conventional commits"));
+}
+
+#[test]
+fn test_dry_run_previews_raw_hooks() {
+    let temp_dir = TempDir::new().unwrap();
+
+    // A missing repository is reported before anything would be written
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--dry-run")
+        .arg("--hooks")
+        .arg("go")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("GitNotInitialized"));
+
+    git_init(&temp_dir);
+    let legacy = "#!/bin/sh\necho legacy\n";
+    temp_dir
+        .child(".git/hooks/pre-push")
+        .write_str(legacy)
+        .unwrap();
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--dry-run")
+        .arg("--hooks=pre-commit,pre-push")
+        .arg("go")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "[CREATE] .git/hooks/pre-commit.d/50-zackstrap",
+        ))
+        .stdout(predicates::str::contains("golangci-lint run"))
+        .stdout(predicates::str::contains(
+            "[CONFLICT] .git/hooks/pre-push (existing hook, kept and chained as .git/hooks/pre-push.d/00-legacy)",
+        ));
+    temp_dir.child(".git/hooks/pre-push").assert(legacy);
+    temp_dir
+        .child(".git/hooks/pre-commit")
+        .assert(predicates::path::missing());
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--hooks=pre-commit")
+        .arg("go")
+        .assert()
+        .success();
+
+    // Installed hooks conflict unless --force replaces them
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["--dry-run", "hooks", "install", "go"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "[CONFLICT] .git/hooks/pre-commit.d/50-zackstrap (already installed",
+        ));
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["--dry-run", "--force", "hooks", "install", "go"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "[OVERWRITE] .git/hooks/pre-commit.d/50-zackstrap",
+        ));
+}
+
+#[test]
+fn test_init_creates_repository_hooks_and_first_commit() {
+    let temp_dir = TempDir::new().unwrap();
+    let git_output = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(temp_dir.path())
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .arg("--dry-run")
+        .arg("init")
+        .arg("go")
+        .arg("--commit")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Would initialize a git repository (branch: main)",
+        ))
+        .stdout(predicates::str::contains(
+            "Would commit the generated files: chore: initial commit",
+        ));
+    temp_dir.child(".git").assert(predicates::path::missing());
+
+    let commit = || {
+        let mut cmd = zackstrap_cmd();
+        cmd.env("GIT_AUTHOR_NAME", "zackstrap")
+            .env("GIT_AUTHOR_EMAIL", "zackstrap@example.com")
+            .env("GIT_COMMITTER_NAME", "zackstrap")
+            .env("GIT_COMMITTER_EMAIL", "zackstrap@example.com")
+            .arg("--target")
+            .arg(temp_dir.path());
+        cmd
+    };
+    // Files that were there before are not zackstrap's to commit
+    temp_dir.child("notes.txt").write_str("todo\n").unwrap();
+    commit()
+        .args(["init", "go", "--branch", "trunk", "--commit"])
+        .assert()
+        .success();
+
+    temp_dir
+        .child(".git/hooks/pre-commit.d/50-zackstrap")
+        .assert(predicates::path::exists());
+    assert_eq!(git_output(&["branch", "--show-current"]), "trunk");
+    assert_eq!(git_output(&["log", "--format=%s"]), "chore: initial commit");
+    assert!(git_output(&["ls-files"]).contains(".golangci.yml"));
+    assert_eq!(git_output(&["status", "--porcelain"]), "?? notes.txt");
+
+    // A second run keeps the history and the branch
+    commit()
+        .args(["--force", "init", "go", "--commit=chore: again"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("skipping the initial commit"));
+    assert_eq!(git_output(&["rev-list", "--count", "HEAD"]), "1");
+
+    // An invalid branch name is rejected before the repository is created
+    let fresh_dir = TempDir::new().unwrap();
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(fresh_dir.path())
+        .args(["init", "--branch", "bad..name"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("GitCommandFailed"));
+    fresh_dir.child(".git").assert(predicates::path::missing());
+
+    // Hooks committed in .githooks/ are part of the first commit
+    zackstrap_cmd()
+        .env("GIT_AUTHOR_NAME", "zackstrap")
+        .env("GIT_AUTHOR_EMAIL", "zackstrap@example.com")
+        .env("GIT_COMMITTER_NAME", "zackstrap")
+        .env("GIT_COMMITTER_EMAIL", "zackstrap@example.com")
+        .arg("--target")
+        .arg(fresh_dir.path())
+        .args(["--hooks-manager", "githooks", "init", "go", "--commit"])
+        .assert()
+        .success();
+    let output = std::process::Command::new("git")
+        .args(["ls-files", ".githooks"])
+        .current_dir(fresh_dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        ".githooks/commit-msg\n.githooks/commit-msg.d/50-zackstrap\n.githooks/pre-commit\n.githooks/pre-commit.d/50-zackstrap\n.githooks/pre-push\n.githooks/pre-push.d/50-zackstrap\n"
+    );
+}
+
+#[test]
+fn test_python_toolchain_changes_hook_commands() {
+    let toolchain = Toolchain {
+        python: PythonToolchain {
+            package_manager: PythonPackageManager::Uv,
+            linter: PythonLinter::Ruff,
+        },
+        ..Default::default()
+    };
+    let checks = hook_checks(&ProjectType::Python, "default", &toolchain);
+    let commands = |kind: HookKind| -> Vec<&str> {
+        checks
+            .iter()
+            .filter(|c| c.hook == kind)
+            .map(|c| c.command.as_ref())
+            .collect()
+    };
+    assert_eq!(
+        commands(HookKind::PreCommit),
+        [
+            "uv run ruff format --check {files}",
+            "uv run ruff check {files}",
+            "uv run mypy {files}",
+        ]
+    );
+    assert_eq!(commands(HookKind::PrePush)[0], "uv lock --check");
+
+    let plan = HookPlan::new(
+        &ProjectType::Python,
+        "default",
+        &toolchain,
+        &HookKind::DEFAULT,
+        CommitMsgPolicy::default(),
+    );
+    let config: serde_yaml::Value = serde_yaml::from_str(&render_lefthook_config(&plan)).unwrap();
+    assert_eq!(
+        config["pre-commit"]["jobs"][1]["run"].as_str(),
+        Some("uv run ruff check {staged_files}")
+    );
+
+    // The raw scripts run the same commands, and the toolchain is found
+    // again from the generated pyproject.toml
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args([
+            "--hooks",
+            "python",
+            "--package-manager",
+            "pdm",
+            "--linter",
+            "ruff",
+        ])
+        .assert()
+        .success();
+    temp_dir
+        .child(".git/hooks/pre-commit.d/50-zackstrap")
+        .assert(predicates::str::contains("    pdm run ruff check \"$@\"\n"));
+    temp_dir
+        .child(".git/hooks/pre-push.d/50-zackstrap")
+        .assert(predicates::str::contains("pdm lock --check"));
+    assert_eq!(
+        Toolchain::detect(temp_dir.path()).python,
+        PythonToolchain {
+            package_manager: PythonPackageManager::Pdm,
+            linter: PythonLinter::Ruff,
+        }
+    );
+}
+
+#[test]
+fn test_typescript_projects_always_type_check() {
+    let command = |template: &str, toolchain: &Toolchain| -> String {
+        hook_checks(&ProjectType::Node, template, toolchain)
+            .into_iter()
+            .find(|c| c.id == "tsc")
+            .unwrap()
+            .command
+            .into_owned()
+    };
+    let javascript = Toolchain::default();
+    let typescript = Toolchain {
+        node: NodeToolchain {
+            typescript: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        command("default", &javascript),
+        "if [ -f tsconfig.json ]; then npx tsc --noEmit; fi"
+    );
+    assert_eq!(command("default", &typescript), "npx tsc --noEmit");
+    assert_eq!(command("library", &javascript), "npx tsc --noEmit");
+}
+
+#[test]
+fn test_node_package_manager_changes_hook_commands() {
+    let toolchain = Toolchain {
+        node: NodeToolchain {
+            package_manager: NodePackageManager::Pnpm,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let checks = hook_checks(&ProjectType::Node, "express", &toolchain);
+    let commands: Vec<&str> = checks.iter().map(|c| c.command.as_ref()).collect();
+    assert_eq!(
+        commands,
+        [
+            "pnpm exec eslint {files}",
+            "pnpm exec prettier --check {files}",
+            "if [ -f tsconfig.json ]; then pnpm exec tsc --noEmit; fi",
+            "pnpm test --coverage",
+            "pnpm audit",
+            "pnpm run build",
+        ]
+    );
+
+    // npm keeps `--` before script arguments
+    let checks = hook_checks(&ProjectType::Node, "react", &Toolchain::default());
+    assert!(checks
+        .iter()
+        .any(|c| c.command == "npm test -- --coverage --watchAll=false"));
+
+    // Every Node lockfile reinstalls with its own package manager
+    let refreshes = lifecycle::dependency_refreshes(&ProjectType::Node);
+    let commands: Vec<&str> = refreshes.iter().map(|r| r.command).collect();
+    assert_eq!(
+        commands,
+        [
+            "npm ci",
+            "pnpm install --frozen-lockfile",
+            "yarn install --immutable",
+            "bun install --frozen-lockfile",
+        ]
+    );
+
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["--hooks", "node", "--package-manager", "bun"])
+        .assert()
+        .success();
+    temp_dir
+        .child(".git/hooks/pre-commit.d/50-zackstrap")
+        .assert(predicates::str::contains("bunx eslint \"$@\""));
+    temp_dir
+        .child(".git/hooks/pre-push.d/50-zackstrap")
+        .assert(predicates::str::contains("bun run test --coverage"));
+    assert_eq!(
+        Toolchain::detect(temp_dir.path()).node.package_manager,
+        NodePackageManager::Bun
+    );
+}
+
+#[test]
+fn test_rust_crate_templates_adjust_hook_checks() {
+    let commands = |template: &str, kind: HookKind| -> Vec<String> {
+        hook_checks(&ProjectType::Rust, template, &Toolchain::default())
+            .into_iter()
+            .filter(|c| c.hook == kind)
+            .map(|c| c.command.to_string())
+            .collect()
+    };
+
+    let workspace = commands("workspace", HookKind::PrePush);
+    assert!(workspace.contains(&"cargo test --workspace --all-features".to_string()));
+    assert!(workspace.iter().any(|c| c.contains("cargo deny check")));
+
+    // Firmware has no host tests, and clippy skips test targets
+    let embedded = commands("embedded", HookKind::PrePush);
+    assert!(!embedded.iter().any(|c| c.contains("cargo test")));
+    assert!(embedded.contains(&"cargo clippy --all-features -- -D warnings".to_string()));
+
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["--hooks", "rust", "--template", "embedded"])
+        .assert()
+        .success();
+    let pre_push =
+        std::fs::read_to_string(temp_dir.path().join(".git/hooks/pre-push.d/50-zackstrap"))
+            .unwrap();
+    assert!(pre_push.contains("cargo deny check"));
+    assert!(!pre_push.contains("cargo test"));
+}
+
+#[test]
+fn test_bash_hooks_cover_bin_scripts() {
+    for template in ["default", "devops", "cli"] {
+        let checks = hook_checks(&ProjectType::Bash, template, &Toolchain::default());
+        let command = |id: &str| {
+            checks
+                .iter()
+                .find(|c| c.id == id)
+                .map(|c| c.command.to_string())
+                .unwrap_or_else(|| panic!("{} has no {} check", template, id))
+        };
+        // shfmt takes its settings from .editorconfig
+        assert!(!command("shfmt").contains("-i 2"));
+        assert!(command("shfmt-full").contains("shfmt -d ."));
+        // Entry points under bin/ have no extension, so pre-push finds them
+        assert!(command("shellcheck-full").contains("-path './bin/*'"));
+        assert!(command("bash-syntax-full").contains("-path './bin/*'"));
+        assert!(command("bats-full").contains("bats test/"));
+        assert!(command("shellcheck-full").contains("-name '*.bats'"));
+
+        // Staged entry points under bin/ are checked; BATS tests are linted
+        let check = |id: &str| checks.iter().find(|c| c.id == id).unwrap();
+        assert_eq!(
+            check("shellcheck").files_regex().unwrap(),
+            "\\.(sh|bash|bats)$|^bin/"
+        );
+        assert_eq!(check("shfmt").files_glob().unwrap(), "{*.sh,*.bash,bin/*}");
+        let runner_check = RunnerCheck::from(check("bash-syntax"));
+        assert!(runner_check.matches("bin/tool"));
+        assert!(!runner_check.matches("test/tool.bats"));
+        assert!(!runner_check.matches("src/bin/tool"));
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    git_init(&temp_dir);
+    zackstrap_cmd()
+        .arg("--target")
+        .arg(temp_dir.path())
+        .args(["--hooks", "bash", "--template", "cli"])
+        .assert()
+        .success();
+    let pre_commit =
+        std::fs::read_to_string(temp_dir.path().join(".git/hooks/pre-commit.d/50-zackstrap"))
+            .unwrap();
+    assert!(pre_commit.contains("shfmt -d \"$@\""));
+    assert!(!pre_commit.contains("main.sh"));
+
+    // The scaffolded script and its tests go through the staged checks
+    let name = ConfigGenerator::new(temp_dir.path().to_path_buf()).bash_script_name();
+    let log = temp_dir.path().join("tools.log");
+    let logger = format!("echo \"$(basename \"$0\") $*\" >> '{}'", log.display());
+    let path = stub_tools(&temp_dir, &[("shellcheck", &logger), ("shfmt", &logger)]);
+    temp_dir.child(".gitignore").write_str(".stubs/\n").unwrap();
+    git(temp_dir.path(), &["add", "bin", "test"]);
+    let output = std::process::Command::new(temp_dir.child(".git/hooks/pre-commit").path())
+        .env("PATH", &path)
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let log = std::fs::read_to_string(&log).unwrap();
+    assert!(
+        log.contains(&format!("shellcheck bin/{} test/{}.bats\n", name, name)),
+        "{}",
+        log
+    );
+    assert!(log.contains(&format!("shfmt -d bin/{}\n", name)), "{}", log);
+    let pre_push =
+        std::fs::read_to_string(temp_dir.path().join(".git/hooks/pre-push.d/50-zackstrap"))
+            .unwrap();
+    assert!(pre_push.contains("shfmt -d ."));
+    assert!(pre_push.contains("bats test/"));
+}
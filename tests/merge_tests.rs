@@ -0,0 +1,306 @@
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use zackstrap::generators::merge::{
+    merge_json, merge_managed_block, merge_toml, merge_yaml, BLOCK_END, BLOCK_START,
+};
+use zackstrap::ConfigGenerator;
+
+const EXISTING_PYPROJECT: &str = r#"# Project metadata maintained by hand
+[project]
+name = "existing-app"
+version = "2.3.4"
+dependencies = ["requests>=2.0"]
+
+[tool.black]
+# We prefer longer lines
+line-length = 100
+"#;
+
+#[test]
+fn test_merge_toml_preserves_existing_content() {
+    let generated = r#"[project]
+name = "python-project"
+
+[tool.black]
+line-length = 88
+target-version = ['py312']
+
+[tool.mypy]
+strict = true
+"#;
+
+    let outcome = merge_toml(EXISTING_PYPROJECT, generated, &["tool.black", "tool.mypy"]).unwrap();
+
+    // User-owned tables and comments are untouched
+    assert!(outcome
+        .content
+        .starts_with("# Project metadata maintained by hand"));
+    assert!(outcome.content.contains("name = \"existing-app\""));
+    assert!(!outcome.content.contains("python-project"));
+    assert!(outcome.content.contains("# We prefer longer lines"));
+
+    // Existing values win, missing keys and tables are added
+    assert!(outcome.content.contains("line-length = 100"));
+    assert!(outcome.content.contains("target-version = ['py312']"));
+    assert!(outcome.content.contains("[tool.mypy]"));
+    assert!(outcome.content.find("[tool.black]") < outcome.content.find("[tool.mypy]"));
+
+    assert_eq!(outcome.conflicts.len(), 1);
+    assert!(outcome.conflicts[0].contains("tool.black.line-length"));
+
+    let parsed: toml::Value = toml::from_str(&outcome.content).unwrap();
+    assert_eq!(parsed["tool"]["mypy"]["strict"].as_bool(), Some(true));
+}
+
+#[test]
+fn test_merge_toml_is_idempotent() {
+    let generated = "[profile.release]\nlto = true\n";
+    let first = merge_toml("[build]\njobs = 4\n", generated, &["profile"]).unwrap();
+    let second = merge_toml(&first.content, generated, &["profile"]).unwrap();
+
+    assert_eq!(first.content, second.content);
+    assert!(second.conflicts.is_empty());
+}
+
+#[test]
+fn test_merge_toml_rejects_invalid_existing_file() {
+    assert!(merge_toml("[project\nname = ", "[tool.black]\n", &["tool.black"]).is_err());
+}
+
+#[tokio::test]
+async fn test_python_generation_merges_existing_pyproject() {
+    let temp_dir = TempDir::new().unwrap();
+    temp_dir
+        .child("pyproject.toml")
+        .write_str(EXISTING_PYPROJECT)
+        .unwrap();
+
+    let generator = ConfigGenerator::new(temp_dir.path().to_path_buf());
+    generator
+        .generate_python_with_template("default")
+        .await
+        .unwrap();
+
+    let pyproject = std::fs::read_to_string(temp_dir.child("pyproject.toml").path()).unwrap();
+    assert!(pyproject.contains("name = \"existing-app\""));
+    assert!(pyproject.contains("line-length = 100"));
+    assert!(pyproject.contains("[tool.mypy]"));
+    assert!(pyproject.contains("[tool.pytest.ini_options]"));
+    assert!(!pyproject.contains("[build-system]"));
+}
+
+#[tokio::test]
+async fn test_rust_generation_merges_existing_cargo_config() {
+    let temp_dir = TempDir::new().unwrap();
+    temp_dir
+        .child(".cargo/config.toml")
+        .write_str("# Local alias\n[alias]\nxtask = \"run --package xtask --\"\n")
+        .unwrap();
+
+    let generator = ConfigGenerator::new(temp_dir.path().to_path_buf());
+    generator
+        .generate_rust_with_template("default")
+        .await
+        .unwrap();
+
+    let config = std::fs::read_to_string(temp_dir.child(".cargo/config.toml").path()).unwrap();
+    assert!(config.starts_with("# Local alias\n[alias]"));
+    assert!(config.contains("xtask = \"run --package xtask --\""));
+    assert!(config.contains("lint = \"clippy"));
+}
+
+#[tokio::test]
+async fn test_merge_dry_run_leaves_file_untouched() {
+    let temp_dir = TempDir::new().unwrap();
+    temp_dir
+        .child("pyproject.toml")
+        .write_str(EXISTING_PYPROJECT)
+        .unwrap();
+
+    let generator = ConfigGenerator::with_options(temp_dir.path().to_path_buf(), true, false);
+    generator
+        .generate_python_with_template("default")
+        .await
+        .unwrap();
+
+    let pyproject = std::fs::read_to_string(temp_dir.child("pyproject.toml").path()).unwrap();
+    assert_eq!(pyproject, EXISTING_PYPROJECT);
+}
+
+const EXISTING_RUBOCOP: &str = r#"# Team RuboCop settings
+require:
+  - rubocop-rspec
+
+AllCops:
+  TargetRubyVersion: 3.2
+  Exclude:
+    - 'db/**/*'
+
+# Long lines are fine in specs
+Layout/LineLength:
+  Max: 140
+"#;
+
+#[test]
+fn test_merge_yaml_keeps_untouched_sections_verbatim() {
+    let generated = r#"AllCops:
+  NewCops: enable
+  TargetRubyVersion: 3.3
+  Exclude:
+    - 'vendor/**/*'
+
+Style/Documentation:
+  Enabled: false
+"#;
+
+    let outcome = merge_yaml(EXISTING_RUBOCOP, generated).unwrap();
+
+    // Sections zackstrap does not change keep their text and comments
+    assert!(outcome
+        .content
+        .starts_with("# Team RuboCop settings\nrequire:\n  - rubocop-rspec\n"));
+    assert!(outcome
+        .content
+        .contains("# Long lines are fine in specs\nLayout/LineLength:\n  Max: 140\n"));
+
+    // Exclude lists are unioned and existing scalars win
+    let parsed: serde_yaml::Value = serde_yaml::from_str(&outcome.content).unwrap();
+    let all_cops = &parsed["AllCops"];
+    assert_eq!(all_cops["TargetRubyVersion"].as_f64(), Some(3.2));
+    assert_eq!(all_cops["NewCops"].as_str(), Some("enable"));
+    let excludes: Vec<&str> = all_cops["Exclude"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    assert_eq!(excludes, vec!["db/**/*", "vendor/**/*"]);
+    assert_eq!(
+        parsed["Style/Documentation"]["Enabled"].as_bool(),
+        Some(false)
+    );
+
+    assert_eq!(outcome.conflicts.len(), 1);
+    assert!(outcome.conflicts[0].contains("AllCops.TargetRubyVersion"));
+}
+
+#[test]
+fn test_merge_yaml_is_idempotent() {
+    let generated = "linters:\n  enable:\n    - govet\n    - errcheck\n";
+    let first = merge_yaml("linters:\n  enable:\n    - revive\n", generated).unwrap();
+    let second = merge_yaml(&first.content, generated).unwrap();
+
+    assert_eq!(first.content, second.content);
+    assert!(second.conflicts.is_empty());
+}
+
+#[test]
+fn test_merge_yaml_does_not_enable_disabled_linters() {
+    let existing = "linters:\n  enable:\n    - revive\n  disable:\n    - errcheck\n";
+    let generated = "linters:\n  enable:\n    - govet\n    - errcheck\n";
+
+    let outcome = merge_yaml(existing, generated).unwrap();
+
+    let parsed: serde_yaml::Value = serde_yaml::from_str(&outcome.content).unwrap();
+    let enabled: Vec<&str> = parsed["linters"]["enable"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    assert_eq!(enabled, ["revive", "govet"]);
+    assert_eq!(parsed["linters"]["disable"][0].as_str(), Some("errcheck"));
+    assert_eq!(outcome.conflicts.len(), 1);
+    assert!(outcome.conflicts[0].contains("linters.enable"));
+    assert!(outcome.conflicts[0].contains("errcheck"));
+}
+
+#[test]
+fn test_merge_yaml_rejects_non_mapping_document() {
+    assert!(merge_yaml("- just\n- a list\n", "AllCops: {}\n").is_err());
+}
+
+#[tokio::test]
+async fn test_ruby_generation_merges_existing_rubocop_config() {
+    let temp_dir = TempDir::new().unwrap();
+    temp_dir
+        .child(".rubocop.yml")
+        .write_str(EXISTING_RUBOCOP)
+        .unwrap();
+
+    let generator = ConfigGenerator::new(temp_dir.path().to_path_buf());
+    generator
+        .generate_ruby_with_template("rails")
+        .await
+        .unwrap();
+
+    let rubocop = std::fs::read_to_string(temp_dir.child(".rubocop.yml").path()).unwrap();
+    assert!(rubocop.contains("rubocop-rspec"));
+    assert!(rubocop.contains("Max: 140"));
+    assert!(rubocop.contains("db/schema.rb"));
+    assert!(rubocop.contains("Style/StringLiterals"));
+}
+
+#[test]
+fn test_managed_block_appends_and_replaces_only_its_block() {
+    let existing = "secrets.txt\n/local/\n";
+
+    let first = merge_managed_block(existing, "node_modules/\n");
+    assert!(first.content.starts_with("secrets.txt\n/local/\n\n"));
+    assert!(first.content.contains(BLOCK_START));
+    assert!(first.content.contains("node_modules/\n"));
+    assert!(first.content.ends_with(&format!("{}\n", BLOCK_END)));
+
+    let user_edit = format!("{}*.log\n", first.content);
+    let second = merge_managed_block(&user_edit, "node_modules/\ndist/\n");
+    assert!(second.content.starts_with("secrets.txt\n/local/\n\n"));
+    assert!(second.content.contains("dist/\n"));
+    assert!(second.content.ends_with("*.log\n"));
+    assert_eq!(second.content.matches(BLOCK_START).count(), 1);
+    assert!(second.conflicts.is_empty());
+
+    let third = merge_managed_block(&second.content, "node_modules/\ndist/\n");
+    assert_eq!(third.content, second.content);
+}
+
+#[tokio::test]
+async fn test_gitignore_block_respects_user_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    temp_dir
+        .child(".gitignore")
+        .write_str("secrets.txt\n")
+        .unwrap();
+
+    // --force must not clobber lines outside the managed block
+    let generator = ConfigGenerator::with_options(temp_dir.path().to_path_buf(), false, true);
+    generator
+        .generate_rust_with_template("default")
+        .await
+        .unwrap();
+    generator
+        .generate_rust_with_template("default")
+        .await
+        .unwrap();
+
+    let gitignore = std::fs::read_to_string(temp_dir.child(".gitignore").path()).unwrap();
+    assert!(gitignore.starts_with("secrets.txt\n"));
+    assert!(gitignore.contains("/target/"));
+    assert_eq!(gitignore.matches(BLOCK_START).count(), 1);
+}
+
+#[test]
+fn test_merge_json_adds_missing_keys_and_keeps_existing() {
+    let existing = "{\n  \"name\": \"app\",\n  \"scripts\": {\n    \"prepare\": \"node setup.js\",\n    \"test\": \"jest\"\n  }\n}\n";
+    let generated = r#"{"scripts": {"prepare": "husky"}, "devDependencies": {"husky": "^9.1.7"}}"#;
+
+    let outcome = merge_json(existing, generated).unwrap();
+    let merged: serde_json::Value = serde_json::from_str(&outcome.content).unwrap();
+    assert_eq!(merged["scripts"]["prepare"].as_str(), Some("node setup.js"));
+    assert_eq!(merged["devDependencies"]["husky"].as_str(), Some("^9.1.7"));
+    assert!(outcome.content.find("\"name\"") < outcome.content.find("\"scripts\""));
+    assert_eq!(outcome.conflicts.len(), 1);
+    assert!(outcome.conflicts[0].contains("scripts.prepare"));
+
+    let second = merge_json(&outcome.content, generated).unwrap();
+    assert_eq!(second.content, outcome.content);
+}
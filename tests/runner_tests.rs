@@ -0,0 +1,322 @@
+use assert_cmd::Command;
+use assert_fs::prelude::*;
+use assert_fs::TempDir;
+use zackstrap::generators::git::GitRepo;
+use zackstrap::generators::hooks::runner::{CheckStatus, HookRunner};
+use zackstrap::generators::hooks::HookKind;
+use zackstrap::{Manifest, ProjectType, ZackstrapError};
+
+const RUNNER_MANIFEST: &str = r#"[hooks]
+language = "basic"
+skip = ["prettier"]
+
+[[hooks.checks]]
+id = "no-todo"
+name = "No TODOs"
+command = "! grep -n TODO {files}"
+extensions = ["txt"]
+
+[[hooks.checks]]
+id = "typos"
+command = "definitely-not-installed-zackstrap-tool {files}"
+extensions = ["md"]
+
+[[hooks.checks]]
+id = "smoke"
+command = "true"
+hook = "pre-push"
+"#;
+
+fn git(dir: &std::path::Path, args: &[&str]) -> std::process::Output {
+    std::process::Command::new("git")
+        .args([
+            "-c",
+            "user.name=zackstrap",
+            "-c",
+            "user.email=zackstrap@example.com",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git")
+}
+
+fn repo_with_manifest(manifest: &str) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    git(temp_dir.path(), &["init"]);
+    temp_dir
+        .child(".zackstrap.toml")
+        .write_str(manifest)
+        .unwrap();
+    temp_dir
+}
+
+fn runner(temp_dir: &TempDir, kind: HookKind) -> HookRunner {
+    let manifest = Manifest::load(temp_dir.path()).unwrap();
+    let repo = GitRepo::discover(temp_dir.path()).unwrap();
+    HookRunner::new(
+        repo,
+        kind,
+        manifest.hooks.language.as_ref().unwrap(),
+        &manifest.hooks,
+    )
+}
+
+/// PATH with the zackstrap binary first, so the native shims find it.
+fn path_with_zackstrap() -> std::ffi::OsString {
+    let bin = assert_cmd::cargo::cargo_bin!("zackstrap");
+    let mut paths = vec![bin.parent().unwrap().to_path_buf()];
+    paths.extend(std::env::split_paths(&std::env::var_os("PATH").unwrap()));
+    std::env::join_paths(paths).unwrap()
+}
+
+#[test]
+fn test_manifest_selects_runner_checks() {
+    let temp_dir = repo_with_manifest(RUNNER_MANIFEST);
+
+    let pre_commit = runner(&temp_dir, HookKind::PreCommit);
+    let ids: Vec<&str> = pre_commit.checks().iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, ["eslint", "rubocop", "black", "no-todo", "typos"]);
+    assert_eq!(pre_commit.checks()[3].name, "No TODOs");
+    assert_eq!(pre_commit.checks()[4].name, "typos");
+
+    // Shell syntax has no program to look up; plain commands do
+    assert_eq!(pre_commit.checks()[0].program(), None);
+    assert_eq!(pre_commit.checks()[3].program(), None);
+    assert_eq!(
+        pre_commit.checks()[4].program(),
+        Some("definitely-not-installed-zackstrap-tool")
+    );
+
+    let pre_push = runner(&temp_dir, HookKind::PrePush);
+    let ids: Vec<&str> = pre_push.checks().iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, ["tests", "smoke"]);
+}
+
+#[test]
+fn test_invalid_runner_checks_are_rejected() {
+    for manifest in [
+        "[[hooks.checks]]\nid = \"lint\"\ncommand = \"lint\"\nhook = \"post-merge\"\n",
+        "[[hooks.checks]]\nid = \"lint\"\ncommand = \"lint\"\n\n[[hooks.checks]]\nid = \"lint\"\ncommand = \"lint --fix\"\n",
+        "[[hooks.checks]]\nid = \"lint\"\ncommand = \"  \"\n",
+        "[hooks]\nlanguage = \"cobol\"\n",
+        "[hooks]\njobs = 0\n",
+    ] {
+        let temp_dir = TempDir::new().unwrap();
+        temp_dir
+            .child(".zackstrap.toml")
+            .write_str(manifest)
+            .unwrap();
+        assert!(
+            matches!(
+                Manifest::load(temp_dir.path()),
+                Err(ZackstrapError::InvalidManifest(_, _))
+            ),
+            "{}",
+            manifest
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_runner_checks_staged_files_and_reports_every_failure() {
+    let temp_dir = repo_with_manifest(RUNNER_MANIFEST);
+    temp_dir
+        .child("notes.txt")
+        .write_str("TODO: ship\n")
+        .unwrap();
+    temp_dir.child("README.md").write_str("# demo\n").unwrap();
+    git(temp_dir.path(), &["add", "notes.txt", "README.md"]);
+    temp_dir
+        .child("notes.txt")
+        .write_str("TODO: ship\nmore\n")
+        .unwrap();
+    // A patch kept by an earlier failed restore is not overwritten
+    let leftover = temp_dir.child(format!(
+        ".git/zackstrap-unstaged-{}-0.patch",
+        std::process::id()
+    ));
+    leftover.write_str("earlier changes\n").unwrap();
+
+    let outcomes = runner(&temp_dir, HookKind::PreCommit).run().await.unwrap();
+    let statuses: Vec<(&str, &CheckStatus)> = outcomes
+        .iter()
+        .map(|o| (o.check.id.as_str(), &o.status))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("eslint", &CheckStatus::Skipped),
+            ("rubocop", &CheckStatus::Skipped),
+            ("black", &CheckStatus::Skipped),
+            ("no-todo", &CheckStatus::Failed),
+            (
+                "typos",
+                &CheckStatus::Missing("definitely-not-installed-zackstrap-tool".to_string())
+            ),
+        ]
+    );
+    assert!(outcomes[3].output.contains("1:TODO: ship"));
+    assert!(outcomes[3].failed() && outcomes[4].failed());
+
+    // The unstaged edit is back once the run finishes
+    temp_dir.child("notes.txt").assert("TODO: ship\nmore\n");
+    leftover.assert("earlier changes\n");
+}
+
+#[test]
+fn test_native_hooks_run_checks_through_zackstrap() {
+    let temp_dir = TempDir::new().unwrap();
+    git(temp_dir.path(), &["init"]);
+
+    Command::from_std(std::process::Command::new(assert_cmd::cargo::cargo_bin!(
+        "zackstrap"
+    )))
+    .arg("--target")
+    .arg(temp_dir.path())
+    .arg("--hooks-manager")
+    .arg("native")
+    .arg("basic")
+    .assert()
+    .success();
+
+    temp_dir
+        .child(".zackstrap.toml")
+        .assert("[hooks]\nlanguage = \"basic\"\ntemplate = \"default\"\n");
+    temp_dir
+        .child(".git/hooks/pre-commit.d/50-zackstrap")
+        .assert(predicates::str::contains(
+            "exec zackstrap hook-run pre-commit \"$@\"",
+        ));
+
+    let mut manifest = std::fs::read_to_string(temp_dir.child(".zackstrap.toml").path()).unwrap();
+    manifest.push_str(
+        "\n[[hooks.checks]]\nid = \"no-todo\"\ncommand = \"! grep -n TODO {files}\"\nextensions = [\"txt\"]\n",
+    );
+    temp_dir
+        .child(".zackstrap.toml")
+        .write_str(&manifest)
+        .unwrap();
+
+    let commit = |message: &str| {
+        std::process::Command::new("git")
+            .args([
+                "-c",
+                "user.name=zackstrap",
+                "-c",
+                "user.email=zackstrap@example.com",
+                "commit",
+                "-m",
+                message,
+            ])
+            .current_dir(temp_dir.path())
+            .env("PATH", path_with_zackstrap())
+            .output()
+            .unwrap()
+    };
+
+    // git sends hook output to stderr
+    temp_dir.child("notes.txt").write_str("TODO\n").unwrap();
+    git(temp_dir.path(), &["add", "notes.txt"]);
+    let output = commit("feat: add notes file");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no-todo"));
+
+    temp_dir.child("notes.txt").write_str("done\n").unwrap();
+    git(temp_dir.path(), &["add", "notes.txt"]);
+    let output = commit("added notes");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("conventional commits"));
+
+    let output = commit("feat: add notes file");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_project_type_round_trips_through_manifest() {
+    let temp_dir = TempDir::new().unwrap();
+    temp_dir
+        .child(".zackstrap.toml")
+        .write_str("[hooks]\nlanguage = \"rust\"\ntemplate = \"cli\"\n")
+        .unwrap();
+    let hooks = Manifest::load(temp_dir.path()).unwrap().hooks;
+    assert_eq!(hooks.language, Some(ProjectType::Rust));
+    assert_eq!(hooks.template.as_deref(), Some("cli"));
+}
+
+/// Two checks that each wait for the other to start: they only pass when
+/// run at the same time.
+const RENDEZVOUS_CHECKS: &str = r#"
+[[hooks.checks]]
+id = "left"
+hook = "pre-push"
+command = "touch .git/left; for i in 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20; do [ -f .git/right ] && exit 0; sleep 0.1; done; exit 1"
+
+[[hooks.checks]]
+id = "right"
+hook = "pre-push"
+command = "touch .git/right; for i in 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20; do [ -f .git/left ] && exit 0; sleep 0.1; done; exit 1"
+"#;
+
+#[tokio::test]
+async fn test_runner_runs_checks_concurrently_up_to_jobs() {
+    let temp_dir = repo_with_manifest(&format!(
+        "[hooks]\nlanguage = \"basic\"\njobs = 2\nskip = [\"tests\"]\n{}",
+        RENDEZVOUS_CHECKS
+    ));
+    let outcomes = runner(&temp_dir, HookKind::PrePush).run().await.unwrap();
+    let ids: Vec<&str> = outcomes.iter().map(|o| o.check.id.as_str()).collect();
+    assert_eq!(ids, ["left", "right"]);
+    assert!(outcomes.iter().all(|o| o.status == CheckStatus::Passed));
+
+    // One job at a time: the first check gives up waiting, the second
+    // still runs and finds the first one's marker
+    let temp_dir = repo_with_manifest(&format!(
+        "[hooks]\nlanguage = \"basic\"\njobs = 1\nskip = [\"tests\"]\n{}",
+        RENDEZVOUS_CHECKS
+    ));
+    let outcomes = runner(&temp_dir, HookKind::PrePush).run().await.unwrap();
+    assert_eq!(outcomes[0].status, CheckStatus::Failed);
+    assert_eq!(outcomes[1].status, CheckStatus::Passed);
+}
+
+#[tokio::test]
+async fn test_runner_runs_serial_checks_before_the_others() {
+    let temp_dir = repo_with_manifest(
+        r#"[hooks]
+language = "basic"
+jobs = 2
+skip = ["tests"]
+
+[[hooks.checks]]
+id = "reader"
+hook = "pre-push"
+command = "test -f .git/tidied"
+
+[[hooks.checks]]
+id = "tidy"
+hook = "pre-push"
+command = "sleep 0.2; touch .git/tidied"
+serial = true
+"#,
+    );
+    let outcomes = runner(&temp_dir, HookKind::PrePush).run().await.unwrap();
+    let ids: Vec<&str> = outcomes.iter().map(|o| o.check.id.as_str()).collect();
+    assert_eq!(ids, ["reader", "tidy"]);
+    assert!(outcomes.iter().all(|o| o.status == CheckStatus::Passed));
+
+    // go mod tidy rewrites go.mod and go.sum, which the tests build against
+    let temp_dir = repo_with_manifest("[hooks]\nlanguage = \"go\"\n");
+    let pre_push = runner(&temp_dir, HookKind::PrePush);
+    let serial: Vec<&str> = pre_push
+        .checks()
+        .iter()
+        .filter(|c| c.serial)
+        .map(|c| c.id.as_str())
+        .collect();
+    assert_eq!(serial, ["go-mod-tidy"]);
+}